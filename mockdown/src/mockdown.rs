@@ -5,22 +5,22 @@ use std::fmt::Debug;
 use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
 use std::thread::{self, ThreadId};
 
-trait AsAny {
-    fn as_any(self) -> Box<dyn Any>;
+trait IntoAny {
+    fn into_any(self) -> Box<dyn Any>;
 }
 
-impl<T: Any> AsAny for T {
-    fn as_any(self) -> Box<dyn Any> {
+impl<T: Any> IntoAny for T {
+    fn into_any(self) -> Box<dyn Any> {
         Box::new(self)
     }
 }
 
-trait AsType {
-    fn as_type<T: Any>(self, expect: &dyn Expect) -> Result<T, &'static str>;
+trait IntoType {
+    fn into_type<T: Any>(self, expect: &dyn Expect) -> Result<T, &'static str>;
 }
 
-impl AsType for Box<dyn Any> {
-    fn as_type<T: Any>(self, expect: &dyn Expect) -> Result<T, &'static str> {
+impl IntoType for Box<dyn Any> {
+    fn into_type<T: Any>(self, expect: &dyn Expect) -> Result<T, &'static str> {
         self.downcast::<T>()
             .map_err(|_| expect.type_name())
             .map(|value| *value)
@@ -40,15 +40,15 @@ impl Debug for dyn Expect {
 
 impl dyn Expect {
     fn on_mock<T: Any, U: Any>(&self, when: T) -> Result<U, &'static str> {
-        let then = self.mock(when.as_any())?;
-        Ok(then.as_type(self)?)
+        let then = self.mock(when.into_any())?;
+        then.into_type(self)
     }
 }
 
 impl<T: Any, U: Any> Expect for fn(T) -> U {
     fn mock(&self, when: Box<dyn Any>) -> Result<Box<dyn Any>, &'static str> {
-        let then = self(when.as_type(self)?);
-        Ok(then.as_any())
+        let then = self(when.into_type(self)?);
+        Ok(then.into_any())
     }
 
     fn type_name(&self) -> &'static str {
@@ -128,9 +128,15 @@ where
 
 pub struct StaticMock<M: Mockdown>(LazyLock<Arc<Mutex<HashMap<ThreadId, M>>>>);
 
+impl<M: Mockdown + Clone + Default> Default for StaticMock<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<M: Mockdown + Clone + Default> StaticMock<M> {
     pub const fn new() -> StaticMock<M> {
-        Self(LazyLock::new(|| Default::default()))
+        Self(LazyLock::new(Default::default))
     }
 
     fn with(&self) -> (MutexGuard<'_, HashMap<ThreadId, M>>, ThreadId) {
@@ -139,10 +145,7 @@ impl<M: Mockdown + Clone + Default> StaticMock<M> {
 
     pub fn static_mock(&self) -> M {
         let (mut map, id) = self.with();
-        if !map.contains_key(&id) {
-            map.insert(id, M::default());
-        }
-        map.get(&id).unwrap().clone().clear()
+        map.entry(id).or_default().clone().clear()
     }
 
     pub fn on_mock<T: Any + Debug, U: Any>(&self, args: T) -> Result<U, String> {
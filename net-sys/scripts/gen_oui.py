@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+"""Builds net-sys/src/oui.csv from the IEEE MA-L, MA-M and MA-S registries.
+
+Usage: gen_oui.py [DIR]
+
+Reads oui.csv, mam.csv and oui36.csv from DIR when given, otherwise
+downloads them from standards-oui.ieee.org. Writes the compact registry,
+the same rows without the address column, sorted by registry and
+assignment, to stdout.
+"""
+
+import csv
+import io
+import sys
+import urllib.request
+from pathlib import Path
+
+SOURCES = [
+    ("MA-L", "oui.csv", "https://standards-oui.ieee.org/oui/oui.csv"),
+    ("MA-M", "mam.csv", "https://standards-oui.ieee.org/oui28/mam.csv"),
+    ("MA-S", "oui36.csv", "https://standards-oui.ieee.org/oui36/oui36.csv"),
+]
+
+
+def read(name, url, directory):
+    if directory is not None:
+        return (Path(directory) / name).read_text(encoding="utf-8")
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode("utf-8")
+
+
+def main():
+    directory = sys.argv[1] if len(sys.argv) > 1 else None
+    rows = set()
+    for registry, name, url in SOURCES:
+        for row in csv.DictReader(io.StringIO(read(name, url, directory))):
+            if row["Registry"] != registry:
+                continue
+            vendor = " ".join(row["Organization Name"].split())
+            rows.add((registry, row["Assignment"].upper(), vendor))
+
+    out = csv.writer(sys.stdout, lineterminator="\n")
+    out.writerow(["Registry", "Assignment", "Organization Name"])
+    out.writerows(sorted(rows))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,33 @@
+use crate::{HardwareAddress, IfFlags, IfId, Link, LinkLevelAddress, Result};
+use core::fmt::Debug;
+
+/// What a [`crate::Nic`] asks of the system, for code that needs to stand
+/// in for it, see [`crate::Nic::with_backend`].
+///
+/// Interfaces are passed on as the caller gave them, names are not looked
+/// up by index or the other way round. `Nic` still refuses unassignable
+/// addresses before `set_lladd` is called, and builds `set_lladd_bounce`
+/// and `reset_lladdr` out of the methods below.
+pub trait NicBackend: Debug {
+    fn get_lladd(&self, ifid: IfId) -> Result<LinkLevelAddress>;
+
+    fn set_lladd(&self, ifid: IfId, lladdr: &LinkLevelAddress) -> Result<()>;
+
+    fn get_flags(&self, ifid: IfId) -> Result<IfFlags>;
+
+    /// Brings the interface up or down, leaving the other flags alone.
+    fn set_admin(&self, ifid: IfId, up: bool) -> Result<()>;
+
+    fn get_mtu(&self, ifid: IfId) -> Result<u32>;
+
+    fn set_mtu(&self, ifid: IfId, mtu: u32) -> Result<()>;
+
+    fn get_permanent_lladdr(&self, ifid: IfId) -> Result<LinkLevelAddress>;
+
+    fn list(&self) -> Result<Vec<Link>>;
+
+    /// Defaults to `get_lladd` as an Ethernet address.
+    fn get_hwaddr(&self, ifid: IfId) -> Result<HardwareAddress> {
+        Ok(HardwareAddress::from(&self.get_lladd(ifid)?))
+    }
+}
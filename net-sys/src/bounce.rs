@@ -0,0 +1,73 @@
+use crate::{ErrorKind, IfId, Operation};
+use core::fmt::{Debug, Display};
+
+/// Failure of `Nic::set_lladd_bounce` where the interface could not be
+/// brought back up after the address change failed, so it is left down.
+pub struct BounceError {
+    ifid: IfId,
+    error: crate::Error,
+    restore_error: Box<dyn std::error::Error>,
+}
+
+impl BounceError {
+    pub(crate) fn new(
+        ifid: IfId,
+        error: Box<dyn std::error::Error>,
+        restore_error: Box<dyn std::error::Error>,
+    ) -> Self {
+        // Custom backends may fail with their own errors, which are kept as
+        // the source of an `ErrorKind::Other`.
+        let error = match error.downcast::<crate::Error>() {
+            Ok(error) => *error,
+            Err(error) => crate::Error::new(
+                ErrorKind::Other,
+                Operation::SetLinkLevelAddress,
+                error.to_string(),
+            )
+            .with_ifid(ifid),
+        };
+        Self {
+            ifid,
+            error,
+            restore_error,
+        }
+    }
+
+    /// The interface left down.
+    pub fn ifid(&self) -> IfId {
+        self.ifid
+    }
+
+    /// Why the address change failed, with the same kind as when
+    /// `Nic::set_lladd` fails.
+    pub fn error(&self) -> &crate::Error {
+        &self.error
+    }
+
+    /// Why the interface could not be brought back up.
+    pub fn restore_error(&self) -> &(dyn std::error::Error + 'static) {
+        &*self.restore_error
+    }
+}
+
+impl std::error::Error for BounceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error())
+    }
+}
+
+impl Display for BounceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for BounceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Nic::BounceError")
+            .field("ifid", &self.ifid)
+            .field("error", &self.error)
+            .field("restore_error", &self.restore_error)
+            .finish()
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{IfId, LinkLevelAddress};
+use core::fmt::{Debug, Display};
+use std::io;
+
+type Source = Box<dyn std::error::Error + Send + Sync>;
+
+/// Broad cause of an [`Error`], mostly derived from the `errno` the system
+/// call failed with.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// `EPERM`, `EACCES`: usually missing `CAP_NET_ADMIN` or root.
+    PermissionDenied,
+    /// `ENODEV`, `ENXIO`: the interface is gone or never existed.
+    NoSuchDevice,
+    /// `EBUSY`: the driver refuses the change while the link is up.
+    Busy,
+    /// `EOPNOTSUPP`, `ENOTTY`, `EAFNOSUPPORT`, `EPROTONOSUPPORT`, or an
+    /// operation the selected backend cannot do.
+    Unsupported,
+    /// `EADDRNOTAVAIL`, or an address `net_sys` refuses to assign.
+    InvalidAddress,
+    /// `EINVAL`, `ERANGE`.
+    InvalidArgument,
+    /// Anything else.
+    Other,
+}
+
+impl ErrorKind {
+    pub fn from_errno(errno: i32) -> Self {
+        match errno {
+            libc::EPERM | libc::EACCES => Self::PermissionDenied,
+            libc::ENODEV | libc::ENXIO => Self::NoSuchDevice,
+            libc::EBUSY => Self::Busy,
+            libc::EOPNOTSUPP | libc::ENOTTY | libc::EAFNOSUPPORT | libc::EPROTONOSUPPORT => {
+                Self::Unsupported
+            }
+            // Same value as EOPNOTSUPP on Linux, not on macOS
+            errno if errno == libc::ENOTSUP => Self::Unsupported,
+            libc::EADDRNOTAVAIL => Self::InvalidAddress,
+            libc::EINVAL | libc::ERANGE => Self::InvalidArgument,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<ErrorKind> for io::ErrorKind {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
+            ErrorKind::NoSuchDevice => io::ErrorKind::NotFound,
+            ErrorKind::Busy => io::ErrorKind::ResourceBusy,
+            ErrorKind::Unsupported => io::ErrorKind::Unsupported,
+            ErrorKind::InvalidAddress => io::ErrorKind::AddrNotAvailable,
+            ErrorKind::InvalidArgument => io::ErrorKind::InvalidInput,
+            ErrorKind::Other => io::ErrorKind::Other,
+        }
+    }
+}
+
+/// What `net_sys` was doing when an [`Error`] happened.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Operation {
+    Open,
+    Send,
+    Receive,
+    Close,
+    GetLinks,
+    ResolveName,
+    GetLinkLevelAddress,
+    SetLinkLevelAddress,
+    GetPermanentLinkLevelAddress,
+    GetFlags,
+    SetFlags,
+    GetMtu,
+    SetMtu,
+    GetInfo,
+    Watch,
+}
+
+/// Failure of a system call or of an operation the backend refused.
+///
+/// `net_sys::Result` stays a boxed error, get this one back with
+/// `error.downcast_ref::<net_sys::Error>()`. The detailed backend error it
+/// wraps is its `source()`, and only shows in the `Debug` output.
+pub struct Error {
+    kind: ErrorKind,
+    operation: Operation,
+    ifid: Option<IfId>,
+    lladdr: Option<LinkLevelAddress>,
+    errno: Option<i32>,
+    source: Source,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, operation: Operation, source: impl Into<Source>) -> Self {
+        Error {
+            kind,
+            operation,
+            ifid: None,
+            lladdr: None,
+            errno: None,
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn from_errno(errno: i32, operation: Operation, source: impl Into<Source>) -> Self {
+        Error {
+            errno: Some(errno),
+            ..Self::new(ErrorKind::from_errno(errno), operation, source)
+        }
+    }
+
+    pub(crate) fn with_ifid(self, ifid: impl Into<IfId>) -> Self {
+        Error {
+            ifid: Some(ifid.into()),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_lladdr(self, lladdr: LinkLevelAddress) -> Self {
+        Error {
+            lladdr: Some(lladdr),
+            ..self
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// The interface the operation was about, if any.
+    pub fn ifid(&self) -> Option<IfId> {
+        self.ifid
+    }
+
+    /// The address being set, if any.
+    pub fn lladdr(&self) -> Option<LinkLevelAddress> {
+        self.lladdr
+    }
+
+    /// The raw `errno`, when a system call failed.
+    pub fn errno(&self) -> Option<i32> {
+        self.errno
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl Error {
+    // Fields left unset by the backend are not shown.
+    fn debug_fields<'a, 'b>(
+        &self,
+        f: &'a mut std::fmt::Formatter<'b>,
+    ) -> std::fmt::DebugStruct<'a, 'b> {
+        let mut debug = f.debug_struct("Error");
+        debug.field("kind", &self.kind);
+        debug.field("operation", &self.operation);
+        if let Some(ifid) = &self.ifid {
+            debug.field("ifid", ifid);
+        }
+        if let Some(lladdr) = &self.lladdr {
+            debug.field("lladdr", lladdr);
+        }
+        if let Some(errno) = &self.errno {
+            debug.field("errno", errno);
+        }
+        debug
+    }
+}
+
+/// Leaves out the backend error, which is the [`std::error::Error::source`].
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.debug_fields(f).finish()
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.debug_fields(f).field("source", &self.source).finish()
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        io::Error::new(error.kind.into(), error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind, Operation};
+    use crate::{lladdr, IfId, IfName};
+    use std::io;
+
+    #[test]
+    fn test_error_kind_from_errno() {
+        assert_eq!(
+            ErrorKind::from_errno(libc::EPERM),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(
+            ErrorKind::from_errno(libc::EACCES),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(ErrorKind::from_errno(libc::ENODEV), ErrorKind::NoSuchDevice);
+        assert_eq!(ErrorKind::from_errno(libc::ENXIO), ErrorKind::NoSuchDevice);
+        assert_eq!(ErrorKind::from_errno(libc::EBUSY), ErrorKind::Busy);
+        assert_eq!(
+            ErrorKind::from_errno(libc::EOPNOTSUPP),
+            ErrorKind::Unsupported
+        );
+        assert_eq!(ErrorKind::from_errno(libc::ENOTSUP), ErrorKind::Unsupported);
+        assert_eq!(
+            ErrorKind::from_errno(libc::EADDRNOTAVAIL),
+            ErrorKind::InvalidAddress
+        );
+        assert_eq!(
+            ErrorKind::from_errno(libc::EINVAL),
+            ErrorKind::InvalidArgument
+        );
+        assert_eq!(ErrorKind::from_errno(libc::ENOMEM), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_error_fields() {
+        let ifname = IfName::try_from("enx").unwrap();
+        let lladdr = lladdr!("02:00:00:00:00:01");
+
+        let error = Error::from_errno(libc::EBUSY, Operation::SetLinkLevelAddress, "SetError")
+            .with_ifid(ifname)
+            .with_lladdr(lladdr);
+
+        assert_eq!(error.kind(), ErrorKind::Busy);
+        assert_eq!(error.operation(), Operation::SetLinkLevelAddress);
+        assert_eq!(error.ifid(), Some(IfId::from(ifname)));
+        assert_eq!(error.lladdr(), Some(lladdr));
+        assert_eq!(error.errno(), Some(libc::EBUSY));
+        assert_eq!(
+            format!("{}", error),
+            "Error { kind: Busy, operation: SetLinkLevelAddress, ifid: Name(\"enx\"), \
+             lladdr: \"02:00:00:00:00:01\", errno: 16 }"
+        );
+        assert_eq!(
+            format!("{:?}", error),
+            "Error { kind: Busy, operation: SetLinkLevelAddress, ifid: Name(\"enx\"), \
+             lladdr: \"02:00:00:00:00:01\", errno: 16, source: \"SetError\" }"
+        );
+    }
+
+    #[test]
+    fn test_error_source() {
+        let error = Error::new(ErrorKind::Other, Operation::Close, "CloseError");
+
+        let source = std::error::Error::source(&error).unwrap();
+
+        assert_eq!(format!("{}", source), "CloseError");
+    }
+
+    #[test]
+    fn test_error_into_io_error() {
+        let error = Error::from_errno(libc::EPERM, Operation::SetFlags, "SetFlagsError");
+
+        let io_error = io::Error::from(error);
+
+        assert_eq!(io_error.kind(), io::ErrorKind::PermissionDenied);
+        let inner = io_error.get_ref().unwrap().downcast_ref::<Error>().unwrap();
+        assert_eq!(inner.operation(), Operation::SetFlags);
+    }
+
+    #[test]
+    fn test_error_downcast_from_boxed() {
+        let error: Box<dyn std::error::Error> =
+            Error::new(ErrorKind::Unsupported, Operation::Watch, "WatchError").into();
+
+        let error = error.downcast_ref::<Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.errno(), None);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{LinkLevelAddress, Result};
+use core::fmt::{Debug, Display};
+use std::ops::Deref;
+
+// Longest hardware address the kernel handles
+// MAX_ADDR_LEN = 32
+// https://github.com/torvalds/linux/blob/master/include/linux/netdevice.h
+const MAX_ADDR_LEN: usize = 32;
+
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_arp.h
+const ARPHRD_ETHER: u16 = 1;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    TooLong(u16, usize),
+    InvalidOctet(String, String, String),
+    NotLinkLevelAddress(HardwareAddress),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooLong(hwtype, len) => f
+                .debug_struct("HardwareAddress::TooLongError")
+                .field("hwtype", hwtype)
+                .field("len", len)
+                .field("max_len", &MAX_ADDR_LEN)
+                .finish(),
+            Self::InvalidOctet(value, octet, error) => f
+                .debug_struct("HardwareAddress::InvalidOctetError")
+                .field("value", value)
+                .field("octet", octet)
+                .field("error", error)
+                .finish(),
+            Self::NotLinkLevelAddress(hwaddr) => f
+                .debug_struct("HardwareAddress::NotLinkLevelAddressError")
+                .field("hwaddr", hwaddr)
+                .field("hwtype", &hwaddr.hwtype)
+                .field("len", &hwaddr.len())
+                .finish(),
+        }
+    }
+}
+
+/// Hardware address of any length, tagged with its `ARPHRD_*` type.
+///
+/// Covers the links [`LinkLevelAddress`] cannot: InfiniBand (20 octets),
+/// FireWire, EUI-64, tunnels with 4 octet or empty addresses.
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct HardwareAddress {
+    hwtype: u16,
+    len: u8,
+    octets: [u8; MAX_ADDR_LEN],
+}
+
+impl HardwareAddress {
+    pub fn new(hwtype: u16, octets: &[u8]) -> Result<Self> {
+        if octets.len() > MAX_ADDR_LEN {
+            return Err(Error::TooLong(hwtype, octets.len()).into());
+        }
+        let mut hwaddr = HardwareAddress {
+            hwtype,
+            len: octets.len() as u8,
+            octets: [0; MAX_ADDR_LEN],
+        };
+        hwaddr.octets[..octets.len()].copy_from_slice(octets);
+        Ok(hwaddr)
+    }
+
+    /// Parses colon separated hex, as found in `/sys/class/net/*/address`.
+    /// An empty string is an empty address.
+    pub fn parse(hwtype: u16, value: &str) -> Result<Self> {
+        let octets = value
+            .split(':')
+            .filter(|_| !value.is_empty())
+            .map(|octet| {
+                u8::from_str_radix(octet, 16).map_err(|error| {
+                    Error::InvalidOctet(value.to_string(), octet.to_string(), error.to_string())
+                })
+            })
+            .collect::<std::result::Result<Vec<u8>, Error>>()?;
+        Self::new(hwtype, &octets)
+    }
+
+    /// The `ARPHRD_*` hardware type.
+    pub fn hwtype(&self) -> u16 {
+        self.hwtype
+    }
+
+    pub fn is_ether(&self) -> bool {
+        self.hwtype == ARPHRD_ETHER
+    }
+}
+
+impl Deref for HardwareAddress {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.octets[..self.len as usize]
+    }
+}
+
+impl Debug for HardwareAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
+impl Display for HardwareAddress {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let octets = self
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect::<Vec<_>>();
+        write!(fmt, "{}", octets.join(":"))
+    }
+}
+
+impl From<&LinkLevelAddress> for HardwareAddress {
+    fn from(lladdr: &LinkLevelAddress) -> Self {
+        let mut hwaddr = HardwareAddress {
+            hwtype: ARPHRD_ETHER,
+            len: lladdr.len() as u8,
+            octets: [0; MAX_ADDR_LEN],
+        };
+        hwaddr.octets[..lladdr.len()].copy_from_slice(&lladdr[..]);
+        hwaddr
+    }
+}
+
+impl TryFrom<&HardwareAddress> for LinkLevelAddress {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(hwaddr: &HardwareAddress) -> Result<Self> {
+        match <&[u8; 6]>::try_from(&hwaddr[..]) {
+            Ok(octets) => Ok(LinkLevelAddress::from(octets)),
+            Err(_) => Err(Error::NotLinkLevelAddress(*hwaddr).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HardwareAddress, ARPHRD_ETHER};
+    use crate::LinkLevelAddress;
+
+    const ARPHRD_INFINIBAND: u16 = 32;
+    const IB_ADDR: &str = "80:00:02:08:fe:80:00:00:00:00:00:00:00:02:c9:03:00:0c:73:61";
+
+    #[test]
+    fn test_hardware_address_new() {
+        let hwaddr =
+            HardwareAddress::new(ARPHRD_ETHER, &[0x00, 0x11, 0x22, 0x33, 0x44, 0x55]).unwrap();
+
+        assert_eq!(hwaddr.hwtype(), ARPHRD_ETHER);
+        assert_eq!(hwaddr.len(), 6);
+        assert!(hwaddr.is_ether());
+        assert_eq!(format!("{}", hwaddr), "00:11:22:33:44:55");
+        assert_eq!(format!("{:?}", hwaddr), "\"00:11:22:33:44:55\"");
+    }
+
+    #[test]
+    fn test_hardware_address_new_too_long() {
+        let error = HardwareAddress::new(ARPHRD_INFINIBAND, &[0; 33]).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "HardwareAddress::TooLongError { hwtype: 32, len: 33, max_len: 32 }"
+        );
+    }
+
+    #[test]
+    fn test_hardware_address_parse() {
+        let hwaddr = HardwareAddress::parse(ARPHRD_INFINIBAND, IB_ADDR).unwrap();
+
+        assert_eq!(hwaddr.len(), 20);
+        assert!(!hwaddr.is_ether());
+        assert_eq!(hwaddr.to_string(), IB_ADDR);
+    }
+
+    #[test]
+    fn test_hardware_address_parse_empty() {
+        let hwaddr = HardwareAddress::parse(0xfffe, "").unwrap();
+
+        assert!(hwaddr.is_empty());
+        assert_eq!(hwaddr.to_string(), "");
+    }
+
+    #[test]
+    fn test_hardware_address_parse_invalid_octet() {
+        let error = HardwareAddress::parse(ARPHRD_ETHER, "00:11:zz").unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "HardwareAddress::InvalidOctetError { value: \"00:11:zz\", octet: \"zz\", error: \"invalid digit found in string\" }"
+        );
+    }
+
+    #[test]
+    fn test_hardware_address_from_lladdr() {
+        let lladdr: LinkLevelAddress = "00:11:22:33:44:55".parse().unwrap();
+
+        let hwaddr = HardwareAddress::from(&lladdr);
+
+        assert!(hwaddr.is_ether());
+        assert_eq!(LinkLevelAddress::try_from(&hwaddr).unwrap(), lladdr);
+    }
+
+    #[test]
+    fn test_hardware_address_to_lladdr_error() {
+        let hwaddr = HardwareAddress::new(768, &[10, 0, 0, 1]).unwrap();
+
+        let error = LinkLevelAddress::try_from(&hwaddr).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "HardwareAddress::NotLinkLevelAddressError { hwaddr: \"0a:00:00:01\", hwtype: 768, len: 4 }"
+        );
+    }
+}
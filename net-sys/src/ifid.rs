@@ -0,0 +1,82 @@
+use crate::{IfIndex, IfName};
+use core::fmt::Display;
+
+/// An interface referred to either by name or by index. Every `Nic`
+/// operation accepts both, so callers can keep tracking a link by its
+/// index after it has been renamed.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum IfId {
+    Name(IfName),
+    Index(IfIndex),
+}
+
+impl Display for IfId {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name(ifname) => write!(fmt, "{}", ifname),
+            Self::Index(ifindex) => write!(fmt, "{}", ifindex),
+        }
+    }
+}
+
+impl From<IfName> for IfId {
+    fn from(value: IfName) -> Self {
+        Self::Name(value)
+    }
+}
+
+impl From<&IfName> for IfId {
+    fn from(value: &IfName) -> Self {
+        Self::Name(*value)
+    }
+}
+
+impl From<IfIndex> for IfId {
+    fn from(value: IfIndex) -> Self {
+        Self::Index(value)
+    }
+}
+
+impl From<&IfIndex> for IfId {
+    fn from(value: &IfIndex) -> Self {
+        Self::Index(*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IfId;
+    use crate::{IfIndex, IfName};
+
+    #[test]
+    fn test_ifid_from_ifname() {
+        let ifname = IfName::try_from("enx").unwrap();
+
+        assert_eq!(IfId::from(ifname), IfId::Name(ifname));
+        assert_eq!(IfId::from(&ifname), IfId::Name(ifname));
+    }
+
+    #[test]
+    fn test_ifid_from_ifindex() {
+        let ifindex = IfIndex::from(2);
+
+        assert_eq!(IfId::from(ifindex), IfId::Index(ifindex));
+        assert_eq!(IfId::from(&ifindex), IfId::Index(ifindex));
+    }
+
+    #[test]
+    fn test_ifid_debug() {
+        let ifname = IfName::try_from("enx").unwrap();
+
+        assert_eq!(format!("{:?}", IfId::from(ifname)), "Name(\"enx\")");
+        assert_eq!(format!("{:?}", IfId::from(IfIndex::from(2))), "Index(2)");
+    }
+
+    #[test]
+    fn test_ifid_display() {
+        let ifname = IfName::try_from("enx").unwrap();
+
+        assert_eq!(format!("{}", IfId::from(ifname)), "enx");
+        assert_eq!(format!("{}", IfId::from(IfIndex::from(2))), "2");
+    }
+}
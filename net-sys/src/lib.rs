@@ -4,23 +4,70 @@ compile_error!("Unsupported system!");
 #[cfg(not(any(target_os = "linux", target_os = "macos")))]
 compile_error!("Unsupported target os!");
 
+mod backend;
+mod bounce;
+mod error;
+mod hwaddr;
+mod ifid;
+mod link;
 mod lladdr;
+mod macrange;
+#[cfg(feature = "oui")]
+mod oui;
+#[cfg(any(test, feature = "sim"))]
+pub mod sim;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod transaction;
 
 #[cfg_attr(feature = "libc", path = "libc")]
 pub mod sys {
     #[cfg_attr(target_os = "linux", path = "linux")]
     #[cfg_attr(target_os = "macos", path = "macos")]
     pub mod os {
+        #[cfg(target_os = "linux")]
+        mod ethtool;
+        mod ifaddrs;
+        pub mod ifindex;
         pub mod ifname;
         mod ifreq;
+        #[cfg(all(target_os = "linux", feature = "netlink"))]
+        mod netlink;
         pub mod nic;
+        #[cfg(all(target_os = "linux", feature = "netlink"))]
+        mod nlmsg;
         mod socket;
         mod sys;
+        #[cfg(target_os = "linux")]
+        pub mod sysfs;
+        #[cfg(all(target_os = "linux", feature = "netlink"))]
+        pub mod watch;
     }
 }
 
-pub use lladdr::{LLAddr, LinkLevelAddress};
+pub use backend::NicBackend;
+pub use bounce::BounceError;
+pub use error::{Error, ErrorKind, Operation};
+pub use hwaddr::HardwareAddress;
+pub use ifid::IfId;
+pub use link::{IfFlags, Link, LinkEvent};
+pub use lladdr::{
+    LLAddr, LinkLevelAddress, MacFormat, OsRandom, ParseLLAddrError, ParseLLAddrErrorKind,
+    RandomSource,
+};
+pub use macrange::{MacPrefix, MacRange};
+#[cfg(feature = "oui")]
+pub use oui::OuiRegistry;
 use std::result;
-pub use sys::os::{ifname::IfName, nic::Nic};
+pub use sys::os::{
+    ifindex::IfIndex,
+    ifname::{IfName, ParseIfNameError, ParseIfNameErrorKind},
+    nic::{Nic, Session},
+};
+#[cfg(target_os = "linux")]
+pub use sys::os::sysfs::{AddrAssignType, LinkInfo, OperState};
+#[cfg(all(target_os = "linux", feature = "netlink"))]
+pub use sys::os::watch::Watch;
+pub use transaction::{Transaction, TransactionError};
 
 pub type Result<T> = result::Result<T, Box<dyn std::error::Error>>;
@@ -0,0 +1,119 @@
+use crate::LinkLevelAddress;
+use libc::c_void;
+
+// Get permanent hardware address
+// ETHTOOL_GPERMADDR = 0x20
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/ethtool.h
+pub(super) const ETHTOOL_GPERMADDR: u32 = 0x20;
+
+// MAX_ADDR_LEN = 32
+// https://github.com/torvalds/linux/blob/master/include/linux/netdevice.h
+const MAX_ADDR_LEN: usize = 32;
+
+// struct ethtool_perm_addr with room for MAX_ADDR_LEN bytes of data
+#[repr(C)]
+pub(super) struct PermAddr {
+    cmd: u32,
+    size: u32,
+    data: [u8; MAX_ADDR_LEN],
+}
+
+pub(super) fn new() -> PermAddr {
+    PermAddr {
+        cmd: ETHTOOL_GPERMADDR,
+        size: MAX_ADDR_LEN as u32,
+        data: [0; MAX_ADDR_LEN],
+    }
+}
+
+pub(super) fn as_mut_ptr(perm_addr: &mut PermAddr) -> *mut c_void {
+    perm_addr as *const _ as *mut c_void
+}
+
+pub(super) fn get_lladdr(perm_addr: &PermAddr) -> LinkLevelAddress {
+    let mut lladdr = [0u8; 6];
+    let len = usize::min(perm_addr.size as usize, lladdr.len());
+    lladdr[..len].copy_from_slice(&perm_addr.data[..len]);
+    LinkLevelAddress::from(&lladdr)
+}
+
+#[cfg(test)]
+mod tests {
+    use libc::c_void;
+
+    const PERM_ADDR_SIZE: usize = 40;
+    const LLADDR: [u8; 6] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+
+    #[test]
+    fn test_ethtool_gpermaddr() {
+        assert_eq!(super::ETHTOOL_GPERMADDR, 0x20)
+    }
+
+    #[test]
+    fn test_perm_addr_size() {
+        let expected_size = std::mem::size_of::<super::PermAddr>();
+
+        assert_eq!(PERM_ADDR_SIZE, expected_size);
+    }
+
+    #[test]
+    fn test_perm_addr_new() {
+        let perm_addr = super::new();
+
+        assert_eq!(perm_addr.cmd, super::ETHTOOL_GPERMADDR);
+        assert_eq!(perm_addr.size, 32);
+        assert_eq!(perm_addr.data, [0; 32]);
+    }
+
+    #[test]
+    fn test_perm_addr_as_mut_ptr() {
+        let mut perm_addr = super::new();
+        let expected_ptr = &perm_addr as *const _ as *mut c_void;
+
+        let ptr = super::as_mut_ptr(&mut perm_addr);
+
+        assert_eq!(ptr, expected_ptr);
+    }
+
+    #[test]
+    fn test_perm_addr_get_lladdr() {
+        let mut perm_addr = super::new();
+        perm_addr.size = LLADDR.len() as u32;
+        perm_addr.data[..LLADDR.len()].copy_from_slice(&LLADDR);
+
+        let lladdr = super::get_lladdr(&perm_addr);
+
+        assert_eq!(*lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_perm_addr_get_lladdr_empty() {
+        let mut perm_addr = super::new();
+        perm_addr.size = 0;
+        perm_addr.data[..LLADDR.len()].copy_from_slice(&LLADDR);
+
+        let lladdr = super::get_lladdr(&perm_addr);
+
+        assert_eq!(*lladdr, [0; 6]);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::PermAddr;
+    use crate::LinkLevelAddress;
+
+    fn from_mut_ptr<'a>(arg: *mut libc::c_void) -> &'a mut PermAddr {
+        unsafe { &mut *(arg as *mut PermAddr) }
+    }
+
+    pub(crate) fn ethtool_get_cmd(arg: *mut libc::c_void) -> u32 {
+        from_mut_ptr(arg).cmd
+    }
+
+    pub(crate) fn ethtool_set_lladdr(arg: *mut libc::c_void, lladdr: LinkLevelAddress) {
+        let perm_addr = from_mut_ptr(arg);
+        perm_addr.size = lladdr.len() as u32;
+        perm_addr.data[..lladdr.len()].copy_from_slice(&lladdr[..]);
+    }
+}
@@ -0,0 +1,195 @@
+use super::ifname::IfName;
+use crate::{HardwareAddress, IfFlags, IfIndex, Link, LinkLevelAddress};
+use libc::{ifaddrs, sockaddr_ll};
+use std::ffi::CStr;
+
+pub(super) fn get_name(ifaddrs: &ifaddrs) -> IfName {
+    let c_str = unsafe { CStr::from_ptr(ifaddrs.ifa_name) };
+    IfName::from_bytes(c_str.to_bytes())
+}
+
+fn get_sockaddr_ll(ifaddrs: &ifaddrs) -> Option<&sockaddr_ll> {
+    let addr = unsafe { ifaddrs.ifa_addr.as_ref() }?;
+    match addr.sa_family as libc::c_int {
+        libc::AF_PACKET => Some(unsafe { &*(addr as *const _ as *const sockaddr_ll) }),
+        _ => None,
+    }
+}
+
+pub(super) fn get_link(ifaddrs: &ifaddrs) -> Option<Link> {
+    let sll = get_sockaddr_ll(ifaddrs)?;
+    // sll_addr holds 8 octets, longer addresses are cut there
+    let octets = &sll.sll_addr[..usize::min(sll.sll_halen as usize, sll.sll_addr.len())];
+    let mut lladdr = [0u8; 6];
+    let len = usize::min(octets.len(), lladdr.len());
+    lladdr[..len].copy_from_slice(&octets[..len]);
+    let link = Link::new(
+        get_name(ifaddrs),
+        IfIndex::from(sll.sll_ifindex as u32),
+        LinkLevelAddress::from(&lladdr),
+        IfFlags::from(ifaddrs.ifa_flags),
+    );
+    Some(match HardwareAddress::new(sll.sll_hatype, octets) {
+        Ok(hwaddr) => link.with_hwaddr(hwaddr),
+        Err(_) => link,
+    })
+}
+
+pub(super) fn get_links(ifap: *mut ifaddrs) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut ifa = ifap;
+    while let Some(ifaddrs) = unsafe { ifa.as_ref() } {
+        links.extend(get_link(ifaddrs));
+        ifa = ifaddrs.ifa_next;
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{ifaddrs_free, ifaddrs_new};
+    use crate::{HardwareAddress, IfFlags, IfName, Link, LinkLevelAddress};
+    use std::sync::LazyLock;
+
+    static LINKS: LazyLock<Vec<Link>> = LazyLock::new(|| {
+        let flags = IfFlags::from((libc::IFF_UP | libc::IFF_RUNNING) as u32);
+        vec![
+            Link::new(
+                "enx".try_into().unwrap(),
+                2.into(),
+                "00:11:22:33:44:55".parse().unwrap(),
+                flags,
+            ),
+            Link::new(
+                "wlan0".try_into().unwrap(),
+                3.into(),
+                "66:77:88:99:aa:bb".parse().unwrap(),
+                IfFlags::default(),
+            ),
+        ]
+    });
+
+    #[test]
+    fn test_ifaddrs_get_name() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let ifname = super::get_name(unsafe { &*ifap });
+
+        assert_eq!(ifname, IfName::try_from("enx").unwrap());
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_link() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let link = super::get_link(unsafe { &*ifap });
+
+        assert_eq!(link, Some(LINKS[0]));
+        assert_eq!(
+            link.unwrap().lladdr(),
+            &"00:11:22:33:44:55".parse::<LinkLevelAddress>().unwrap()
+        );
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_link_no_address() {
+        let hwaddr = HardwareAddress::new(libc::ARPHRD_NONE, &[]).unwrap();
+        let tun = Link::new(
+            "tun0".try_into().unwrap(),
+            5.into(),
+            LinkLevelAddress::from(&[0; 6]),
+            IfFlags::default(),
+        )
+        .with_hwaddr(hwaddr);
+        let ifap = ifaddrs_new(&[tun]);
+
+        let link = super::get_link(unsafe { &*ifap }).unwrap();
+
+        assert_eq!(link, tun);
+        assert_eq!(link.hwaddr().len(), 0);
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_links() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let links = super::get_links(ifap);
+
+        assert_eq!(links, *LINKS);
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_links_empty() {
+        let links = super::get_links(std::ptr::null_mut());
+
+        assert_eq!(links, vec![]);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use crate::Link;
+    use libc::{ifaddrs, sockaddr, sockaddr_ll, sockaddr_storage};
+    use std::ffi::CString;
+
+    fn new_entry(link: &Link, addr: Option<sockaddr_storage>, next: *mut ifaddrs) -> *mut ifaddrs {
+        let mut ifa: ifaddrs = unsafe { std::mem::zeroed() };
+        ifa.ifa_next = next;
+        ifa.ifa_name = CString::new(String::from(link.ifname()))
+            .unwrap()
+            .into_raw();
+        ifa.ifa_flags = link.flags().bits();
+        if let Some(addr) = addr {
+            ifa.ifa_addr = Box::into_raw(Box::new(addr)) as *mut sockaddr;
+        }
+        Box::into_raw(Box::new(ifa))
+    }
+
+    fn new_sockaddr_ll(link: &Link) -> sockaddr_storage {
+        let mut addr: sockaddr_storage = unsafe { std::mem::zeroed() };
+        let sll = unsafe { &mut *(&mut addr as *mut _ as *mut sockaddr_ll) };
+        sll.sll_family = libc::AF_PACKET as u16;
+        sll.sll_ifindex = *link.index() as i32;
+        sll.sll_hatype = link.hwaddr().hwtype();
+        let len = usize::min(link.hwaddr().len(), sll.sll_addr.len());
+        sll.sll_halen = len as u8;
+        sll.sll_addr[..len].copy_from_slice(&link.hwaddr()[..len]);
+        addr
+    }
+
+    fn new_sockaddr_in() -> sockaddr_storage {
+        let mut addr: sockaddr_storage = unsafe { std::mem::zeroed() };
+        addr.ss_family = libc::AF_INET as u16;
+        addr
+    }
+
+    // Builds a getifaddrs(3) like list, with an AF_INET and an address-less
+    // entry after the AF_PACKET ones, so callers must filter them out.
+    pub(crate) fn ifaddrs_new(links: &[Link]) -> *mut ifaddrs {
+        let mut head = std::ptr::null_mut();
+        if let Some(link) = links.first() {
+            head = new_entry(link, None, head);
+            head = new_entry(link, Some(new_sockaddr_in()), head);
+        }
+        for link in links.iter().rev() {
+            head = new_entry(link, Some(new_sockaddr_ll(link)), head);
+        }
+        head
+    }
+
+    pub(crate) fn ifaddrs_free(ifap: *mut ifaddrs) {
+        let mut ifa = ifap;
+        while !ifa.is_null() {
+            let entry = unsafe { Box::from_raw(ifa) };
+            drop(unsafe { CString::from_raw(entry.ifa_name) });
+            if !entry.ifa_addr.is_null() {
+                drop(unsafe { Box::from_raw(entry.ifa_addr as *mut sockaddr_storage) });
+            }
+            ifa = entry.ifa_next;
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use core::fmt::{Debug, Display};
+use std::{ffi::CString, ops::Deref, ptr};
+
+const IF_NAME_SIZE: libc::size_t = libc::IFNAMSIZ;
+const IF_NAME_MIN: libc::size_t = 1;
+const IF_NAME_MAX: libc::size_t = IF_NAME_SIZE - 1;
+
+type IfNameType = [libc::c_char; IF_NAME_SIZE];
+
+// What dev_valid_name() in net/core/dev.c refuses, 0xa0 is a space to the kernel's isspace().
+const fn is_invalid_char(byte: u8) -> bool {
+    matches!(byte, b'/' | b':' | b' ' | b'\t'..=b'\r' | 0xa0)
+}
+
+const fn is_reserved(bytes: &[u8]) -> bool {
+    matches!(bytes, [b'.'] | [b'.', b'.'])
+}
+
+/// What made [`ParseIfNameError`] reject its input.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIfNameErrorKind {
+    /// The input is empty, the token is empty too.
+    TooSmall,
+    /// Longer than `IFNAMSIZ - 1` bytes, the token is what does not fit.
+    TooLarge,
+    /// The token is a NUL byte.
+    NulByte,
+    /// The token is a byte the kernel refuses in a name: `/`, `:` or a space.
+    InvalidChar,
+    /// `.` or `..`, the token is the whole input.
+    Reserved,
+}
+
+/// Error returned when building an [`IfName`] from a string.
+///
+/// `offset` is the byte position of `token` within `value`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParseIfNameError {
+    kind: ParseIfNameErrorKind,
+    value: String,
+    offset: usize,
+    token: String,
+}
+
+impl ParseIfNameError {
+    fn new(kind: ParseIfNameErrorKind, value: &str, offset: usize) -> Self {
+        let token = match kind {
+            ParseIfNameErrorKind::TooSmall => &[][..],
+            ParseIfNameErrorKind::TooLarge => &value.as_bytes()[offset..],
+            ParseIfNameErrorKind::NulByte | ParseIfNameErrorKind::InvalidChar => {
+                &value.as_bytes()[offset..offset + 1]
+            }
+            ParseIfNameErrorKind::Reserved => value.as_bytes(),
+        };
+        ParseIfNameError {
+            kind,
+            value: value.to_string(),
+            offset,
+            token: String::from_utf8_lossy(token).into_owned(),
+        }
+    }
+
+    pub fn kind(&self) -> ParseIfNameErrorKind {
+        self.kind
+    }
+
+    /// The whole input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl std::error::Error for ParseIfNameError {}
+
+impl Display for ParseIfNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct IfName(IfNameType);
+
+impl Deref for IfName {
+    type Target = IfNameType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for IfName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", String::from(self))
+    }
+}
+
+impl Display for IfName {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", String::from(self))
+    }
+}
+
+impl From<&IfName> for String {
+    fn from(value: &IfName) -> Self {
+        let c_str = unsafe { std::ffi::CStr::from_ptr(value.as_ptr()) };
+        c_str.to_bytes().escape_ascii().to_string()
+    }
+}
+
+impl From<&IfNameType> for IfName {
+    fn from(value: &IfNameType) -> Self {
+        Self(*value)
+    }
+}
+
+/// Builds an [`IfName`] from a string literal, checked at compile time.
+///
+/// ```
+/// const IFNAME: net_sys::IfName = net_sys::ifname!("en0");
+/// ```
+///
+/// ```compile_fail
+/// let ifname = net_sys::ifname!("a-name-way-too-long");
+/// ```
+#[macro_export]
+macro_rules! ifname {
+    ($value:literal) => {{
+        const IFNAME: $crate::IfName = match $crate::IfName::parse_const($value) {
+            Some(ifname) => ifname,
+            None => panic!(concat!("invalid interface name: ", $value)),
+        };
+        IFNAME
+    }};
+}
+
+impl IfName {
+    /// `const` counterpart of `try_from`.
+    pub const fn parse_const(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() < IF_NAME_MIN || bytes.len() > IF_NAME_MAX || is_reserved(bytes) {
+            return None;
+        }
+        let mut ifname: IfNameType = [0; IF_NAME_SIZE];
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 || is_invalid_char(bytes[i]) {
+                return None;
+            }
+            ifname[i] = bytes[i] as libc::c_char;
+            i += 1;
+        }
+        Some(Self(ifname))
+    }
+
+    pub(crate) fn from_bytes(value: &[u8]) -> Self {
+        let len = value.iter().position(|&c| c == 0).unwrap_or(value.len());
+        let mut ifname: IfNameType = unsafe { std::mem::zeroed() };
+        for (c, &byte) in ifname.iter_mut().zip(&value[..len.min(IF_NAME_MAX)]) {
+            *c = byte as libc::c_char;
+        }
+        Self::from(&ifname)
+    }
+}
+
+impl TryFrom<&str> for IfName {
+    type Error = ParseIfNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let c_value = match value.len() {
+            len if len < IF_NAME_MIN => {
+                return Err(ParseIfNameError::new(
+                    ParseIfNameErrorKind::TooSmall,
+                    value,
+                    len,
+                ))
+            }
+            len if len > IF_NAME_MAX => {
+                return Err(ParseIfNameError::new(
+                    ParseIfNameErrorKind::TooLarge,
+                    value,
+                    IF_NAME_MAX,
+                ))
+            }
+            _ => CString::new(value).map_err(|error| {
+                ParseIfNameError::new(ParseIfNameErrorKind::NulByte, value, error.nul_position())
+            })?,
+        };
+        if let Some(offset) = value.bytes().position(is_invalid_char) {
+            return Err(ParseIfNameError::new(
+                ParseIfNameErrorKind::InvalidChar,
+                value,
+                offset,
+            ));
+        }
+        if is_reserved(value.as_bytes()) {
+            return Err(ParseIfNameError::new(
+                ParseIfNameErrorKind::Reserved,
+                value,
+                0,
+            ));
+        }
+
+        let mut ifname: IfNameType = unsafe { std::mem::zeroed() };
+        unsafe {
+            ptr::copy_nonoverlapping(c_value.as_ptr(), ifname.as_mut_ptr(), value.len());
+        }
+        Ok(Self::from(&ifname))
+    }
+}
+
+impl TryFrom<String> for IfName {
+    type Error = ParseIfNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for IfName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IfName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        IfName::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{IfName, IfNameType, ParseIfNameErrorKind};
+
+    const IF_NAME_SIZE: usize = 16;
+    const IF_NAME: IfNameType = [
+        // '0'..'9' and 'A'..'F'
+        0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x41, 0x42, 0x43, 0x44, 0x45,
+        0x00,
+    ];
+
+    #[test]
+    fn test_ifname_len() {
+        let ifname = IfName(IF_NAME);
+
+        let len = ifname.len();
+
+        assert_eq!(len, IF_NAME_SIZE);
+    }
+
+    #[test]
+    fn test_ifname_char_values() {
+        let source = IF_NAME;
+
+        let ifname = IfName(source);
+
+        for i in 0..ifname.len() {
+            assert_eq!(ifname[i], source[i]);
+        }
+    }
+
+    #[test]
+    fn test_ifname_copy() {
+        let ifname = IfName(IF_NAME);
+
+        let copy_ifname = ifname;
+
+        for i in 0..ifname.len() {
+            assert_eq!(ifname[i], copy_ifname[i]);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::clone_on_copy)]
+    fn test_ifname_clone() {
+        let ifname = IfName(IF_NAME);
+
+        let clone_ifname = ifname.clone();
+
+        for i in 0..ifname.len() {
+            assert_eq!(ifname[i], clone_ifname[i]);
+        }
+    }
+
+    #[test]
+    fn test_ifname_partial_eq() {
+        let ifname = IfName(IF_NAME);
+        let eq_ifname = IfName(IF_NAME);
+
+        assert_eq!(ifname, eq_ifname);
+    }
+
+    #[test]
+    fn test_ifname_partial_ne() {
+        let ifname = IfName(IF_NAME);
+        let ne_ifname = IfName(unsafe { std::mem::zeroed() });
+
+        assert_ne!(ifname, ne_ifname);
+    }
+
+    #[test]
+    fn test_ifname_eq_and_hash() {
+        let ifname = IfName(IF_NAME);
+        let mut map: HashMap<IfName, &str> = HashMap::new();
+
+        map.insert(ifname, "interface");
+
+        assert_eq!(map.get(&ifname), Some(&"interface"));
+    }
+
+    #[test]
+    fn test_ifname_as_ptr() {
+        let ifname = IfName(IF_NAME);
+
+        let ifname_ptr = ifname.as_ptr();
+
+        unsafe {
+            for i in 0..ifname.len() {
+                assert_eq!(ifname.get_unchecked(i), &*ifname_ptr.add(i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ifname_as_ptr_ne_null() {
+        let ifname = IfName(IF_NAME);
+
+        let ifname_ptr = ifname.as_ptr();
+
+        assert_ne!(ifname_ptr, std::ptr::null());
+    }
+
+    #[test]
+    fn test_ifname_display() {
+        let ifname = IfName(IF_NAME);
+
+        let ifname_str = format!("{}", ifname);
+
+        assert_eq!(ifname_str, "0123456789ABCDE");
+    }
+
+    #[test]
+    fn test_ifname_debug() {
+        let ifname = IfName(IF_NAME);
+
+        let ifname_debug = format!("{:?}", ifname);
+
+        assert_eq!(ifname_debug, "\"0123456789ABCDE\"");
+    }
+
+    #[test]
+    fn test_ifname_from_octets() {
+        let source = &IF_NAME;
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::from(source);
+
+        assert_eq!(ifname, expected);
+    }
+
+    #[test]
+    fn test_ifname_from_bytes() {
+        let source = b"0123456789ABCDE\0garbage";
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::from_bytes(source);
+
+        assert_eq!(ifname, expected);
+    }
+
+    #[test]
+    fn test_ifname_from_bytes_truncated() {
+        let source = b"0123456789ABCDEF";
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::from_bytes(source);
+
+        assert_eq!(ifname, expected);
+    }
+
+    #[test]
+    fn test_ifname_from_str() {
+        let source = "0123456789ABCDE";
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::try_from(source).unwrap();
+
+        assert_eq!(ifname, expected);
+    }
+
+    #[test]
+    fn test_ifname_from_str_short() {
+        for source in ["lo", "a"] {
+            let ifname = IfName::try_from(source).unwrap();
+
+            assert_eq!(String::from(&ifname), source);
+        }
+    }
+
+    #[test]
+    fn test_ifname_from_str_length_too_large() {
+        let source = "0123456789ABCDEF";
+        let expected_error = "ParseIfNameError { kind: TooLarge, value: \"0123456789ABCDEF\", offset: 15, token: \"F\" }";
+
+        let error = IfName::try_from(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+        assert_eq!(format!("{:?}", error), expected_error);
+    }
+
+    #[test]
+    fn test_ifname_from_str_empty() {
+        let source = "";
+        let expected_error =
+            "ParseIfNameError { kind: TooSmall, value: \"\", offset: 0, token: \"\" }";
+
+        let error = IfName::try_from(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+        assert_eq!(format!("{:?}", error), expected_error);
+    }
+
+    #[test]
+    fn test_ifname_from_str_nul_error() {
+        let source = "0123456\089ABCDE";
+        let expected_error = "ParseIfNameError { kind: NulByte, value: \"0123456\\089ABCDE\", offset: 7, token: \"\\0\" }";
+
+        let error = IfName::try_from(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+        assert_eq!(format!("{:?}", error), expected_error);
+    }
+
+    #[test]
+    fn test_ifname_from_str_invalid_char() {
+        let source = "eth0:1";
+        let expected_error =
+            "ParseIfNameError { kind: InvalidChar, value: \"eth0:1\", offset: 4, token: \":\" }";
+
+        let error = IfName::try_from(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+        assert_eq!(format!("{:?}", error), expected_error);
+    }
+
+    #[test]
+    fn test_ifname_from_str_invalid_chars() {
+        for (source, offset) in [
+            ("en/0", 2),
+            ("en 0", 2),
+            ("en0\t", 3),
+            ("\nen0", 0),
+            ("enà", 3),
+        ] {
+            let error = IfName::try_from(source).unwrap_err();
+
+            assert_eq!(error.kind(), ParseIfNameErrorKind::InvalidChar);
+            assert_eq!(error.offset(), offset);
+        }
+    }
+
+    #[test]
+    fn test_ifname_from_str_error_fields() {
+        let error = IfName::try_from("usb-c-ethernet-dongle").unwrap_err();
+
+        assert_eq!(error.kind(), ParseIfNameErrorKind::TooLarge);
+        assert_eq!(error.value(), "usb-c-ethernet-dongle");
+        assert_eq!(error.offset(), 15);
+        assert_eq!(error.token(), "dongle");
+    }
+
+    #[test]
+    fn test_ifname_from_str_too_large_inside_char() {
+        let error = IfName::try_from("0123456789ABCDé").unwrap_err();
+
+        assert_eq!(error.kind(), ParseIfNameErrorKind::TooLarge);
+        assert_eq!(error.offset(), 15);
+        assert_eq!(error.token(), "\u{fffd}");
+    }
+
+    #[test]
+    fn test_ifname_from_str_reserved() {
+        for source in [".", ".."] {
+            let error = IfName::try_from(source).unwrap_err();
+
+            assert_eq!(error.kind(), ParseIfNameErrorKind::Reserved);
+            assert_eq!(error.offset(), 0);
+            assert_eq!(error.token(), source);
+        }
+    }
+
+    #[test]
+    fn test_ifname_from_str_dots() {
+        for source in ["...", ".en0", "en0."] {
+            let ifname = IfName::try_from(source).unwrap();
+
+            assert_eq!(String::from(&ifname), source);
+        }
+    }
+
+    #[test]
+    fn test_ifname_parse_const() {
+        let ifname = IfName::parse_const("enx");
+
+        assert_eq!(ifname, Some(IfName::try_from("enx").unwrap()));
+    }
+
+    #[test]
+    fn test_ifname_parse_const_invalid() {
+        for source in [
+            "",
+            "0123456789ABCDEF",
+            "en\0x",
+            "en/0",
+            "en 0",
+            "eth0:1",
+            ".",
+            "..",
+        ] {
+            assert_eq!(IfName::parse_const(source), None);
+        }
+    }
+
+    #[test]
+    fn test_ifname_parse_const_short() {
+        assert_eq!(
+            IfName::parse_const("lo"),
+            Some(IfName::try_from("lo").unwrap())
+        );
+        assert_eq!(
+            IfName::parse_const("a"),
+            Some(IfName::try_from("a").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_ifname_macro() {
+        const IFNAME: IfName = crate::ifname!("0123456789ABCDE");
+
+        assert_eq!(IFNAME, IfName(IF_NAME));
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::IfName;
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        #[test]
+        fn test_ifname_serde() {
+            let ifname = IfName::try_from("enx").unwrap();
+
+            assert_tokens(&ifname, &[Token::Str("enx")]);
+        }
+
+        #[test]
+        fn test_ifname_serde_short() {
+            let ifname = IfName::try_from("lo").unwrap();
+
+            assert_tokens(&ifname, &[Token::Str("lo")]);
+        }
+
+        #[test]
+        fn test_ifname_serde_error() {
+            assert_de_tokens_error::<IfName>(
+                &[Token::Str("")],
+                "ParseIfNameError { kind: TooSmall, value: \"\", offset: 0, token: \"\" }",
+            );
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use super::ifname::IfName;
+use crate::{IfFlags, LinkLevelAddress};
+use libc::{c_void, ifreq};
+use std::ptr;
+
+pub(super) fn new() -> ifreq {
+    unsafe { std::mem::zeroed() }
+}
+
+pub(super) fn as_mut_ptr(ifreq: &mut ifreq) -> *mut c_void {
+    ifreq as *const _ as *mut c_void
+}
+
+pub(super) fn from_mut_ptr<'a>(arg: *mut c_void) -> &'a mut ifreq {
+    unsafe { &mut *(arg as *mut ifreq) }
+}
+
+pub(super) fn set_name(ifreq: &mut ifreq, ifname: &IfName) {
+    unsafe {
+        ptr::copy_nonoverlapping(ifname.as_ptr(), ifreq.ifr_name.as_mut_ptr(), ifname.len());
+    }
+}
+
+pub(super) fn get_name(ifreq: &ifreq) -> IfName {
+    IfName::from(&ifreq.ifr_name)
+}
+
+pub(super) fn set_lladdr(ifreq: &mut ifreq, lladdr: &LinkLevelAddress) {
+    unsafe {
+        ifreq.ifr_ifru.ifru_hwaddr.sa_family = libc::ARPHRD_ETHER;
+        ptr::copy_nonoverlapping(
+            lladdr.as_ptr(),
+            ifreq.ifr_ifru.ifru_hwaddr.sa_data.as_mut_ptr() as *mut u8,
+            lladdr.len(),
+        );
+    }
+}
+
+pub(super) fn get_lladdr(ifreq: &ifreq) -> LinkLevelAddress {
+    let sa_data = unsafe { &*(&ifreq.ifr_ifru.ifru_hwaddr.sa_data as *const _ as *const [u8; 6]) };
+    LinkLevelAddress::from(sa_data)
+}
+
+/// The `ARPHRD_*` type `SIOCGIFHWADDR` reports along with the address.
+pub(super) fn get_hwtype(ifreq: &ifreq) -> u16 {
+    unsafe { ifreq.ifr_ifru.ifru_hwaddr.sa_family }
+}
+
+pub(super) fn set_data(ifreq: &mut ifreq, data: *mut c_void) {
+    ifreq.ifr_ifru.ifru_data = data as *mut libc::c_char;
+}
+
+pub(super) fn set_flags(ifreq: &mut ifreq, flags: IfFlags) {
+    ifreq.ifr_ifru.ifru_flags = flags.bits() as libc::c_short;
+}
+
+pub(super) fn get_flags(ifreq: &ifreq) -> IfFlags {
+    IfFlags::from(unsafe { ifreq.ifr_ifru.ifru_flags } as u16 as u32)
+}
+
+pub(super) fn set_mtu(ifreq: &mut ifreq, mtu: u32) {
+    ifreq.ifr_ifru.ifru_mtu = mtu as libc::c_int;
+}
+
+pub(super) fn get_mtu(ifreq: &ifreq) -> u32 {
+    unsafe { ifreq.ifr_ifru.ifru_mtu as u32 }
+}
+
+#[cfg(test)]
+mod tests {
+    use libc::{c_char, c_void};
+
+    const IFREQ_SIZE: usize = 40;
+    const NAME_SIZE: usize = 16;
+    const NAME: [c_char; NAME_SIZE] = [
+        // '0'..'9' and 'A'..'F'
+        0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x41, 0x42, 0x43, 0x44, 0x45,
+        0x00,
+    ];
+    const LADDR_SIZE: usize = 6;
+    const LLADDR: [u8; LADDR_SIZE] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    const FLAGS: libc::c_short = (libc::IFF_UP | libc::IFF_BROADCAST) as libc::c_short;
+    const MTU: u32 = 1420;
+
+    struct IfReq<'a>(&'a libc::ifreq);
+
+    impl std::fmt::Debug for IfReq<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            unsafe {
+                f.debug_struct("ifreq")
+                    .field("ifr_name", &self.0.ifr_name)
+                    .field(
+                        "ifr_ifru.ifru_hwaddr.sa_family",
+                        &self.0.ifr_ifru.ifru_hwaddr.sa_family,
+                    )
+                    .field(
+                        "ifr_ifru.ifru_hwaddr.sa_data",
+                        &self.0.ifr_ifru.ifru_hwaddr.sa_data,
+                    )
+                    .finish()
+            }
+        }
+    }
+
+    impl PartialEq for IfReq<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            let self_ptr = self.0 as *const _ as *const c_char;
+            let other_ptr = other.0 as *const _ as *const c_char;
+            for i in 0..IFREQ_SIZE {
+                unsafe {
+                    if *self_ptr.add(i) != *other_ptr.add(i) {
+                        return false;
+                    }
+                }
+            }
+            true
+        }
+    }
+
+    #[test]
+    fn test_ifreq_size() {
+        let expected_size = std::mem::size_of::<libc::ifreq>();
+
+        assert_eq!(IFREQ_SIZE, expected_size);
+    }
+
+    #[test]
+    fn test_ifreq_new() {
+        let expected_ifreq = unsafe { std::mem::zeroed() };
+
+        let ifreq = super::new();
+
+        assert_eq!(IfReq(&ifreq), IfReq(&expected_ifreq));
+    }
+
+    #[test]
+    fn test_ifreq_as_mut_ptr() {
+        let mut ifreq = super::new();
+        let exptected_ifreq_ptr = &ifreq as *const _ as *mut c_void;
+
+        let ifreq_ptr = super::as_mut_ptr(&mut ifreq);
+
+        assert_eq!(ifreq_ptr, exptected_ifreq_ptr);
+    }
+
+    #[test]
+    fn test_ifreq_from_mut_ptr() {
+        let expected_ifreq = super::new();
+        let ifreq_ptr = &expected_ifreq as *const _ as *mut c_void;
+
+        let ifreq = super::from_mut_ptr(ifreq_ptr);
+
+        assert_eq!(IfReq(ifreq), IfReq(&expected_ifreq));
+    }
+
+    #[test]
+    fn test_ifreq_set_name() {
+        let mut ifreq = super::new();
+
+        super::set_name(&mut ifreq, &super::IfName::from(&NAME));
+
+        assert_eq!(ifreq.ifr_name, NAME);
+    }
+
+    #[test]
+    fn test_ifreq_get_name() {
+        let mut ifreq = super::new();
+        unsafe {
+            std::ptr::copy_nonoverlapping(NAME.as_ptr(), ifreq.ifr_name.as_mut_ptr(), NAME.len());
+        }
+
+        let ifname = super::get_name(&ifreq);
+
+        assert_eq!(*ifname, NAME);
+    }
+
+    #[test]
+    fn test_ifreq_set_lladdr() {
+        let mut ifreq = super::new();
+
+        super::set_lladdr(&mut ifreq, &super::LinkLevelAddress::from(&LLADDR));
+
+        let (sa_family, sa_data) = unsafe {
+            (
+                ifreq.ifr_ifru.ifru_hwaddr.sa_family,
+                *(&ifreq.ifr_ifru.ifru_hwaddr.sa_data as *const _ as *const [u8; 6]),
+            )
+        };
+        assert_eq!(sa_family, libc::ARPHRD_ETHER);
+        assert_eq!(sa_data, LLADDR);
+    }
+
+    #[test]
+    fn test_ifreq_get_hwtype() {
+        let mut ifreq = super::new();
+        ifreq.ifr_ifru.ifru_hwaddr.sa_family = libc::ARPHRD_INFINIBAND;
+
+        let hwtype = super::get_hwtype(&ifreq);
+
+        assert_eq!(hwtype, libc::ARPHRD_INFINIBAND);
+    }
+
+    #[test]
+    fn test_ifreq_get_lladdr() {
+        let mut ifreq = super::new();
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                LLADDR.as_ptr(),
+                ifreq.ifr_ifru.ifru_hwaddr.sa_data.as_mut_ptr() as *mut u8,
+                LLADDR.len(),
+            );
+        }
+
+        let lladdr = super::get_lladdr(&ifreq);
+
+        assert_eq!(*lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_ifreq_set_flags() {
+        let mut ifreq = super::new();
+
+        super::set_flags(&mut ifreq, super::IfFlags::from(FLAGS as u32));
+
+        assert_eq!(unsafe { ifreq.ifr_ifru.ifru_flags }, FLAGS);
+    }
+
+    #[test]
+    fn test_ifreq_get_flags() {
+        let mut ifreq = super::new();
+        ifreq.ifr_ifru.ifru_flags = FLAGS;
+
+        let flags = super::get_flags(&ifreq);
+
+        assert_eq!(flags.bits(), FLAGS as u32);
+        assert!(flags.is_up());
+    }
+
+    #[test]
+    fn test_ifreq_set_mtu() {
+        let mut ifreq = super::new();
+
+        super::set_mtu(&mut ifreq, MTU);
+
+        assert_eq!(unsafe { ifreq.ifr_ifru.ifru_mtu }, MTU as libc::c_int);
+    }
+
+    #[test]
+    fn test_ifreq_get_mtu() {
+        let mut ifreq = super::new();
+        ifreq.ifr_ifru.ifru_mtu = MTU as libc::c_int;
+
+        let mtu = super::get_mtu(&ifreq);
+
+        assert_eq!(mtu, MTU);
+    }
+
+    #[test]
+    fn test_ifreq_set_data() {
+        let mut ifreq = super::new();
+        let mut data = [0u8; 4];
+        let data_ptr = data.as_mut_ptr() as *mut c_void;
+
+        super::set_data(&mut ifreq, data_ptr);
+
+        assert_eq!(unsafe { ifreq.ifr_ifru.ifru_data } as *mut c_void, data_ptr);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use crate::{IfFlags, IfName, LinkLevelAddress};
+
+    use super::{
+        from_mut_ptr, get_flags, get_lladdr, get_mtu, get_name, set_flags, set_lladdr, set_mtu,
+    };
+
+    pub(crate) fn ifreq_get_name(arg: *mut libc::c_void) -> IfName {
+        let ifreq = from_mut_ptr(arg);
+        get_name(ifreq)
+    }
+
+    pub(crate) fn ifreq_get_lladdr(arg: *mut libc::c_void) -> LinkLevelAddress {
+        let ifreq = from_mut_ptr(arg);
+        get_lladdr(ifreq)
+    }
+
+    pub(crate) fn ifreq_set_lladdr(arg: *mut libc::c_void, lladdr: LinkLevelAddress) {
+        let ifreq = from_mut_ptr(arg);
+        set_lladdr(ifreq, &lladdr);
+    }
+
+    pub(crate) fn ifreq_set_hwtype(arg: *mut libc::c_void, hwtype: u16) {
+        let ifreq = from_mut_ptr(arg);
+        ifreq.ifr_ifru.ifru_hwaddr.sa_family = hwtype;
+    }
+
+    pub(crate) fn ifreq_get_data(arg: *mut libc::c_void) -> *mut libc::c_void {
+        let ifreq = from_mut_ptr(arg);
+        unsafe { ifreq.ifr_ifru.ifru_data as *mut libc::c_void }
+    }
+
+    pub(crate) fn ifreq_get_flags(arg: *mut libc::c_void) -> IfFlags {
+        let ifreq = from_mut_ptr(arg);
+        get_flags(ifreq)
+    }
+
+    pub(crate) fn ifreq_set_flags(arg: *mut libc::c_void, flags: IfFlags) {
+        let ifreq = from_mut_ptr(arg);
+        set_flags(ifreq, flags);
+    }
+
+    pub(crate) fn ifreq_get_mtu(arg: *mut libc::c_void) -> u32 {
+        let ifreq = from_mut_ptr(arg);
+        get_mtu(ifreq)
+    }
+
+    pub(crate) fn ifreq_set_mtu(arg: *mut libc::c_void, mtu: u32) {
+        let ifreq = from_mut_ptr(arg);
+        set_mtu(ifreq, mtu);
+    }
+}
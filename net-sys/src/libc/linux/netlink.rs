@@ -0,0 +1,1334 @@
+use super::nlmsg::{self, NlMsg};
+use super::sys::{self, BoxSys};
+use crate::{IfFlags, IfId, LinkLevelAddress, Operation, Result};
+use std::cell::Cell;
+use std::fmt::{Debug, Display};
+use std::ops::Deref;
+
+const RECV_BUF_SIZE: usize = 32 * 1024;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    OpenRoute(libc::c_int, libc::c_int),
+    Bind(libc::c_int, u32, libc::c_int, libc::c_int),
+    GetSockName(libc::c_int, libc::c_int, libc::c_int),
+    Send(libc::c_int, libc::ssize_t, libc::c_int),
+    Recv(libc::c_int, libc::ssize_t, libc::c_int),
+    GetLinkLevelAddress(libc::c_int, IfId, libc::c_int),
+    SetLinkLevelAddress(libc::c_int, IfId, LinkLevelAddress, libc::c_int),
+    SetFlags(libc::c_int, IfId, IfFlags, libc::c_int),
+    SetMtu(libc::c_int, IfId, u32, libc::c_int),
+    GetLinks(libc::c_int, libc::c_int),
+    ResolveAltName(libc::c_int, String, libc::c_int),
+    Close(libc::c_int, libc::c_int, libc::c_int),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::OpenRoute(ret, errno) => f
+                .debug_struct("Netlink::OpenRouteError")
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::Bind(fd, groups, ret, errno) => f
+                .debug_struct("Netlink::BindError")
+                .field("fd", fd)
+                .field("groups", groups)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetSockName(fd, ret, errno) => f
+                .debug_struct("Netlink::GetSockNameError")
+                .field("fd", fd)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::Send(fd, ret, errno) => f
+                .debug_struct("Netlink::SendError")
+                .field("fd", fd)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::Recv(fd, ret, errno) => f
+                .debug_struct("Netlink::RecvError")
+                .field("fd", fd)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetLinkLevelAddress(fd, ifid, errno) => f
+                .debug_struct("Netlink::GetLinkLevelAddressError")
+                .field("fd", fd)
+                .field("ifid", ifid)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetLinkLevelAddress(fd, ifid, lladdr, errno) => f
+                .debug_struct("Netlink::SetLinkLevelAddressError")
+                .field("fd", fd)
+                .field("ifid", ifid)
+                .field("lladdr", lladdr)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetFlags(fd, ifid, flags, errno) => f
+                .debug_struct("Netlink::SetFlagsError")
+                .field("fd", fd)
+                .field("ifid", ifid)
+                .field("flags", flags)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetMtu(fd, ifid, mtu, errno) => f
+                .debug_struct("Netlink::SetMtuError")
+                .field("fd", fd)
+                .field("ifid", ifid)
+                .field("mtu", mtu)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetLinks(fd, errno) => f
+                .debug_struct("Netlink::GetLinksError")
+                .field("fd", fd)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::ResolveAltName(fd, alt_name, errno) => f
+                .debug_struct("Netlink::ResolveAltNameError")
+                .field("fd", fd)
+                .field("alt_name", alt_name)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::Close(fd, ret, errno) => f
+                .debug_struct("Netlink::CloseError")
+                .field("fd", fd)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(error: Error) -> Self {
+        match error.clone() {
+            Error::OpenRoute(_, errno)
+            | Error::Bind(_, _, _, errno)
+            | Error::GetSockName(_, _, errno) => Self::from_errno(errno, Operation::Open, error),
+            Error::Send(_, _, errno) => Self::from_errno(errno, Operation::Send, error),
+            Error::Recv(_, _, errno) => Self::from_errno(errno, Operation::Receive, error),
+            Error::GetLinkLevelAddress(_, ifid, errno) => {
+                Self::from_errno(errno, Operation::GetLinkLevelAddress, error).with_ifid(ifid)
+            }
+            Error::SetLinkLevelAddress(_, ifid, lladdr, errno) => {
+                Self::from_errno(errno, Operation::SetLinkLevelAddress, error)
+                    .with_ifid(ifid)
+                    .with_lladdr(lladdr)
+            }
+            Error::SetFlags(_, ifid, _, errno) => {
+                Self::from_errno(errno, Operation::SetFlags, error).with_ifid(ifid)
+            }
+            Error::SetMtu(_, ifid, _, errno) => {
+                Self::from_errno(errno, Operation::SetMtu, error).with_ifid(ifid)
+            }
+            Error::GetLinks(_, errno) => Self::from_errno(errno, Operation::GetLinks, error),
+            Error::ResolveAltName(_, _, errno) => {
+                Self::from_errno(errno, Operation::ResolveName, error)
+            }
+            Error::Close(_, _, errno) => Self::from_errno(errno, Operation::Close, error),
+        }
+    }
+}
+
+type NetlinkResult<'a> = Result<Box<dyn OpenNetlink + 'a>>;
+
+pub(super) trait Netlink: Debug {
+    fn open_route(&self) -> NetlinkResult<'_>;
+    fn open_route_groups(&self, groups: u32) -> NetlinkResult<'_>;
+}
+
+#[derive(Debug, Default)]
+pub(super) struct BoxNetlink(pub(super) Box<dyn Netlink>);
+
+impl Default for Box<dyn Netlink> {
+    fn default() -> Self {
+        Box::new(LibcNetlink::default())
+    }
+}
+
+impl Deref for BoxNetlink {
+    type Target = Box<dyn Netlink>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct LibcNetlink(BoxSys);
+
+impl Deref for LibcNetlink {
+    type Target = BoxSys;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl LibcNetlink {
+    // Binds to let the kernel pick the port id, the replies are addressed to it.
+    fn open(&self, groups: u32) -> NetlinkResult<'_> {
+        let fd = match self.socket(libc::AF_NETLINK, libc::SOCK_RAW, libc::NETLINK_ROUTE) {
+            fd if fd >= 0 => fd,
+            ret => {
+                let errno = self.errno();
+                return Err(crate::Error::from(Error::OpenRoute(ret, errno)).into());
+            }
+        };
+        let mut netlink = LibcOpenNetlink {
+            fd,
+            pid: 0,
+            seq: Cell::new(0),
+            sys: self,
+        };
+
+        let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = groups;
+        let mut len = std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t;
+        match self.bind(fd, &addr as *const _ as *const libc::sockaddr, len) {
+            0 => (),
+            ret => {
+                let errno = self.errno();
+                return Err(crate::Error::from(Error::Bind(fd, groups, ret, errno)).into());
+            }
+        }
+        match self.getsockname(fd, &mut addr as *mut _ as *mut libc::sockaddr, &mut len) {
+            0 => {
+                netlink.pid = addr.nl_pid;
+                Ok(Box::new(netlink))
+            }
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetSockName(fd, ret, errno)).into())
+            }
+        }
+    }
+}
+
+impl Netlink for LibcNetlink {
+    fn open_route(&self) -> NetlinkResult<'_> {
+        self.open(0)
+    }
+
+    // Subscribes to the RTMGRP_* multicast groups in `groups`.
+    fn open_route_groups(&self, groups: u32) -> NetlinkResult<'_> {
+        self.open(groups)
+    }
+}
+
+pub(super) trait OpenNetlink: Debug {
+    fn get_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()>;
+    fn set_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()>;
+    fn set_flags(&self, nlmsg: &mut NlMsg) -> Result<()>;
+    fn set_mtu(&self, nlmsg: &mut NlMsg) -> Result<()>;
+    fn get_links(&self, nlmsg: &NlMsg) -> Result<Vec<NlMsg>>;
+    fn resolve_alt_name(&self, nlmsg: &mut NlMsg) -> Result<()>;
+    fn recv_links(&self) -> Result<Vec<NlMsg>>;
+}
+
+#[derive(Debug)]
+struct LibcOpenNetlink<'a> {
+    fd: libc::c_int,
+    pid: u32,
+    seq: Cell<u32>,
+    sys: &'a BoxSys,
+}
+
+impl<'a> Deref for LibcOpenNetlink<'a> {
+    type Target = &'a BoxSys;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sys
+    }
+}
+
+impl LibcOpenNetlink<'_> {
+    // Sends `nlmsg` numbered after the previous request, returns its sequence number.
+    fn send(&self, nlmsg: &NlMsg) -> Result<u32> {
+        let fd = self.fd;
+        let seq = self.seq.get().wrapping_add(1);
+        self.seq.set(seq);
+        let mut nlmsg = nlmsg.clone();
+        nlmsg::set_seq(&mut nlmsg, seq);
+        let buf = nlmsg.as_ptr() as *const libc::c_void;
+        match self.sys.send(fd, buf, nlmsg.len(), 0) {
+            ret if ret >= 0 => Ok(seq),
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::Send(fd, ret, errno)).into())
+            }
+        }
+    }
+
+    fn recv(&self) -> Result<NlMsg> {
+        let fd = self.fd;
+        let mut nlmsg = vec![0u8; RECV_BUF_SIZE];
+        let buf = nlmsg.as_mut_ptr() as *mut libc::c_void;
+        match self.sys.recv(fd, buf, nlmsg.len(), 0) {
+            ret if ret >= 0 => {
+                nlmsg.truncate(ret as usize);
+                Ok(nlmsg)
+            }
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::Recv(fd, ret, errno)).into())
+            }
+        }
+    }
+
+    // Tells a reply to request `seq` from leftovers of earlier requests and from
+    // messages addressed to another socket.
+    fn is_reply(&self, nlmsg: &NlMsg, seq: u32) -> bool {
+        nlmsg::get_seq(nlmsg) == seq && nlmsg::get_pid(nlmsg) == self.pid
+    }
+
+    fn request(&self, nlmsg: &NlMsg) -> Result<NlMsg> {
+        let seq = self.send(nlmsg)?;
+        loop {
+            let reply = self.recv()?;
+            if reply.is_empty() {
+                return Ok(reply);
+            }
+            if let Some(reply) = nlmsg::split(&reply)
+                .into_iter()
+                .find(|reply| self.is_reply(reply, seq))
+            {
+                return Ok(reply);
+            }
+        }
+    }
+}
+
+impl OpenNetlink for LibcOpenNetlink<'_> {
+    fn get_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()> {
+        let reply = self.request(nlmsg)?;
+        match nlmsg::get_errno(&reply) {
+            None => {
+                *nlmsg = reply;
+                Ok(())
+            }
+            Some(errno) => {
+                let ifid = nlmsg::get_ifid(nlmsg);
+                Err(crate::Error::from(Error::GetLinkLevelAddress(self.fd, ifid, errno)).into())
+            }
+        }
+    }
+
+    fn set_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()> {
+        let reply = self.request(nlmsg)?;
+        match nlmsg::get_errno(&reply) {
+            None => Ok(()),
+            Some(errno) => {
+                let ifid = nlmsg::get_ifid(nlmsg);
+                let lladdr = nlmsg::get_lladdr(nlmsg);
+                Err(
+                    crate::Error::from(Error::SetLinkLevelAddress(self.fd, ifid, lladdr, errno))
+                        .into(),
+                )
+            }
+        }
+    }
+
+    fn set_flags(&self, nlmsg: &mut NlMsg) -> Result<()> {
+        let reply = self.request(nlmsg)?;
+        match nlmsg::get_errno(&reply) {
+            None => Ok(()),
+            Some(errno) => {
+                let ifid = nlmsg::get_ifid(nlmsg);
+                let flags = IfFlags::from(nlmsg::get_flags(nlmsg));
+                Err(crate::Error::from(Error::SetFlags(self.fd, ifid, flags, errno)).into())
+            }
+        }
+    }
+
+    fn set_mtu(&self, nlmsg: &mut NlMsg) -> Result<()> {
+        let reply = self.request(nlmsg)?;
+        match nlmsg::get_errno(&reply) {
+            None => Ok(()),
+            Some(errno) => {
+                let ifid = nlmsg::get_ifid(nlmsg);
+                let mtu = nlmsg::get_mtu(nlmsg);
+                Err(crate::Error::from(Error::SetMtu(self.fd, ifid, mtu, errno)).into())
+            }
+        }
+    }
+
+    fn resolve_alt_name(&self, nlmsg: &mut NlMsg) -> Result<()> {
+        let reply = self.request(nlmsg)?;
+        match nlmsg::get_errno(&reply) {
+            None => {
+                *nlmsg = reply;
+                Ok(())
+            }
+            Some(errno) => {
+                let alt_name = nlmsg::get_alt_name(nlmsg);
+                Err(crate::Error::from(Error::ResolveAltName(self.fd, alt_name, errno)).into())
+            }
+        }
+    }
+
+    // Blocks until the next batch of RTM_NEWLINK / RTM_DELLINK notifications.
+    fn recv_links(&self) -> Result<Vec<NlMsg>> {
+        let reply = self.recv()?;
+        Ok(nlmsg::split(&reply)
+            .into_iter()
+            .filter(|nlmsg| {
+                matches!(
+                    nlmsg::get_type(nlmsg),
+                    libc::RTM_NEWLINK | libc::RTM_DELLINK
+                )
+            })
+            .collect())
+    }
+
+    fn get_links(&self, nlmsg: &NlMsg) -> Result<Vec<NlMsg>> {
+        let seq = self.send(nlmsg)?;
+        let mut links = Vec::new();
+        loop {
+            let reply = self.recv()?;
+            if reply.is_empty() {
+                return Ok(links);
+            }
+            for reply in nlmsg::split(&reply) {
+                if !self.is_reply(&reply, seq) {
+                    continue;
+                }
+                match nlmsg::get_type(&reply) {
+                    libc::RTM_NEWLINK => links.push(reply),
+                    ty if ty == libc::NLMSG_DONE as u16 => return Ok(links),
+                    ty if ty == libc::NLMSG_ERROR as u16 => match nlmsg::get_errno(&reply) {
+                        None => return Ok(links),
+                        Some(errno) => {
+                            return Err(crate::Error::from(Error::GetLinks(self.fd, errno)).into())
+                        }
+                    },
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for LibcOpenNetlink<'_> {
+    fn drop(&mut self) {
+        let fd = self.fd;
+        match self.close(fd) {
+            0 => (),
+            ret => {
+                let errno = self.errno();
+                let error = Error::Close(fd, ret, errno);
+                eprintln!("Error: {:?}", error);
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sys::mock::{self, MockSys};
+    use super::{nlmsg, BoxSys, LibcNetlink, LinkLevelAddress, Netlink, Result};
+    use crate::sys::os::nlmsg::mock::{
+        nlmsg_done, nlmsg_error, nlmsg_from_link, nlmsg_new_link, nlmsg_reply, recv_reply,
+    };
+    use crate::{ErrorKind, IfFlags, IfId, IfIndex, IfName, Link, Operation};
+    use mockdown::Mockdown;
+
+    impl LibcNetlink {
+        fn new(sys: &MockSys) -> LibcNetlink {
+            LibcNetlink(BoxSys(Box::new(sys.clone())))
+        }
+    }
+
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+
+    const ALT_NAME: &str = "usb-c-ethernet-dongle";
+    const MOCK_FD: libc::c_int = 3;
+    const MOCK_PID: u32 = 4242;
+
+    const RETURN_FD: libc::c_int = MOCK_FD;
+    const RETURN_SUCCESS: libc::c_int = 0;
+    const RETURN_FAILURE: libc::c_int = -1;
+    const RETURN_LEN_FAILURE: libc::ssize_t = -1;
+
+    const MOCK_SOCKET: mock::Socket =
+        mock::Socket(libc::AF_NETLINK, libc::SOCK_RAW, libc::NETLINK_ROUTE);
+    const MOCK_CLOSE: mock::Close = mock::Close(MOCK_FD);
+
+    // What the kernel answers the first request of a socket bound to MOCK_PID with.
+    fn reply(nlmsgs: &[nlmsg::NlMsg]) -> nlmsg::NlMsg {
+        nlmsg_reply(nlmsgs, 1, MOCK_PID)
+    }
+
+    fn numbered(mut nlmsg: nlmsg::NlMsg, seq: u32) -> nlmsg::NlMsg {
+        nlmsg::set_seq(&mut nlmsg, seq);
+        nlmsg
+    }
+
+    fn open_route(sys: MockSys) -> MockSys {
+        sys.expect(|args| {
+            assert_eq!(MOCK_SOCKET, args);
+            RETURN_FD
+        })
+        .expect(|args| {
+            assert_eq!(mock::Bind(MOCK_FD, 0), args);
+            RETURN_SUCCESS
+        })
+        .expect(|args| {
+            assert_eq!(mock::GetSockName(MOCK_FD), args);
+            (RETURN_SUCCESS, MOCK_PID)
+        })
+    }
+
+    fn get_link_request() -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        nlmsg::set_name(&mut nlmsg, &IFNAME);
+        nlmsg
+    }
+
+    fn resolve_alt_name_request() -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        nlmsg::set_alt_name(&mut nlmsg, ALT_NAME);
+        nlmsg
+    }
+
+    fn get_links_request() -> nlmsg::NlMsg {
+        nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST | libc::NLM_F_DUMP)
+    }
+
+    fn get_links_reply() -> Vec<Link> {
+        let flags = IfFlags::from(libc::IFF_UP as u32);
+        vec![
+            Link::new(IFNAME, 2.into(), LLADDR, flags),
+            Link::new("wlan0".try_into().unwrap(), 3.into(), LLADDR, flags),
+        ]
+    }
+
+    fn set_link_request() -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+        nlmsg::set_name(&mut nlmsg, &IFNAME);
+        nlmsg::set_lladdr(&mut nlmsg, &LLADDR);
+        nlmsg
+    }
+
+    fn set_mtu_request() -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+        nlmsg::set_name(&mut nlmsg, &IFNAME);
+        nlmsg::set_mtu(&mut nlmsg, 1420);
+        nlmsg
+    }
+
+    fn set_flags_request() -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+        nlmsg::set_name(&mut nlmsg, &IFNAME);
+        nlmsg::set_flags(&mut nlmsg, libc::IFF_UP as u32, libc::IFF_UP as u32);
+        nlmsg
+    }
+
+    #[test]
+    fn test_netlink_box_default() {
+        let expected_default = "BoxNetlink(LibcNetlink(BoxSys(LibcSys)))";
+
+        let box_netlink = super::BoxNetlink::default();
+
+        assert_eq!(format!("{:?}", box_netlink), expected_default);
+    }
+
+    #[test]
+    fn test_netlink_box_deref() {
+        let netlink = super::LibcNetlink::default();
+        let expected_deref = "LibcNetlink(BoxSys(LibcSys))";
+
+        let deref_box_netlink = &*super::BoxNetlink(Box::new(netlink));
+
+        assert_eq!(format!("{:?}", deref_box_netlink), expected_deref);
+    }
+
+    #[test]
+    fn test_netlink_open_route() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                10
+            })
+            .expect(|args| {
+                assert_eq!(mock::Bind(10, 0), args);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(mock::GetSockName(10), args);
+                (RETURN_SUCCESS, MOCK_PID)
+            })
+            .expect(|args| {
+                assert_eq!(mock::Close(10), args);
+                RETURN_SUCCESS
+            });
+
+        let expected_open_netlink =
+            "LibcOpenNetlink { fd: 10, pid: 4242, seq: Cell { value: 0 }, sys: BoxSys(MockSys) }";
+        let netlink = LibcNetlink::new(&sys);
+
+        let open_netlink = netlink.open_route().unwrap();
+
+        assert_eq!(format!("{:?}", open_netlink), expected_open_netlink);
+    }
+
+    #[test]
+    fn test_netlink_open_route_groups() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::Bind(fd, groups)| {
+                assert_eq!((MOCK_FD, libc::RTMGRP_LINK as u32), (fd, groups));
+                RETURN_SUCCESS
+            })
+            .expect(|mock::GetSockName(_)| (RETURN_SUCCESS, MOCK_PID))
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let netlink = LibcNetlink::new(&sys);
+
+        let open_netlink = netlink.open_route_groups(libc::RTMGRP_LINK as u32).unwrap();
+
+        assert_eq!(
+            format!("{:?}", open_netlink),
+            "LibcOpenNetlink { fd: 3, pid: 4242, seq: Cell { value: 0 }, sys: BoxSys(MockSys) }"
+        );
+    }
+
+    #[test]
+    fn test_netlink_open_route_groups_bind_error() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::Bind(_, _)| RETURN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::EPERM)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::BindError { fd: 3, groups: 1, ret: -1, errno: 1, strerror: \"Operation not permitted\" }";
+        let netlink = LibcNetlink::new(&sys);
+
+        let error = netlink
+            .open_route_groups(libc::RTMGRP_LINK as u32)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+    }
+
+    #[test]
+    fn test_netlink_open_route_getsockname_error() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::Bind(_, _)| RETURN_SUCCESS)
+            .expect(|mock::GetSockName(_)| (RETURN_FAILURE, 0u32))
+            .expect(|_: mock::ErrNo| libc::ENOBUFS)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::GetSockNameError { fd: 3, ret: -1, errno: 105, strerror: \"No buffer space available\" }";
+        let netlink = LibcNetlink::new(&sys);
+
+        let error = netlink.open_route().unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.operation(), Operation::Open);
+    }
+
+    #[test]
+    fn test_netlink_open_route_error() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EPROTONOSUPPORT);
+
+        let expected_error =
+            "Netlink::OpenRouteError { ret: -1, errno: 93, strerror: \"Protocol not supported\" }";
+        let netlink = LibcNetlink::new(&sys);
+
+        let error = netlink.open_route().unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+    }
+
+    #[test]
+    fn test_open_netlink_get_lladdr() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(get_link_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                recv_reply(buf, len, &reply(&[nlmsg_new_link(IFNAME, LLADDR)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = get_link_request();
+
+        LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)?;
+
+        assert_eq!(nlmsg::get_type(&nlmsg), libc::RTM_NEWLINK);
+        assert_eq!(nlmsg::get_lladdr(&nlmsg), LLADDR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_lladdr_skips_other_replies() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                let stale = nlmsg_reply(&[nlmsg_error(libc::ENODEV)], 0, MOCK_PID);
+                recv_reply(buf, len, &stale)
+            })
+            .expect(|mock::Recv(_, buf, len)| {
+                let other = nlmsg_reply(&[nlmsg_error(libc::EPERM)], 1, MOCK_PID + 1);
+                let nlmsgs = [other, reply(&[nlmsg_new_link(IFNAME, LLADDR)])].concat();
+                recv_reply(buf, len, &nlmsgs)
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = get_link_request();
+
+        LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)?;
+
+        assert_eq!(nlmsg::get_lladdr(&nlmsg), LLADDR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_numbers_requests() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| {
+                assert_eq!(buf, numbered(set_flags_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(_, buf, len)| recv_reply(buf, len, &reply(&[nlmsg_error(0)])))
+            .expect(|mock::Send(_, buf, _)| {
+                assert_eq!(buf, numbered(set_link_request(), 2));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(_, buf, len)| {
+                let nlmsgs = [
+                    reply(&[nlmsg_error(libc::EBUSY)]),
+                    nlmsg_reply(&[nlmsg_error(0)], 2, MOCK_PID),
+                ];
+                recv_reply(buf, len, &nlmsgs.concat())
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let netlink = LibcNetlink::new(&sys);
+        let open_netlink = netlink.open_route()?;
+
+        open_netlink.set_flags(&mut set_flags_request())?;
+        open_netlink.set_lladdr(&mut set_link_request())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_lladdr_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::ENODEV)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::GetLinkLevelAddressError { fd: 3, ifid: Name(\"enx\"), errno: 19, strerror: \"No such device\" }";
+        let mut nlmsg = get_link_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_lladdr_by_index_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::ENODEV)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::GetLinkLevelAddressError { fd: 3, ifid: Index(7), errno: 19, strerror: \"No such device\" }";
+        let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        nlmsg::set_ifid(&mut nlmsg, IfId::from(IfIndex::from(7)));
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.ifid(), Some(IfId::from(IfIndex::from(7))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_resolve_alt_name() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(resolve_alt_name_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                recv_reply(buf, len, &reply(&[nlmsg_new_link(IFNAME, LLADDR)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = resolve_alt_name_request();
+
+        LibcNetlink::new(&sys)
+            .open_route()?
+            .resolve_alt_name(&mut nlmsg)?;
+
+        assert_eq!(nlmsg::get_name(&nlmsg), IFNAME);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_resolve_alt_name_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::ENODEV)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::ResolveAltNameError { fd: 3, alt_name: \"usb-c-ethernet-dongle\", errno: 19, strerror: \"No such device\" }";
+        let mut nlmsg = resolve_alt_name_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .resolve_alt_name(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_lladdr() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(set_link_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                recv_reply(buf, len, &reply(&[nlmsg_error(0)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = set_link_request();
+
+        LibcNetlink::new(&sys)
+            .open_route()?
+            .set_lladdr(&mut nlmsg)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_lladdr_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::EBUSY)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::SetLinkLevelAddressError { fd: 3, ifid: Name(\"enx\"), lladdr: \"00:11:22:33:44:55\", errno: 16, strerror: \"Device or resource busy\" }";
+        let mut nlmsg = set_link_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .set_lladdr(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Busy);
+        assert_eq!(error.ifid(), Some(IfId::from(IFNAME)));
+        assert_eq!(error.lladdr(), Some(LLADDR));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_flags() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(set_flags_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                recv_reply(buf, len, &reply(&[nlmsg_error(0)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = set_flags_request();
+
+        LibcNetlink::new(&sys).open_route()?.set_flags(&mut nlmsg)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_flags_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::EPERM)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::SetFlagsError { fd: 3, ifid: Name(\"enx\"), flags: \"UP\", errno: 1, strerror: \"Operation not permitted\" }";
+        let mut nlmsg = set_flags_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .set_flags(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_mtu() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(set_mtu_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                recv_reply(buf, len, &reply(&[nlmsg_error(0)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut nlmsg = set_mtu_request();
+
+        LibcNetlink::new(&sys).open_route()?.set_mtu(&mut nlmsg)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_set_mtu_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::EINVAL)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::SetMtuError { fd: 3, ifid: Name(\"enx\"), mtu: 1420, errno: 22, strerror: \"Invalid argument\" }";
+        let mut nlmsg = set_mtu_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .set_mtu(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_send_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, _, _)| RETURN_LEN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::ENOBUFS)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::SendError { fd: 3, ret: -1, errno: 105, strerror: \"No buffer space available\" }";
+        let mut nlmsg = get_link_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_recv_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, _, _)| RETURN_LEN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::EINTR)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Netlink::RecvError { fd: 3, ret: -1, errno: 4, strerror: \"Interrupted system call\" }";
+        let mut nlmsg = get_link_request();
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_lladdr(&mut nlmsg)
+            .unwrap_err();
+
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_close_error() {
+        let sys = open_route(MockSys::default())
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EINTR);
+
+        let netlink = LibcNetlink::new(&sys);
+
+        let open_netlink = netlink.open_route().unwrap();
+
+        drop(open_netlink);
+    }
+
+    #[test]
+    fn test_open_netlink_recv_links() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::Bind(_, _)| RETURN_SUCCESS)
+            .expect(|mock::GetSockName(_)| (RETURN_SUCCESS, MOCK_PID))
+            .expect(|mock::Recv(args, buf, len)| {
+                assert_eq!((MOCK_FD, 0), args);
+                let links = get_links_reply();
+                let mut del_link = nlmsg_from_link(&links[1]);
+                del_link[4..6].copy_from_slice(&libc::RTM_DELLINK.to_ne_bytes());
+                let reply = [nlmsg_from_link(&links[0]), nlmsg_done(), del_link].concat();
+                recv_reply(buf, len, &reply)
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let nlmsgs = LibcNetlink::new(&sys)
+            .open_route_groups(libc::RTMGRP_LINK as u32)?
+            .recv_links()?;
+
+        let links = get_links_reply();
+        assert_eq!(nlmsgs.len(), 2);
+        assert_eq!(nlmsgs[0], nlmsg_from_link(&links[0]));
+        assert_eq!(nlmsg::get_type(&nlmsgs[1]), libc::RTM_DELLINK);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_links() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(fd, buf, flags)| {
+                assert_eq!((MOCK_FD, 0), (fd, flags));
+                assert_eq!(buf, numbered(get_links_request(), 1));
+                buf.len() as isize
+            })
+            .expect(|mock::Recv(_, buf, len)| {
+                let links = get_links_reply();
+                let nlmsgs = [nlmsg_from_link(&links[0]), nlmsg_from_link(&links[1])];
+                recv_reply(buf, len, &reply(&nlmsgs))
+            })
+            .expect(|mock::Recv(_, buf, len)| recv_reply(buf, len, &reply(&[nlmsg_done()])))
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let nlmsgs = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_links(&get_links_request())?;
+
+        let links = get_links_reply();
+        assert_eq!(
+            nlmsgs,
+            vec![
+                reply(&[nlmsg_from_link(&links[0])]),
+                reply(&[nlmsg_from_link(&links[1])])
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_links_skips_other_replies() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                let links = get_links_reply();
+                let stale = nlmsg_reply(&[nlmsg_from_link(&links[1])], 0, MOCK_PID);
+                let other = nlmsg_reply(&[nlmsg_done()], 1, MOCK_PID + 1);
+                let nlmsgs = [stale, reply(&[nlmsg_from_link(&links[0])]), other].concat();
+                recv_reply(buf, len, &nlmsgs)
+            })
+            .expect(|mock::Recv(_, buf, len)| recv_reply(buf, len, &reply(&[nlmsg_done()])))
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let nlmsgs = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_links(&get_links_request())?;
+
+        assert_eq!(
+            nlmsgs,
+            vec![reply(&[nlmsg_from_link(&get_links_reply()[0])])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_netlink_get_links_error() -> Result<()> {
+        let sys = open_route(MockSys::default())
+            .expect(|mock::Send(_, buf, _)| buf.len() as isize)
+            .expect(|mock::Recv(_, buf, len)| {
+                recv_reply(buf, len, &reply(&[nlmsg_error(libc::EPERM)]))
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error =
+            "Netlink::GetLinksError { fd: 3, errno: 1, strerror: \"Operation not permitted\" }";
+
+        let error = LibcNetlink::new(&sys)
+            .open_route()?
+            .get_links(&get_links_request())
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::{Error, Netlink, NetlinkResult, NlMsg, OpenNetlink};
+    use crate::Result;
+    use mockdown::{ExpectStore, Mockdown};
+    use std::ops::Deref;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct OpenRoute();
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct OpenRouteGroups(pub u32);
+    pub(crate) type ErrNo = Option<i32>;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetLLAddr(pub *mut NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetLLAddr(pub *mut NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetFlags(pub *mut NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetMtu(pub *mut NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetLinks(pub NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct ResolveAltName(pub *mut NlMsg);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct RecvLinks();
+
+    // What a real socket fails with, for the callers that look at the errno
+    pub(crate) fn recv_error(errno: i32) -> Box<dyn std::error::Error> {
+        crate::Error::from(Error::Recv(-1, -1, errno)).into()
+    }
+
+    #[derive(Clone, Debug, Default)]
+    pub(crate) struct MockNetlink(ExpectStore);
+
+    impl Mockdown for MockNetlink {
+        fn store(&self) -> &ExpectStore {
+            &self.0
+        }
+    }
+
+    impl Netlink for MockNetlink {
+        fn open_route(&self) -> NetlinkResult<'_> {
+            let args = OpenRoute();
+            let on_mock: ErrNo = self.on_mock(args).unwrap();
+            match on_mock {
+                None => Ok(Box::new(MockOpenNetlink(self))),
+                Some(errno) => Err(crate::Error::from(Error::OpenRoute(-1, errno)).into()),
+            }
+        }
+
+        fn open_route_groups(&self, groups: u32) -> NetlinkResult<'_> {
+            let args = OpenRouteGroups(groups);
+            let on_mock: ErrNo = self.on_mock(args).unwrap();
+            match on_mock {
+                None => Ok(Box::new(MockOpenNetlink(self))),
+                Some(errno) => Err(crate::Error::from(Error::Bind(-1, groups, -1, errno)).into()),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct MockOpenNetlink<'a>(pub &'a MockNetlink);
+
+    impl<'a> Deref for MockOpenNetlink<'a> {
+        type Target = &'a MockNetlink;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl OpenNetlink for MockOpenNetlink<'_> {
+        fn get_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()> {
+            let args = GetLLAddr(nlmsg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_lladdr(&self, nlmsg: &mut NlMsg) -> Result<()> {
+            let args = SetLLAddr(nlmsg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_flags(&self, nlmsg: &mut NlMsg) -> Result<()> {
+            let args = SetFlags(nlmsg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_mtu(&self, nlmsg: &mut NlMsg) -> Result<()> {
+            let args = SetMtu(nlmsg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_links(&self, nlmsg: &NlMsg) -> Result<Vec<NlMsg>> {
+            let args = GetLinks(nlmsg.clone());
+            self.on_mock(args).unwrap()
+        }
+
+        fn resolve_alt_name(&self, nlmsg: &mut NlMsg) -> Result<()> {
+            let args = ResolveAltName(nlmsg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn recv_links(&self) -> Result<Vec<NlMsg>> {
+            let args = RecvLinks();
+            self.on_mock(args).unwrap()
+        }
+    }
+}
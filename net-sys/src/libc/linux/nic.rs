@@ -1 +1,1468 @@
+use super::ethtool::{self};
+use super::ifname::IfName;
+use super::ifreq::{self};
+#[cfg(feature = "netlink")]
+use super::netlink::BoxNetlink;
+#[cfg(feature = "netlink")]
+use super::nlmsg::{self};
+use super::socket::{BoxSocket, OpenSocket};
+use super::sysfs::{self, LinkInfo, Sysfs};
+#[cfg(feature = "netlink")]
+use super::watch::{self, Watch};
+use crate::{
+    BounceError, HardwareAddress, IfFlags, IfId, Link, LinkLevelAddress, NicBackend, Operation,
+    Result, Transaction,
+};
+use std::path::PathBuf;
 
+#[derive(Debug)]
+enum Backend {
+    Socket(BoxSocket),
+    #[cfg(feature = "netlink")]
+    Netlink(BoxNetlink),
+    Sysfs(Sysfs),
+    Custom(Box<dyn NicBackend>),
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Socket(BoxSocket::default())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Nic {
+    backend: Backend,
+    // Where backends other than sysfs read what only sysfs exposes
+    sysfs: Sysfs,
+}
+
+impl Nic {
+    /// Returns a `Nic` that talks `NETLINK_ROUTE` instead of issuing ioctls.
+    #[cfg(feature = "netlink")]
+    pub fn netlink() -> Nic {
+        Nic {
+            backend: Backend::Netlink(BoxNetlink::default()),
+            sysfs: Sysfs::default(),
+        }
+    }
+
+    /// Returns a read-only `Nic` backed by `/sys/class/net`.
+    pub fn sysfs() -> Nic {
+        Nic {
+            backend: Backend::Sysfs(Sysfs::default()),
+            sysfs: Sysfs::default(),
+        }
+    }
+
+    /// Same as [`Nic::sysfs`], but reads the tree found under `root`.
+    pub fn sysfs_at(root: impl Into<PathBuf>) -> Nic {
+        Nic {
+            backend: Backend::Sysfs(Sysfs::new(root)),
+            sysfs: Sysfs::default(),
+        }
+    }
+
+    /// Returns a `Nic` that hands every operation to `backend`, such as a
+    /// fake for tests. Alt names are still looked up over netlink, and
+    /// [`Nic::info`] still reads sysfs.
+    pub fn with_backend(backend: impl NicBackend + 'static) -> Nic {
+        Nic {
+            backend: Backend::Custom(Box::new(backend)),
+            sysfs: Sysfs::default(),
+        }
+    }
+
+    pub fn get_lladd(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_lladdr(&ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+
+                netlink.open_route()?.get_lladdr(&mut nlmsg)?;
+
+                Ok(nlmsg::get_lladdr(&nlmsg))
+            }
+            Backend::Sysfs(sysfs) => sysfs.get_lladdr(ifid),
+            Backend::Custom(backend) => backend.get_lladd(ifid),
+        }
+    }
+
+    /// Returns the address at its full length along with its `ARPHRD_*`
+    /// type, for links such as InfiniBand that `get_lladd` cannot describe.
+    /// `SIOCGIFHWADDR` does not report the length, so the socket backend
+    /// reads anything but Ethernet and loopback addresses from sysfs.
+    pub fn get_hwaddr(&self, ifid: impl Into<IfId>) -> Result<HardwareAddress> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                match ifreq::get_hwtype(&ifreq) {
+                    hwtype @ (libc::ARPHRD_ETHER | libc::ARPHRD_LOOPBACK) => {
+                        HardwareAddress::new(hwtype, &ifreq::get_lladdr(&ifreq)[..])
+                    }
+                    _ => self.sysfs.get_hwaddr(ifid),
+                }
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+
+                netlink.open_route()?.get_lladdr(&mut nlmsg)?;
+
+                nlmsg::get_hwaddr(&nlmsg)
+            }
+            Backend::Sysfs(sysfs) => sysfs.get_hwaddr(ifid),
+            Backend::Custom(backend) => backend.get_hwaddr(ifid),
+        }
+    }
+
+    pub fn set_lladd(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        self.set_lladd_unchecked(ifid.into(), lladdr)
+    }
+
+    // Also puts back an address the interface had, which may not be one
+    // `check_assignable` lets through.
+    pub(crate) fn set_lladd_unchecked(&self, ifid: IfId, lladdr: &LinkLevelAddress) -> Result<()> {
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_lladdr(&mut ifreq, lladdr);
+
+                socket
+                    .open_local_dgram()?
+                    .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg =
+                    nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+                nlmsg::set_lladdr(&mut nlmsg, lladdr);
+
+                netlink.open_route()?.set_lladdr(&mut nlmsg)
+            }
+            Backend::Sysfs(_) => sysfs::unsupported(Operation::SetLinkLevelAddress, ifid),
+            Backend::Custom(backend) => backend.set_lladd(ifid, lladdr),
+        }
+    }
+
+    /// Changes the address with the interface taken down first, as many
+    /// drivers reject the change with `EBUSY` while the link is up. The
+    /// previous admin state is restored whether or not the change succeeds,
+    /// failing with a [`BounceError`] when both the change and the restore
+    /// fail.
+    pub fn set_lladd_bounce(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        self.set_lladd_bounce_unchecked(ifid.into(), lladdr)
+    }
+
+    pub(crate) fn set_lladd_bounce_unchecked(
+        &self,
+        ifid: IfId,
+        lladdr: &LinkLevelAddress,
+    ) -> Result<()> {
+        if !self.get_flags(ifid)?.is_up() {
+            return self.set_lladd_unchecked(ifid, lladdr);
+        }
+
+        self.set_down(ifid)?;
+        let result = self.set_lladd_unchecked(ifid, lladdr);
+        match (result, self.set_up(ifid)) {
+            (Ok(()), restore) => restore,
+            (Err(error), Ok(())) => Err(error),
+            (Err(error), Err(restore_error)) => {
+                Err(BounceError::new(ifid, error, restore_error).into())
+            }
+        }
+    }
+
+    pub fn get_flags(&self, ifid: impl Into<IfId>) -> Result<IfFlags> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_flags(&ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+
+                netlink.open_route()?.get_lladdr(&mut nlmsg)?;
+
+                Ok(IfFlags::from(nlmsg::get_flags(&nlmsg)))
+            }
+            Backend::Sysfs(sysfs) => sysfs.get_flags(ifid),
+            Backend::Custom(backend) => backend.get_flags(ifid),
+        }
+    }
+
+    pub fn set_up(&self, ifid: impl Into<IfId>) -> Result<()> {
+        self.set_admin(ifid.into(), true)
+    }
+
+    pub fn set_down(&self, ifid: impl Into<IfId>) -> Result<()> {
+        self.set_admin(ifid.into(), false)
+    }
+
+    fn set_admin(&self, ifid: IfId, up: bool) -> Result<()> {
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                let socket = socket.open_local_dgram()?;
+                socket.get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                let flags = ifreq::get_flags(&ifreq).bits();
+                let flags = match up {
+                    true => flags | libc::IFF_UP as u32,
+                    false => flags & !(libc::IFF_UP as u32),
+                };
+                ifreq::set_flags(&mut ifreq, IfFlags::from(flags));
+
+                socket.set_flags(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let flags = match up {
+                    true => libc::IFF_UP as u32,
+                    false => 0,
+                };
+                let mut nlmsg =
+                    nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+                nlmsg::set_flags(&mut nlmsg, flags, libc::IFF_UP as u32);
+
+                netlink.open_route()?.set_flags(&mut nlmsg)
+            }
+            Backend::Sysfs(_) => sysfs::unsupported(Operation::SetFlags, ifid),
+            Backend::Custom(backend) => backend.set_admin(ifid, up),
+        }
+    }
+
+    pub fn get_mtu(&self, ifid: impl Into<IfId>) -> Result<u32> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_mtu(&ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+
+                netlink.open_route()?.get_lladdr(&mut nlmsg)?;
+
+                Ok(nlmsg::get_mtu(&nlmsg))
+            }
+            Backend::Sysfs(sysfs) => sysfs.get_mtu(ifid),
+            Backend::Custom(backend) => backend.get_mtu(ifid),
+        }
+    }
+
+    pub fn set_mtu(&self, ifid: impl Into<IfId>, mtu: u32) -> Result<()> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_mtu(&mut ifreq, mtu);
+
+                socket
+                    .open_local_dgram()?
+                    .set_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg =
+                    nlmsg::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+                nlmsg::set_mtu(&mut nlmsg, mtu);
+
+                netlink.open_route()?.set_mtu(&mut nlmsg)
+            }
+            Backend::Sysfs(_) => sysfs::unsupported(Operation::SetMtu, ifid),
+            Backend::Custom(backend) => backend.set_mtu(ifid, mtu),
+        }
+    }
+
+    /// Returns the address the NIC shipped with, regardless of any address
+    /// set since. Devices without one report `00:00:00:00:00:00`.
+    pub fn get_permanent_lladdr(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut perm_addr = ethtool::new();
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_data(&mut ifreq, ethtool::as_mut_ptr(&mut perm_addr));
+
+                socket
+                    .open_local_dgram()?
+                    .get_perm_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ethtool::get_lladdr(&perm_addr))
+            }
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+                nlmsg::set_ifid(&mut nlmsg, ifid);
+
+                netlink.open_route()?.get_lladdr(&mut nlmsg)?;
+
+                Ok(nlmsg::get_perm_lladdr(&nlmsg))
+            }
+            Backend::Sysfs(_) => sysfs::unsupported(Operation::GetPermanentLinkLevelAddress, ifid),
+            Backend::Custom(backend) => backend.get_permanent_lladdr(ifid),
+        }
+    }
+
+    /// Writes the permanent address back, undoing any `set_lladd`, and
+    /// returns it. Interfaces without one, such as most virtual ones, fail
+    /// with `ErrorKind::Unsupported`.
+    pub fn reset_lladdr(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        let lladdr = self.get_permanent_lladdr(ifid)?;
+        lladdr.check_permanent(ifid)?;
+        self.set_lladd_unchecked(ifid, &lladdr)?;
+        Ok(lladdr)
+    }
+
+    pub fn list(&self) -> Result<Vec<Link>> {
+        match &self.backend {
+            Backend::Socket(socket) => socket.get_links(),
+            #[cfg(feature = "netlink")]
+            Backend::Netlink(netlink) => {
+                let nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST | libc::NLM_F_DUMP);
+
+                let links = netlink.open_route()?.get_links(&nlmsg)?;
+
+                Ok(links.iter().map(|nlmsg| nlmsg::get_link(nlmsg)).collect())
+            }
+            Backend::Sysfs(sysfs) => sysfs.get_links(),
+            Backend::Custom(backend) => backend.list(),
+        }
+    }
+
+    /// Opens a session that runs every call over one socket, for callers
+    /// that touch many interfaces in a row. The socket backend opens its
+    /// descriptor here and closes it when the session is dropped, other
+    /// backends behave as the `Nic` does.
+    pub fn session(&self) -> Result<Session<'_>> {
+        let socket = match &self.backend {
+            Backend::Socket(socket) => Some(socket.open_local_dgram()?),
+            _ => None,
+        };
+        Ok(Session { nic: self, socket })
+    }
+
+    /// Starts a batch of address changes that are undone if one of them
+    /// fails, see [`Transaction`].
+    pub fn transaction(&self) -> Transaction<'_> {
+        Transaction::new(self)
+    }
+
+    /// Reads the details only sysfs exposes: how the address was assigned,
+    /// the hardware type, operational state, carrier and bound driver.
+    /// Backends other than sysfs read them from `/sys/class/net`.
+    pub fn info(&self, ifid: impl Into<IfId>) -> Result<LinkInfo> {
+        match &self.backend {
+            Backend::Sysfs(sysfs) => sysfs.get_info(ifid.into()),
+            _ => self.sysfs.get_info(ifid.into()),
+        }
+    }
+
+    /// Turns a user-supplied name into an `IfId`. Names that fit `IFNAMSIZ`
+    /// are used as-is, since the kernel matches alt names wherever it takes
+    /// an interface name. Longer alt names are looked up over netlink and
+    /// resolve to the kernel name.
+    pub fn resolve(&self, name: &str) -> Result<IfId> {
+        #[cfg(feature = "netlink")]
+        if name.len() >= libc::IFNAMSIZ {
+            let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+            nlmsg::set_alt_name(&mut nlmsg, name);
+
+            self.with_netlink(|netlink| netlink.open_route()?.resolve_alt_name(&mut nlmsg))?;
+
+            return Ok(IfId::from(nlmsg::get_name(&nlmsg)));
+        }
+
+        Ok(IfId::from(IfName::try_from(name)?))
+    }
+
+    /// Lists the alternative names given to the interface with
+    /// `ip link property add`.
+    #[cfg(feature = "netlink")]
+    pub fn get_alt_names(&self, ifid: impl Into<IfId>) -> Result<Vec<String>> {
+        let mut nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        nlmsg::set_ifid(&mut nlmsg, ifid.into());
+
+        self.with_netlink(|netlink| netlink.open_route()?.get_lladdr(&mut nlmsg))?;
+
+        Ok(nlmsg::get_alt_names(&nlmsg))
+    }
+
+    /// Subscribes to link notifications and yields a [`crate::LinkEvent`]
+    /// for every link added, removed, renamed, re-addressed, or brought up
+    /// or down. Needs the netlink backend, see [`Nic::netlink`].
+    #[cfg(feature = "netlink")]
+    pub fn watch(&self) -> Result<Watch<'_>> {
+        match &self.backend {
+            Backend::Netlink(netlink) => Watch::new(netlink),
+            Backend::Socket(_) => watch::unsupported("Socket"),
+            Backend::Sysfs(_) => watch::unsupported("Sysfs"),
+            Backend::Custom(_) => watch::unsupported("Custom"),
+        }
+    }
+
+    // Alt names are only reachable over netlink, whatever the backend.
+    #[cfg(feature = "netlink")]
+    fn with_netlink<T>(&self, f: impl FnOnce(&BoxNetlink) -> Result<T>) -> Result<T> {
+        match &self.backend {
+            Backend::Netlink(netlink) => f(netlink),
+            _ => f(&BoxNetlink::default()),
+        }
+    }
+}
+
+/// Batch of `Nic` calls sharing one socket, returned by [`Nic::session`].
+#[derive(Debug)]
+pub struct Session<'a> {
+    nic: &'a Nic,
+    socket: Option<Box<dyn OpenSocket + 'a>>,
+}
+
+impl Session<'_> {
+    pub fn get_lladd(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        match (&self.nic.backend, &self.socket) {
+            (Backend::Socket(socket), Some(open_socket)) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                open_socket.get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_lladdr(&ifreq))
+            }
+            _ => self.nic.get_lladd(ifid),
+        }
+    }
+
+    pub fn set_lladd(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        let ifid = ifid.into();
+        match (&self.nic.backend, &self.socket) {
+            (Backend::Socket(socket), Some(open_socket)) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_lladdr(&mut ifreq, lladdr);
+
+                open_socket.set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            _ => self.nic.set_lladd(ifid, lladdr),
+        }
+    }
+
+    /// Reads the address of every interface, in order. Each interface gets
+    /// its own result, as links may come and go while polling.
+    pub fn get_many(&self, ifnames: &[IfName]) -> Vec<Result<LinkLevelAddress>> {
+        ifnames
+            .iter()
+            .map(|ifname| self.get_lladd(ifname))
+            .collect()
+    }
+
+    /// Sets every address in order and stops at the first failure, leaving
+    /// the earlier changes in place. All addresses are checked to be
+    /// assignable before any is set.
+    pub fn apply(&self, changes: &[(IfName, LinkLevelAddress)]) -> Result<()> {
+        for (_, lladdr) in changes {
+            lladdr.check_assignable()?;
+        }
+        for (ifname, lladdr) in changes {
+            self.set_lladd(ifname, lladdr)?;
+        }
+        Ok(())
+    }
+}
+
+fn get_name(socket: &BoxSocket, ifid: IfId) -> Result<IfName> {
+    match ifid {
+        IfId::Name(ifname) => Ok(ifname),
+        IfId::Index(ifindex) => socket.get_name(ifindex),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Backend, BoxSocket, IfName, Nic, Sysfs};
+    use crate::sys::os::ethtool::mock::ethtool_set_lladdr;
+    use crate::sys::os::ifreq::mock::{
+        ifreq_get_data, ifreq_get_flags, ifreq_get_lladdr, ifreq_get_mtu, ifreq_get_name,
+        ifreq_set_flags, ifreq_set_hwtype, ifreq_set_lladdr, ifreq_set_mtu,
+    };
+    use crate::sys::os::socket::mock::{self, ErrNo, MockSocket};
+    use crate::sys::os::sysfs::mock::SysfsTree;
+    use crate::{
+        BounceError, ErrorKind, IfFlags, IfId, IfIndex, Link, LinkLevelAddress, Operation, Result,
+    };
+    use mockdown::Mockdown;
+
+    impl Nic {
+        fn new(socket: &MockSocket) -> Nic {
+            Nic {
+                backend: Backend::Socket(BoxSocket(Box::new(socket.clone()))),
+                sysfs: Sysfs::default(),
+            }
+        }
+
+        fn with_sysfs(self, root: &std::path::Path) -> Nic {
+            Nic {
+                sysfs: Sysfs::new(root),
+                ..self
+            }
+        }
+    }
+
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+    const PERM_LLADDR: LinkLevelAddress = crate::lladdr!("66:77:88:99:aa:bb");
+
+    #[test]
+    fn test_nic_default() {
+        let expected_default = "Nic { backend: Socket(BoxSocket(LibcSocket(BoxSys(LibcSys)))), sysfs: Sysfs { root: \"/sys/class/net\" } }";
+
+        let nic = super::Nic::default();
+
+        assert_eq!(format!("{:?}", nic), expected_default);
+    }
+
+    #[test]
+    fn test_get_lladd() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::new(&socket).get_lladd(IFNAME).unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_get_lladd_by_index() {
+        let socket = MockSocket::default()
+            .expect(|mock::GetName(ifindex)| {
+                assert_eq!(ifindex, IfIndex::from(2));
+                Result::Ok(IFNAME)
+            })
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::new(&socket).get_lladd(IfIndex::from(2)).unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_get_lladd_by_index_error() {
+        let socket = MockSocket::default()
+            .expect(|mock::GetName(_)| Result::<IfName>::Err("IndexToNameError".into()));
+
+        let error = Nic::new(&socket).get_lladd(IfIndex::from(2)).unwrap_err();
+
+        assert_eq!(format!("{}", error), "IndexToNameError");
+    }
+
+    #[test]
+    fn test_get_hwaddr() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+
+        let hwaddr = Nic::new(&socket).get_hwaddr(IFNAME).unwrap();
+
+        assert!(hwaddr.is_ether());
+        assert_eq!(hwaddr[..], LLADDR[..]);
+    }
+
+    #[test]
+    fn test_get_hwaddr_falls_back_to_sysfs() {
+        let tree = SysfsTree::new("get_hwaddr_fallback")
+            .with_link("enx", 2, "00:11:22:33:44:55:66:77")
+            .with_attr("enx", "type", "32\n");
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                ifreq_set_hwtype(ifreq, libc::ARPHRD_INFINIBAND);
+                Result::Ok(())
+            });
+
+        let hwaddr = Nic::new(&socket)
+            .with_sysfs(tree.root())
+            .get_hwaddr(IFNAME)
+            .unwrap();
+
+        assert_eq!(hwaddr.hwtype(), libc::ARPHRD_INFINIBAND);
+        assert_eq!(hwaddr.to_string(), "00:11:22:33:44:55:66:77");
+    }
+
+    #[test]
+    fn test_list() {
+        let socket = MockSocket::default().expect(|mock::GetLinks()| {
+            Result::Ok(vec![Link::new(
+                IFNAME,
+                2.into(),
+                LLADDR,
+                IfFlags::default(),
+            )])
+        });
+
+        let links = Nic::new(&socket).list().unwrap();
+
+        assert_eq!(
+            links,
+            vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::default())]
+        );
+    }
+
+    #[test]
+    fn test_set_lladd() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_lladd(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_multicast_error() {
+        let socket = MockSocket::default();
+        let lladdr = "01:00:5e:00:00:01".parse().unwrap();
+
+        let error = Nic::new(&socket).set_lladd(IFNAME, &lladdr).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "LinkLevelAddress::UnassignableError { lladdr: \"01:00:5e:00:00:01\", reason: \"multicast\" }"
+        );
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, crate::ErrorKind::InvalidAddress);
+    }
+
+    #[test]
+    fn test_get_flags() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                Result::Ok(())
+            });
+
+        let flags = Nic::new(&socket).get_flags(IFNAME).unwrap();
+
+        assert!(flags.is_up());
+    }
+
+    #[test]
+    fn test_get_mtu() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetMtu(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_mtu(ifreq, 1420);
+                Result::Ok(())
+            });
+
+        let mtu = Nic::new(&socket).get_mtu(IFNAME).unwrap();
+
+        assert_eq!(mtu, 1420);
+    }
+
+    #[test]
+    fn test_set_mtu() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetMtu(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_mtu(ifreq), 1420);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_mtu(IFNAME, 1420).unwrap();
+    }
+
+    #[test]
+    fn test_set_up() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_BROADCAST as u32));
+                Result::Ok(())
+            })
+            .expect(|mock::SetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                let flags = (libc::IFF_UP | libc::IFF_BROADCAST) as u32;
+                assert_eq!(ifreq_get_flags(ifreq), IfFlags::from(flags));
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_up(IFNAME).unwrap();
+    }
+
+    #[test]
+    fn test_set_down() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                let flags = (libc::IFF_UP | libc::IFF_BROADCAST) as u32;
+                ifreq_set_flags(ifreq, IfFlags::from(flags));
+                Result::Ok(())
+            })
+            .expect(|mock::SetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                let flags = libc::IFF_BROADCAST as u32;
+                assert_eq!(ifreq_get_flags(ifreq), IfFlags::from(flags));
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_down(IFNAME).unwrap();
+    }
+
+    fn expect_flags(socket: MockSocket, up: bool) -> MockSocket {
+        let socket = socket.expect(|mock::OpenLocalDgram()| ErrNo::None);
+        match up {
+            true => socket.expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                Result::Ok(())
+            }),
+            false => socket.expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::default());
+                Result::Ok(())
+            }),
+        }
+    }
+
+    #[test]
+    fn test_set_lladd_bounce() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(ifreq)| {
+                assert!(!ifreq_get_flags(ifreq).is_up());
+                Result::Ok(())
+            })
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+        let socket = expect_flags(socket, false).expect(|mock::SetFlags(ifreq)| {
+            assert!(ifreq_get_flags(ifreq).is_up());
+            Result::Ok(())
+        });
+
+        Nic::new(&socket).set_lladd_bounce(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_when_down() {
+        let socket = expect_flags(MockSocket::default(), false)
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_lladd_bounce(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_restores_on_error() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(_)| Result::Ok(()))
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| Result::<()>::Err("SetLinkLevelAddressError".into()));
+        let socket = expect_flags(socket, false).expect(|mock::SetFlags(ifreq)| {
+            assert!(ifreq_get_flags(ifreq).is_up());
+            Result::Ok(())
+        });
+
+        let error = Nic::new(&socket)
+            .set_lladd_bounce(IFNAME, &LLADDR)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error), "SetLinkLevelAddressError");
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_restore_error() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(_)| Result::Ok(()))
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| Result::<()>::Err("SetLinkLevelAddressError".into()));
+        let socket = expect_flags(socket, false)
+            .expect(|mock::SetFlags(_)| Result::<()>::Err("SetFlagsError".into()));
+
+        let error = Nic::new(&socket)
+            .set_lladd_bounce(IFNAME, &LLADDR)
+            .unwrap_err();
+
+        let error = error.downcast_ref::<BounceError>().unwrap();
+        assert_eq!(error.ifid(), IfId::from(IFNAME));
+        assert_eq!(error.error().kind(), ErrorKind::Other);
+        assert_eq!(error.error().operation(), Operation::SetLinkLevelAddress);
+        assert_eq!(
+            format!("{}", std::error::Error::source(error.error()).unwrap()),
+            "SetLinkLevelAddressError"
+        );
+        assert_eq!(format!("{}", error.restore_error()), "SetFlagsError");
+    }
+
+    #[test]
+    fn test_get_permanent_lladdr() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetPermLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ethtool_set_lladdr(ifreq_get_data(ifreq), PERM_LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::new(&socket).get_permanent_lladdr(IFNAME).unwrap();
+
+        assert_eq!(lladdr, PERM_LLADDR);
+    }
+
+    #[test]
+    fn test_reset_lladdr() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetPermLLAddr(ifreq)| {
+                ethtool_set_lladdr(ifreq_get_data(ifreq), PERM_LLADDR);
+                Result::Ok(())
+            })
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), PERM_LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::new(&socket).reset_lladdr(IFNAME).unwrap();
+
+        assert_eq!(lladdr, PERM_LLADDR);
+    }
+
+    #[test]
+    fn test_reset_lladdr_no_permanent_address() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetPermLLAddr(ifreq)| {
+                ethtool_set_lladdr(ifreq_get_data(ifreq), crate::lladdr!("00:00:00:00:00:00"));
+                Result::Ok(())
+            });
+
+        let error = Nic::new(&socket).reset_lladdr(IFNAME).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "LinkLevelAddress::NoPermanentAddressError { ifid: Name(\"enx\"), lladdr: \"00:00:00:00:00:00\" }"
+        );
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.ifid(), Some(IfId::from(IFNAME)));
+    }
+
+    #[test]
+    fn test_reset_lladdr_error() {
+        let socket = MockSocket::default().expect(|mock::OpenLocalDgram()| Some(libc::EMFILE));
+
+        let error = Nic::new(&socket).reset_lladdr(IFNAME).unwrap_err();
+
+        assert_eq!(
+            format!("{:?}", error.source().unwrap()),
+            "Socket::OpenLocalDgramError { ret: -1, errno: 24, strerror: \"Too many open files\" }"
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let ifid = Nic::new(&MockSocket::default()).resolve("enx").unwrap();
+
+        assert_eq!(ifid, IfId::from(IFNAME));
+    }
+
+    #[cfg(feature = "netlink")]
+    #[test]
+    fn test_watch_unsupported() {
+        let error = Nic::new(&MockSocket::default()).watch().unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "Watch::UnsupportedBackendError { backend: \"Socket\" }"
+        );
+    }
+
+    #[cfg(not(feature = "netlink"))]
+    #[test]
+    fn test_resolve_alt_name_without_netlink() {
+        let error = Nic::new(&MockSocket::default())
+            .resolve("usb-c-ethernet-dongle")
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "ParseIfNameError { kind: TooLarge, value: \"usb-c-ethernet-dongle\", offset: 15, token: \"dongle\" }"
+        );
+    }
+
+    #[test]
+    fn test_session_get_many() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            })
+            .expect(|mock::GetLLAddr(_)| -> Result<()> { Err("GetLinkLevelAddressError".into()) })
+            .expect(|mock::GetLLAddr(ifreq)| {
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+        let nic = Nic::new(&socket);
+
+        let lladdrs = nic.session().unwrap().get_many(&[IFNAME; 3]);
+
+        assert_eq!(lladdrs.len(), 3);
+        assert_eq!(*lladdrs[0].as_ref().unwrap(), LLADDR);
+        assert_eq!(
+            format!("{}", lladdrs[1].as_ref().unwrap_err()),
+            "GetLinkLevelAddressError"
+        );
+        assert_eq!(*lladdrs[2].as_ref().unwrap(), LLADDR);
+    }
+
+    #[test]
+    fn test_session_apply() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            })
+            .expect(|mock::GetName(ifindex)| {
+                assert_eq!(ifindex, IfIndex::from(2));
+                Result::Ok(IFNAME)
+            })
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), PERM_LLADDR);
+                Result::Ok(())
+            });
+        let nic = Nic::new(&socket);
+        let session = nic.session().unwrap();
+
+        session.apply(&[(IFNAME, LLADDR)]).unwrap();
+        session.set_lladd(IfIndex::from(2), &PERM_LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_session_apply_stops_at_error() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| -> Result<()> { Err("SetLinkLevelAddressError".into()) });
+        let nic = Nic::new(&socket);
+
+        let error = nic
+            .session()
+            .unwrap()
+            .apply(&[(IFNAME, LLADDR), (IFNAME, PERM_LLADDR)])
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error), "SetLinkLevelAddressError");
+    }
+
+    #[test]
+    fn test_session_apply_unassignable() {
+        let socket = MockSocket::default().expect(|mock::OpenLocalDgram()| ErrNo::None);
+        let nic = Nic::new(&socket);
+        let multicast = crate::lladdr!("01:00:5e:00:00:01");
+
+        let error = nic
+            .session()
+            .unwrap()
+            .apply(&[(IFNAME, LLADDR), (IFNAME, multicast)])
+            .unwrap_err();
+
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, crate::ErrorKind::InvalidAddress);
+    }
+
+    #[test]
+    fn test_session_open_error() {
+        let socket = MockSocket::default().expect(|mock::OpenLocalDgram()| Some(libc::EMFILE));
+
+        let error = Nic::new(&socket).session().unwrap_err();
+
+        let operation = error.downcast_ref::<crate::Error>().unwrap().operation();
+        assert_eq!(operation, crate::Operation::Open);
+    }
+
+    #[cfg(feature = "netlink")]
+    mod netlink {
+        use super::{Backend, IfName, Nic, Sysfs, IFNAME, LLADDR, PERM_LLADDR};
+        use crate::sys::os::netlink::mock::{self, ErrNo, MockNetlink};
+        use crate::sys::os::netlink::BoxNetlink;
+        use crate::sys::os::nlmsg::mock::{
+            nlmsg_from_link, nlmsg_get_alt_name, nlmsg_get_flags, nlmsg_get_index,
+            nlmsg_get_lladdr, nlmsg_get_mtu, nlmsg_get_name, nlmsg_new_link, nlmsg_set_alt_names,
+            nlmsg_set_flags, nlmsg_set_hwaddr, nlmsg_set_lladdr, nlmsg_set_mtu,
+            nlmsg_set_perm_lladdr, nlmsg_set_reply,
+        };
+        use crate::sys::os::nlmsg::{self};
+        use crate::{IfFlags, IfId, IfIndex, Link, Result};
+        use mockdown::Mockdown;
+
+        impl Nic {
+            fn new_netlink(netlink: &MockNetlink) -> Nic {
+                Nic {
+                    backend: Backend::Netlink(BoxNetlink(Box::new(netlink.clone()))),
+                    sysfs: Sysfs::default(),
+                }
+            }
+        }
+
+        #[test]
+        fn test_nic_netlink() {
+            let expected_debug =
+                "Nic { backend: Netlink(BoxNetlink(LibcNetlink(BoxSys(LibcSys)))), sysfs: Sysfs { root: \"/sys/class/net\" } }";
+
+            let nic = Nic::netlink();
+
+            assert_eq!(format!("{:?}", nic), expected_debug);
+        }
+
+        #[test]
+        fn test_get_lladd() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    nlmsg_set_lladdr(nlmsg, LLADDR);
+                    Result::Ok(())
+                });
+
+            let lladdr = Nic::new_netlink(&netlink).get_lladd(IFNAME).unwrap();
+
+            assert_eq!(lladdr, LLADDR);
+        }
+
+        #[test]
+        fn test_session_get_many() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    nlmsg_set_lladdr(nlmsg, LLADDR);
+                    Result::Ok(())
+                })
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    nlmsg_set_lladdr(nlmsg, PERM_LLADDR);
+                    Result::Ok(())
+                });
+            let nic = Nic::new_netlink(&netlink);
+
+            let lladdrs = nic.session().unwrap().get_many(&[IFNAME, IFNAME]);
+
+            assert_eq!(*lladdrs[0].as_ref().unwrap(), LLADDR);
+            assert_eq!(*lladdrs[1].as_ref().unwrap(), PERM_LLADDR);
+        }
+
+        #[test]
+        fn test_get_lladd_by_index() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_index(nlmsg), 2);
+                    nlmsg_set_lladdr(nlmsg, LLADDR);
+                    Result::Ok(())
+                });
+
+            let lladdr = Nic::new_netlink(&netlink)
+                .get_lladd(IfIndex::from(2))
+                .unwrap();
+
+            assert_eq!(lladdr, LLADDR);
+        }
+
+        #[test]
+        fn test_get_lladd_open_route_error() {
+            let netlink = MockNetlink::default().expect(|mock::OpenRoute()| Some(libc::EACCES));
+
+            let error = Nic::new_netlink(&netlink).get_lladd(IFNAME).unwrap_err();
+
+            assert_eq!(
+                format!("{:?}", error.source().unwrap()),
+                "Netlink::OpenRouteError { ret: -1, errno: 13, strerror: \"Permission denied\" }"
+            );
+        }
+
+        #[test]
+        fn test_set_lladd() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::SetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    assert_eq!(nlmsg_get_lladdr(nlmsg), LLADDR);
+                    Result::Ok(())
+                });
+
+            let ifname: &IfName = &IFNAME;
+            Nic::new_netlink(&netlink)
+                .set_lladd(ifname, &LLADDR)
+                .unwrap();
+        }
+
+        #[test]
+        fn test_get_flags() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    nlmsg_set_flags(nlmsg, libc::IFF_UP as u32);
+                    Result::Ok(())
+                });
+
+            let flags = Nic::new_netlink(&netlink).get_flags(IFNAME).unwrap();
+
+            assert!(flags.is_up());
+        }
+
+        #[test]
+        fn test_get_hwaddr() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    nlmsg_set_hwaddr(nlmsg, libc::ARPHRD_INFINIBAND, &[0xab; 20]);
+                    Result::Ok(())
+                });
+
+            let hwaddr = Nic::new_netlink(&netlink).get_hwaddr(IFNAME).unwrap();
+
+            assert_eq!(hwaddr.hwtype(), libc::ARPHRD_INFINIBAND);
+            assert_eq!(hwaddr.len(), 20);
+        }
+
+        #[test]
+        fn test_get_mtu() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    nlmsg_set_mtu(nlmsg, 1420);
+                    Result::Ok(())
+                });
+
+            let mtu = Nic::new_netlink(&netlink).get_mtu(IFNAME).unwrap();
+
+            assert_eq!(mtu, 1420);
+        }
+
+        #[test]
+        fn test_set_mtu() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::SetMtu(nlmsg)| {
+                    assert_eq!(nlmsg_get_index(nlmsg), 2);
+                    assert_eq!(nlmsg_get_mtu(nlmsg), 1420);
+                    Result::Ok(())
+                });
+
+            Nic::new_netlink(&netlink)
+                .set_mtu(IfIndex::from(2), 1420)
+                .unwrap();
+        }
+
+        #[test]
+        fn test_set_up() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::SetFlags(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    let up = libc::IFF_UP as u32;
+                    assert_eq!(nlmsg_get_flags(nlmsg), (up, up));
+                    Result::Ok(())
+                });
+
+            Nic::new_netlink(&netlink).set_up(IFNAME).unwrap();
+        }
+
+        #[test]
+        fn test_set_down() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::SetFlags(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    assert_eq!(nlmsg_get_flags(nlmsg), (0, libc::IFF_UP as u32));
+                    Result::Ok(())
+                });
+
+            Nic::new_netlink(&netlink).set_down(IFNAME).unwrap();
+        }
+
+        #[test]
+        fn test_get_permanent_lladdr() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    nlmsg_set_lladdr(nlmsg, LLADDR);
+                    nlmsg_set_perm_lladdr(nlmsg, PERM_LLADDR);
+                    Result::Ok(())
+                });
+
+            let lladdr = Nic::new_netlink(&netlink)
+                .get_permanent_lladdr(IFNAME)
+                .unwrap();
+
+            assert_eq!(lladdr, PERM_LLADDR);
+        }
+
+        #[test]
+        fn test_reset_lladdr() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    nlmsg_set_perm_lladdr(nlmsg, PERM_LLADDR);
+                    Result::Ok(())
+                })
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::SetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_name(nlmsg), IFNAME);
+                    assert_eq!(nlmsg_get_lladdr(nlmsg), PERM_LLADDR);
+                    Result::Ok(())
+                });
+
+            let lladdr = Nic::new_netlink(&netlink).reset_lladdr(IFNAME).unwrap();
+
+            assert_eq!(lladdr, PERM_LLADDR);
+        }
+
+        #[test]
+        fn test_list() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLinks(nlmsg)| {
+                    let request =
+                        nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST | libc::NLM_F_DUMP);
+                    assert_eq!(nlmsg, request);
+                    let flags = IfFlags::from(libc::IFF_UP as u32);
+                    Result::Ok(vec![nlmsg_from_link(&Link::new(
+                        IFNAME,
+                        2.into(),
+                        LLADDR,
+                        flags,
+                    ))])
+                });
+
+            let links = Nic::new_netlink(&netlink).list().unwrap();
+
+            let flags = IfFlags::from(libc::IFF_UP as u32);
+            assert_eq!(links, vec![Link::new(IFNAME, 2.into(), LLADDR, flags)]);
+        }
+
+        #[test]
+        fn test_resolve_alt_name() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::ResolveAltName(nlmsg)| {
+                    assert_eq!(nlmsg_get_alt_name(nlmsg), "usb-c-ethernet-dongle");
+                    nlmsg_set_reply(nlmsg, nlmsg_new_link(IFNAME, LLADDR));
+                    Result::Ok(())
+                });
+
+            let ifid = Nic::new_netlink(&netlink)
+                .resolve("usb-c-ethernet-dongle")
+                .unwrap();
+
+            assert_eq!(ifid, IfId::from(IFNAME));
+        }
+
+        #[test]
+        fn test_resolve_alt_name_error() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::ResolveAltName(_)| Result::<()>::Err("ResolveAltNameError".into()));
+
+            let error = Nic::new_netlink(&netlink)
+                .resolve("usb-c-ethernet-dongle")
+                .unwrap_err();
+
+            assert_eq!(format!("{}", error), "ResolveAltNameError");
+        }
+
+        #[test]
+        fn test_get_alt_names() {
+            let netlink = MockNetlink::default()
+                .expect(|mock::OpenRoute()| ErrNo::None)
+                .expect(|mock::GetLLAddr(nlmsg)| {
+                    assert_eq!(nlmsg_get_index(nlmsg), 2);
+                    nlmsg_set_alt_names(nlmsg, &["dongle", "usb-c-ethernet-dongle"]);
+                    Result::Ok(())
+                });
+
+            let alt_names = Nic::new_netlink(&netlink)
+                .get_alt_names(IfIndex::from(2))
+                .unwrap();
+
+            assert_eq!(alt_names, vec!["dongle", "usb-c-ethernet-dongle"]);
+        }
+    }
+
+    mod sysfs {
+        use super::{Nic, IFNAME, LLADDR};
+        use crate::sys::os::sysfs::mock::SysfsTree;
+        use crate::{IfIndex, OperState};
+
+        #[test]
+        fn test_nic_sysfs_at() {
+            let nic = Nic::sysfs_at("/tmp/net");
+
+            assert_eq!(
+                format!("{:?}", nic),
+                "Nic { backend: Sysfs(Sysfs { root: \"/tmp/net\" }), sysfs: Sysfs { root: \"/sys/class/net\" } }"
+            );
+        }
+
+        #[test]
+        fn test_get_lladd() {
+            let tree = SysfsTree::new("nic_get_lladd").with_link("enx", 2, "00:11:22:33:44:55");
+
+            let lladdr = Nic::sysfs_at(tree.root())
+                .get_lladd(IfIndex::from(2))
+                .unwrap();
+
+            assert_eq!(lladdr, LLADDR);
+        }
+
+        #[test]
+        fn test_set_lladd_unsupported() {
+            let tree = SysfsTree::new("nic_set_lladd").with_link("enx", 2, "00:11:22:33:44:55");
+
+            let error = Nic::sysfs_at(tree.root())
+                .set_lladd(IFNAME, &LLADDR)
+                .unwrap_err();
+
+            assert_eq!(
+                format!("{}", error.source().unwrap()),
+                "Sysfs::UnsupportedError { op: SetLinkLevelAddress, ifid: Name(\"enx\") }"
+            );
+        }
+
+        #[test]
+        fn test_set_up_unsupported() {
+            let error = Nic::sysfs_at("/nonexistent").set_up(IFNAME).unwrap_err();
+
+            assert_eq!(
+                format!("{}", error.source().unwrap()),
+                "Sysfs::UnsupportedError { op: SetFlags, ifid: Name(\"enx\") }"
+            );
+        }
+
+        #[test]
+        fn test_get_hwaddr() {
+            let tree = SysfsTree::new("nic_get_hwaddr").with_link("enx", 2, "00:11:22:33:44:55");
+
+            let hwaddr = Nic::sysfs_at(tree.root()).get_hwaddr(IFNAME).unwrap();
+
+            assert!(hwaddr.is_ether());
+            assert_eq!(hwaddr[..], LLADDR[..]);
+        }
+
+        #[test]
+        fn test_get_mtu() {
+            let tree = SysfsTree::new("nic_get_mtu").with_link("enx", 2, "00:11:22:33:44:55");
+
+            let mtu = Nic::sysfs_at(tree.root()).get_mtu(IFNAME).unwrap();
+
+            assert_eq!(mtu, 1500);
+        }
+
+        #[test]
+        fn test_set_mtu_unsupported() {
+            let error = Nic::sysfs_at("/nonexistent")
+                .set_mtu(IFNAME, 1420)
+                .unwrap_err();
+
+            assert_eq!(
+                format!("{}", error.source().unwrap()),
+                "Sysfs::UnsupportedError { op: SetMtu, ifid: Name(\"enx\") }"
+            );
+        }
+
+        #[test]
+        fn test_info() {
+            let tree = SysfsTree::new("nic_info").with_link("enx", 2, "00:11:22:33:44:55");
+
+            let info = Nic::sysfs_at(tree.root()).info(IFNAME).unwrap();
+
+            assert_eq!(info.index(), IfIndex::from(2));
+            assert_eq!(info.operstate(), OperState::Up);
+        }
+    }
+}
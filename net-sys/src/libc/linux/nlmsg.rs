@@ -0,0 +1,747 @@
+use super::ifindex::IfIndex;
+use super::ifname::IfName;
+use crate::{HardwareAddress, IfFlags, IfId, Link, LinkLevelAddress, Result};
+use libc::c_int;
+
+// struct nlmsghdr { u32 nlmsg_len; u16 nlmsg_type; u16 nlmsg_flags; u32 nlmsg_seq; u32 nlmsg_pid; }
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/netlink.h
+const NLMSG_HDRLEN: usize = 16;
+// struct ifinfomsg { u8 ifi_family; u8 pad; u16 ifi_type; i32 ifi_index; u32 ifi_flags; u32 ifi_change; }
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/rtnetlink.h
+const IFINFOMSG_LEN: usize = 16;
+// struct rtattr { u16 rta_len; u16 rta_type; }
+const RTA_HDRLEN: usize = 4;
+
+const NLMSG_LEN: usize = 0;
+const NLMSG_TYPE: usize = 4;
+const NLMSG_FLAGS: usize = 6;
+const NLMSG_SEQ: usize = 8;
+const NLMSG_PID: usize = 12;
+const NLMSG_ERRNO: usize = NLMSG_HDRLEN;
+
+const IFI_FAMILY: usize = NLMSG_HDRLEN;
+const IFI_TYPE: usize = NLMSG_HDRLEN + 2;
+const IFI_INDEX: usize = NLMSG_HDRLEN + 4;
+const IFI_FLAGS: usize = NLMSG_HDRLEN + 8;
+const IFI_CHANGE: usize = NLMSG_HDRLEN + 12;
+
+pub(super) type NlMsg = Vec<u8>;
+
+const fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn get_u16(nlmsg: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes([nlmsg[offset], nlmsg[offset + 1]])
+}
+
+fn set_u16(nlmsg: &mut [u8], offset: usize, value: u16) {
+    nlmsg[offset..offset + 2].copy_from_slice(&value.to_ne_bytes());
+}
+
+fn get_u32(nlmsg: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&nlmsg[offset..offset + 4]);
+    u32::from_ne_bytes(bytes)
+}
+
+fn set_u32(nlmsg: &mut [u8], offset: usize, value: u32) {
+    nlmsg[offset..offset + 4].copy_from_slice(&value.to_ne_bytes());
+}
+
+fn set_len(nlmsg: &mut NlMsg) {
+    let len = nlmsg.len() as u32;
+    set_u32(nlmsg, NLMSG_LEN, len);
+}
+
+fn put_attr(nlmsg: &mut NlMsg, ty: u16, data: &[u8]) {
+    let offset = nlmsg.len();
+    let rta_len = RTA_HDRLEN + data.len();
+    nlmsg.resize(offset + align(rta_len), 0);
+    set_u16(nlmsg, offset, rta_len as u16);
+    set_u16(nlmsg, offset + 2, ty);
+    nlmsg[offset + RTA_HDRLEN..offset + rta_len].copy_from_slice(data);
+    set_len(nlmsg);
+}
+
+// Walks a run of rtattrs, such as the ones following the ifinfomsg or the
+// ones nested inside IFLA_PROP_LIST. The kernel flags nested attributes with
+// NLA_F_NESTED, which is masked off the returned type.
+fn get_attrs(attrs: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while offset + RTA_HDRLEN <= attrs.len() {
+        let rta_len = get_u16(attrs, offset) as usize;
+        if rta_len < RTA_HDRLEN || offset + rta_len > attrs.len() {
+            break;
+        }
+        let ty = get_u16(attrs, offset + 2) & libc::NLA_TYPE_MASK as u16;
+        found.push((ty, &attrs[offset + RTA_HDRLEN..offset + rta_len]));
+        offset += align(rta_len);
+    }
+    found
+}
+
+fn get_attr(nlmsg: &[u8], ty: u16) -> Option<&[u8]> {
+    let len = usize::min(get_len(nlmsg), nlmsg.len());
+    let attrs = nlmsg.get(NLMSG_HDRLEN + IFINFOMSG_LEN..len)?;
+    get_attrs(attrs)
+        .into_iter()
+        .find(|(attr_ty, _)| *attr_ty == ty)
+        .map(|(_, data)| data)
+}
+
+fn to_string(data: &[u8]) -> String {
+    let data = data.split(|&byte| byte == 0).next().unwrap_or_default();
+    String::from_utf8_lossy(data).into_owned()
+}
+
+pub(super) fn new(ty: u16, flags: c_int) -> NlMsg {
+    let mut nlmsg = vec![0u8; NLMSG_HDRLEN + IFINFOMSG_LEN];
+    set_u16(&mut nlmsg, NLMSG_TYPE, ty);
+    set_u16(&mut nlmsg, NLMSG_FLAGS, flags as u16);
+    set_len(&mut nlmsg);
+    nlmsg
+}
+
+pub(super) fn get_len(nlmsg: &[u8]) -> usize {
+    get_u32(nlmsg, NLMSG_LEN) as usize
+}
+
+pub(super) fn get_type(nlmsg: &[u8]) -> u16 {
+    get_u16(nlmsg, NLMSG_TYPE)
+}
+
+pub(super) fn get_seq(nlmsg: &[u8]) -> u32 {
+    get_u32(nlmsg, NLMSG_SEQ)
+}
+
+pub(super) fn set_seq(nlmsg: &mut NlMsg, seq: u32) {
+    set_u32(nlmsg, NLMSG_SEQ, seq);
+}
+
+// The port id of the socket a reply was sent to, 0 for the kernel itself.
+pub(super) fn get_pid(nlmsg: &[u8]) -> u32 {
+    get_u32(nlmsg, NLMSG_PID)
+}
+
+pub(super) fn get_family(nlmsg: &[u8]) -> u8 {
+    nlmsg[IFI_FAMILY]
+}
+
+pub(super) fn get_index(nlmsg: &[u8]) -> u32 {
+    get_u32(nlmsg, IFI_INDEX)
+}
+
+pub(super) fn get_flags(nlmsg: &[u8]) -> u32 {
+    get_u32(nlmsg, IFI_FLAGS)
+}
+
+// Only the bits set in `change` are applied by the kernel.
+pub(super) fn set_flags(nlmsg: &mut NlMsg, flags: u32, change: u32) {
+    set_u32(nlmsg, IFI_FLAGS, flags);
+    set_u32(nlmsg, IFI_CHANGE, change);
+}
+
+pub(super) fn split(buf: &[u8]) -> Vec<NlMsg> {
+    let mut nlmsgs = Vec::new();
+    let mut offset = 0;
+    while offset + NLMSG_HDRLEN <= buf.len() {
+        let len = get_len(&buf[offset..]);
+        if len < NLMSG_HDRLEN || offset + len > buf.len() {
+            break;
+        }
+        nlmsgs.push(buf[offset..offset + len].to_vec());
+        offset += align(len);
+    }
+    nlmsgs
+}
+
+pub(super) fn get_errno(nlmsg: &[u8]) -> Option<c_int> {
+    if get_type(nlmsg) != libc::NLMSG_ERROR as u16 || nlmsg.len() < NLMSG_ERRNO + 4 {
+        return None;
+    }
+    match -(get_u32(nlmsg, NLMSG_ERRNO) as i32) {
+        0 => None,
+        errno => Some(errno),
+    }
+}
+
+pub(super) fn set_name(nlmsg: &mut NlMsg, ifname: &IfName) {
+    let c_str = unsafe { std::ffi::CStr::from_ptr(ifname.as_ptr()) };
+    put_attr(nlmsg, libc::IFLA_IFNAME, c_str.to_bytes_with_nul());
+}
+
+// The kernel looks the link up by ifi_index when set, otherwise by IFLA_IFNAME.
+pub(super) fn set_ifid(nlmsg: &mut NlMsg, ifid: IfId) {
+    match ifid {
+        IfId::Name(ifname) => set_name(nlmsg, &ifname),
+        IfId::Index(ifindex) => set_u32(nlmsg, IFI_INDEX, *ifindex),
+    }
+}
+
+// What `set_ifid` put in the request: the name when there is one.
+pub(super) fn get_ifid(nlmsg: &[u8]) -> IfId {
+    match get_attr(nlmsg, libc::IFLA_IFNAME) {
+        Some(_) => IfId::from(get_name(nlmsg)),
+        None => IfId::from(IfIndex::from(get_index(nlmsg))),
+    }
+}
+
+pub(super) fn get_name(nlmsg: &[u8]) -> IfName {
+    IfName::from_bytes(get_attr(nlmsg, libc::IFLA_IFNAME).unwrap_or_default())
+}
+
+// Alt names may be up to ALTIFNAMSIZ (128) bytes, so they do not fit an IfName.
+pub(super) fn set_alt_name(nlmsg: &mut NlMsg, alt_name: &str) {
+    let mut data = alt_name.as_bytes().to_vec();
+    data.push(0);
+    put_attr(nlmsg, libc::IFLA_ALT_IFNAME, &data);
+}
+
+pub(super) fn get_alt_name(nlmsg: &[u8]) -> String {
+    to_string(get_attr(nlmsg, libc::IFLA_ALT_IFNAME).unwrap_or_default())
+}
+
+pub(super) fn get_alt_names(nlmsg: &[u8]) -> Vec<String> {
+    get_attrs(get_attr(nlmsg, libc::IFLA_PROP_LIST).unwrap_or_default())
+        .into_iter()
+        .filter(|(ty, _)| *ty == libc::IFLA_ALT_IFNAME)
+        .map(|(_, data)| to_string(data))
+        .collect()
+}
+
+pub(super) fn set_lladdr(nlmsg: &mut NlMsg, lladdr: &LinkLevelAddress) {
+    put_attr(nlmsg, libc::IFLA_ADDRESS, &lladdr[..]);
+}
+
+fn get_lladdr_attr(nlmsg: &[u8], ty: u16) -> LinkLevelAddress {
+    let mut lladdr = [0u8; 6];
+    if let Some(data) = get_attr(nlmsg, ty) {
+        let len = usize::min(data.len(), lladdr.len());
+        lladdr[..len].copy_from_slice(&data[..len]);
+    }
+    LinkLevelAddress::from(&lladdr)
+}
+
+pub(super) fn get_lladdr(nlmsg: &[u8]) -> LinkLevelAddress {
+    get_lladdr_attr(nlmsg, libc::IFLA_ADDRESS)
+}
+
+/// The `IFLA_ADDRESS` at whatever length the link uses, with `ifi_type`.
+pub(super) fn get_hwaddr(nlmsg: &[u8]) -> Result<HardwareAddress> {
+    let octets = get_attr(nlmsg, libc::IFLA_ADDRESS).unwrap_or_default();
+    HardwareAddress::new(get_u16(nlmsg, IFI_TYPE), octets)
+}
+
+pub(super) fn get_perm_lladdr(nlmsg: &[u8]) -> LinkLevelAddress {
+    get_lladdr_attr(nlmsg, libc::IFLA_PERM_ADDRESS)
+}
+
+pub(super) fn set_mtu(nlmsg: &mut NlMsg, mtu: u32) {
+    put_attr(nlmsg, libc::IFLA_MTU, &mtu.to_ne_bytes());
+}
+
+pub(super) fn get_mtu(nlmsg: &[u8]) -> u32 {
+    match get_attr(nlmsg, libc::IFLA_MTU) {
+        Some(data) if data.len() >= 4 => get_u32(data, 0),
+        _ => 0,
+    }
+}
+
+pub(super) fn get_link(nlmsg: &[u8]) -> Link {
+    let link = Link::new(
+        get_name(nlmsg),
+        IfIndex::from(get_index(nlmsg)),
+        get_lladdr(nlmsg),
+        IfFlags::from(get_flags(nlmsg)),
+    );
+    match get_hwaddr(nlmsg) {
+        Ok(hwaddr) => link.with_hwaddr(hwaddr),
+        Err(_) => link,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IfFlags, IfId, IfName, Link, LinkLevelAddress};
+    use crate::IfIndex;
+
+    const NAME: &str = "enx";
+    const LLADDR: [u8; 6] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+
+    #[test]
+    fn test_nlmsg_new() {
+        let expected_nlmsg: [u8; 32] = [
+            32, 0, 0, 0, // nlmsg_len
+            18, 0, // nlmsg_type
+            5, 0, // nlmsg_flags
+            0, 0, 0, 0, // nlmsg_seq
+            0, 0, 0, 0, // nlmsg_pid
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // ifinfomsg
+        ];
+
+        let nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST | libc::NLM_F_ACK);
+
+        assert_eq!(nlmsg, expected_nlmsg);
+    }
+
+    #[test]
+    fn test_nlmsg_header() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+
+        assert_eq!(super::get_len(&nlmsg), 32);
+        assert_eq!(super::get_type(&nlmsg), libc::RTM_NEWLINK);
+        assert_eq!(
+            super::get_u16(&nlmsg, super::NLMSG_FLAGS),
+            libc::NLM_F_REQUEST as u16
+        );
+    }
+
+    #[test]
+    fn test_nlmsg_seq_and_pid() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        nlmsg[12..16].copy_from_slice(&4242u32.to_ne_bytes());
+
+        super::set_seq(&mut nlmsg, 7);
+
+        assert_eq!(nlmsg[8..12], 7u32.to_ne_bytes());
+        assert_eq!(super::get_seq(&nlmsg), 7);
+        assert_eq!(super::get_pid(&nlmsg), 4242);
+    }
+
+    #[test]
+    fn test_nlmsg_get_index_and_flags() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        nlmsg[20..24].copy_from_slice(&7u32.to_ne_bytes());
+        nlmsg[24..28].copy_from_slice(&(libc::IFF_UP as u32).to_ne_bytes());
+
+        assert_eq!(super::get_index(&nlmsg), 7);
+        assert_eq!(super::get_flags(&nlmsg), libc::IFF_UP as u32);
+    }
+
+    #[test]
+    fn test_nlmsg_set_flags() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        super::set_flags(&mut nlmsg, libc::IFF_UP as u32, libc::IFF_UP as u32);
+
+        assert_eq!(super::get_flags(&nlmsg), libc::IFF_UP as u32);
+        assert_eq!(nlmsg[28..32], (libc::IFF_UP as u32).to_ne_bytes());
+    }
+
+    #[test]
+    fn test_nlmsg_split() {
+        let mut first = super::new(libc::RTM_NEWLINK, 0);
+        super::set_name(&mut first, &IfName::try_from("enx0").unwrap());
+        let second = super::new(libc::NLMSG_DONE as u16, 0);
+        let buf = [first.clone(), second.clone()].concat();
+
+        let nlmsgs = super::split(&buf);
+
+        assert_eq!(nlmsgs, vec![first, second]);
+    }
+
+    #[test]
+    fn test_nlmsg_split_truncated() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        let nlmsgs = super::split(&nlmsg[..20]);
+
+        assert_eq!(nlmsgs, Vec::<super::NlMsg>::new());
+    }
+
+    #[test]
+    fn test_nlmsg_set_name() {
+        let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+
+        super::set_name(&mut nlmsg, &IfName::try_from(NAME).unwrap());
+
+        assert_eq!(super::get_len(&nlmsg), 40);
+        assert_eq!(nlmsg[32..], [8, 0, 3, 0, b'e', b'n', b'x', 0]);
+    }
+
+    #[test]
+    fn test_nlmsg_get_name() {
+        let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        super::set_name(&mut nlmsg, &IfName::try_from(NAME).unwrap());
+
+        let ifname = super::get_name(&nlmsg);
+
+        assert_eq!(ifname, IfName::try_from(NAME).unwrap());
+    }
+
+    #[test]
+    fn test_nlmsg_get_name_missing() {
+        let nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+
+        let ifname = super::get_name(&nlmsg);
+
+        assert_eq!(ifname, IfName::from(&[0; libc::IFNAMSIZ]));
+    }
+
+    #[test]
+    fn test_nlmsg_set_ifid_name() {
+        let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+        let ifname = IfName::try_from(NAME).unwrap();
+
+        super::set_ifid(&mut nlmsg, IfId::from(ifname));
+
+        assert_eq!(super::get_index(&nlmsg), 0);
+        assert_eq!(super::get_name(&nlmsg), ifname);
+    }
+
+    #[test]
+    fn test_nlmsg_set_ifid_index() {
+        let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+
+        super::set_ifid(&mut nlmsg, IfId::from(IfIndex::from(7)));
+
+        assert_eq!(super::get_len(&nlmsg), 32);
+        assert_eq!(super::get_index(&nlmsg), 7);
+    }
+
+    #[test]
+    fn test_nlmsg_get_ifid() {
+        for ifid in [
+            IfId::from(IfName::try_from(NAME).unwrap()),
+            IfId::from(IfIndex::from(7)),
+        ] {
+            let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+
+            super::set_ifid(&mut nlmsg, ifid);
+
+            assert_eq!(super::get_ifid(&nlmsg), ifid);
+        }
+    }
+
+    #[test]
+    fn test_nlmsg_set_alt_name() {
+        let mut nlmsg = super::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST);
+
+        super::set_alt_name(&mut nlmsg, "enx-dongle");
+
+        assert_eq!(super::get_len(&nlmsg), 48);
+        assert_eq!(nlmsg[32..36], [15, 0, 53, 0]);
+        assert_eq!(super::get_alt_name(&nlmsg), "enx-dongle");
+    }
+
+    #[test]
+    fn test_nlmsg_get_alt_names() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        super::set_name(&mut nlmsg, &IfName::try_from(NAME).unwrap());
+        let mut prop_list = super::new(0, 0);
+        super::set_alt_name(&mut prop_list, "enx-dongle");
+        super::set_alt_name(&mut prop_list, "usb-c-ethernet-dongle-on-the-left");
+        let ty = libc::IFLA_PROP_LIST | libc::NLA_F_NESTED as u16;
+        super::put_attr(&mut nlmsg, ty, &prop_list[32..]);
+
+        let alt_names = super::get_alt_names(&nlmsg);
+
+        assert_eq!(
+            alt_names,
+            vec!["enx-dongle", "usb-c-ethernet-dongle-on-the-left"]
+        );
+    }
+
+    #[test]
+    fn test_nlmsg_get_alt_names_missing() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        let alt_names = super::get_alt_names(&nlmsg);
+
+        assert_eq!(alt_names, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_nlmsg_get_attr_truncated() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        super::set_name(&mut nlmsg, &IfName::try_from(NAME).unwrap());
+        nlmsg[32..34].copy_from_slice(&64u16.to_ne_bytes());
+
+        assert_eq!(super::get_attr(&nlmsg, libc::IFLA_IFNAME), None);
+    }
+
+    #[test]
+    fn test_nlmsg_set_lladdr() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+
+        super::set_lladdr(&mut nlmsg, &LinkLevelAddress::from(&LLADDR));
+
+        assert_eq!(super::get_len(&nlmsg), 44);
+        assert_eq!(nlmsg[32..], [10, 0, 1, 0, 1, 2, 3, 4, 5, 6, 0, 0]);
+    }
+
+    #[test]
+    fn test_nlmsg_get_lladdr() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+        super::set_name(&mut nlmsg, &IfName::try_from(NAME).unwrap());
+        super::set_lladdr(&mut nlmsg, &LinkLevelAddress::from(&LLADDR));
+
+        let lladdr = super::get_lladdr(&nlmsg);
+
+        assert_eq!(*lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_nlmsg_get_lladdr_missing() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+
+        let lladdr = super::get_lladdr(&nlmsg);
+
+        assert_eq!(*lladdr, [0; 6]);
+    }
+
+    #[test]
+    fn test_nlmsg_get_hwaddr() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        nlmsg[18..20].copy_from_slice(&libc::ARPHRD_INFINIBAND.to_ne_bytes());
+        super::put_attr(&mut nlmsg, libc::IFLA_ADDRESS, &[0xab; 20]);
+
+        let hwaddr = super::get_hwaddr(&nlmsg).unwrap();
+
+        assert_eq!(hwaddr.hwtype(), libc::ARPHRD_INFINIBAND);
+        assert_eq!(hwaddr[..], [0xab; 20]);
+    }
+
+    #[test]
+    fn test_nlmsg_get_hwaddr_missing() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        let hwaddr = super::get_hwaddr(&nlmsg).unwrap();
+
+        assert!(hwaddr.is_empty());
+    }
+
+    #[test]
+    fn test_nlmsg_get_perm_lladdr() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+        super::set_lladdr(&mut nlmsg, &LinkLevelAddress::from(&[0xff; 6]));
+        super::put_attr(&mut nlmsg, libc::IFLA_PERM_ADDRESS, &LLADDR);
+
+        let lladdr = super::get_perm_lladdr(&nlmsg);
+
+        assert_eq!(*lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_nlmsg_get_perm_lladdr_missing() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+        super::set_lladdr(&mut nlmsg, &LinkLevelAddress::from(&LLADDR));
+
+        let lladdr = super::get_perm_lladdr(&nlmsg);
+
+        assert_eq!(*lladdr, [0; 6]);
+    }
+
+    #[test]
+    fn test_nlmsg_set_mtu() {
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, libc::NLM_F_REQUEST);
+
+        super::set_mtu(&mut nlmsg, 1420);
+
+        assert_eq!(super::get_len(&nlmsg), 40);
+        assert_eq!(nlmsg[32..36], [8, 0, 4, 0]);
+        assert_eq!(super::get_mtu(&nlmsg), 1420);
+    }
+
+    #[test]
+    fn test_nlmsg_get_mtu_missing() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        assert_eq!(super::get_mtu(&nlmsg), 0);
+    }
+
+    #[test]
+    fn test_nlmsg_get_link() {
+        let ifname = IfName::try_from(NAME).unwrap();
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        nlmsg[18..20].copy_from_slice(&libc::ARPHRD_ETHER.to_ne_bytes());
+        nlmsg[20..24].copy_from_slice(&7u32.to_ne_bytes());
+        nlmsg[24..28].copy_from_slice(&(libc::IFF_UP as u32).to_ne_bytes());
+        super::set_name(&mut nlmsg, &ifname);
+        super::set_lladdr(&mut nlmsg, &LinkLevelAddress::from(&LLADDR));
+
+        let link = super::get_link(&nlmsg);
+
+        assert_eq!(
+            link,
+            Link::new(
+                ifname,
+                IfIndex::from(7),
+                LinkLevelAddress::from(&LLADDR),
+                IfFlags::from(libc::IFF_UP as u32)
+            )
+        );
+    }
+
+    #[test]
+    fn test_nlmsg_get_link_infiniband() {
+        let octets: Vec<u8> = (0..20).collect();
+        let mut nlmsg = super::new(libc::RTM_NEWLINK, 0);
+        nlmsg[18..20].copy_from_slice(&libc::ARPHRD_INFINIBAND.to_ne_bytes());
+        super::set_name(&mut nlmsg, &IfName::try_from("ib0").unwrap());
+        super::put_attr(&mut nlmsg, libc::IFLA_ADDRESS, &octets);
+
+        let link = super::get_link(&nlmsg);
+
+        assert_eq!(link.lladdr(), &LinkLevelAddress::from(&[0, 1, 2, 3, 4, 5]));
+        assert_eq!(link.hwaddr().hwtype(), libc::ARPHRD_INFINIBAND);
+        assert_eq!(link.hwaddr()[..], octets[..]);
+    }
+
+    #[test]
+    fn test_nlmsg_get_errno() {
+        let mut nlmsg = super::new(libc::NLMSG_ERROR as u16, 0);
+        nlmsg[16..20].copy_from_slice(&(-libc::ENODEV).to_ne_bytes());
+
+        let errno = super::get_errno(&nlmsg);
+
+        assert_eq!(errno, Some(libc::ENODEV));
+    }
+
+    #[test]
+    fn test_nlmsg_get_errno_ack() {
+        let nlmsg = super::new(libc::NLMSG_ERROR as u16, 0);
+
+        let errno = super::get_errno(&nlmsg);
+
+        assert_eq!(errno, None);
+    }
+
+    #[test]
+    fn test_nlmsg_get_errno_not_error() {
+        let nlmsg = super::new(libc::RTM_NEWLINK, 0);
+
+        let errno = super::get_errno(&nlmsg);
+
+        assert_eq!(errno, None);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::{
+        get_alt_name, get_flags, get_index, get_lladdr, get_mtu, get_name, new, put_attr,
+        set_alt_name, set_flags, set_lladdr, set_mtu, set_name, set_seq, set_u16, set_u32, NlMsg,
+        IFINFOMSG_LEN, IFI_TYPE, NLMSG_HDRLEN, NLMSG_PID,
+    };
+    use crate::{IfName, Link, LinkLevelAddress};
+
+    fn from_mut_ptr<'a>(arg: *mut NlMsg) -> &'a mut NlMsg {
+        unsafe { &mut *arg }
+    }
+
+    pub(crate) fn nlmsg_get_name(arg: *mut NlMsg) -> IfName {
+        get_name(from_mut_ptr(arg))
+    }
+
+    pub(crate) fn nlmsg_get_lladdr(arg: *mut NlMsg) -> LinkLevelAddress {
+        get_lladdr(from_mut_ptr(arg))
+    }
+
+    pub(crate) fn nlmsg_set_lladdr(arg: *mut NlMsg, lladdr: LinkLevelAddress) {
+        set_lladdr(from_mut_ptr(arg), &lladdr);
+    }
+
+    pub(crate) fn nlmsg_get_index(arg: *mut NlMsg) -> u32 {
+        get_index(from_mut_ptr(arg))
+    }
+
+    pub(crate) fn nlmsg_get_flags(arg: *mut NlMsg) -> (u32, u32) {
+        let nlmsg = from_mut_ptr(arg);
+        (
+            get_flags(nlmsg),
+            u32::from_ne_bytes(nlmsg[28..32].try_into().unwrap()),
+        )
+    }
+
+    pub(crate) fn nlmsg_set_flags(arg: *mut NlMsg, flags: u32) {
+        set_flags(from_mut_ptr(arg), flags, 0);
+    }
+
+    pub(crate) fn nlmsg_set_hwaddr(arg: *mut NlMsg, hwtype: u16, octets: &[u8]) {
+        let nlmsg = from_mut_ptr(arg);
+        nlmsg[18..20].copy_from_slice(&hwtype.to_ne_bytes());
+        put_attr(nlmsg, libc::IFLA_ADDRESS, octets);
+    }
+
+    pub(crate) fn nlmsg_set_perm_lladdr(arg: *mut NlMsg, lladdr: LinkLevelAddress) {
+        put_attr(from_mut_ptr(arg), libc::IFLA_PERM_ADDRESS, &lladdr[..]);
+    }
+
+    pub(crate) fn nlmsg_get_alt_name(arg: *mut NlMsg) -> String {
+        get_alt_name(from_mut_ptr(arg))
+    }
+
+    pub(crate) fn nlmsg_set_alt_names(arg: *mut NlMsg, alt_names: &[&str]) {
+        let mut prop_list = new(0, 0);
+        for alt_name in alt_names {
+            set_alt_name(&mut prop_list, alt_name);
+        }
+        put_attr(
+            from_mut_ptr(arg),
+            libc::IFLA_PROP_LIST | libc::NLA_F_NESTED as u16,
+            &prop_list[NLMSG_HDRLEN + IFINFOMSG_LEN..],
+        );
+    }
+
+    pub(crate) fn nlmsg_get_mtu(arg: *mut NlMsg) -> u32 {
+        get_mtu(from_mut_ptr(arg))
+    }
+
+    pub(crate) fn nlmsg_set_mtu(arg: *mut NlMsg, mtu: u32) {
+        set_mtu(from_mut_ptr(arg), mtu);
+    }
+
+    pub(crate) fn nlmsg_set_reply(arg: *mut NlMsg, reply: NlMsg) {
+        *from_mut_ptr(arg) = reply;
+    }
+
+    pub(crate) fn nlmsg_new_link(ifname: IfName, lladdr: LinkLevelAddress) -> NlMsg {
+        let mut nlmsg = new(libc::RTM_NEWLINK, 0);
+        set_name(&mut nlmsg, &ifname);
+        set_lladdr(&mut nlmsg, &lladdr);
+        nlmsg
+    }
+
+    pub(crate) fn nlmsg_from_link(link: &Link) -> NlMsg {
+        let mut nlmsg = new(libc::RTM_NEWLINK, 0);
+        set_name(&mut nlmsg, link.ifname());
+        put_attr(&mut nlmsg, libc::IFLA_ADDRESS, &link.hwaddr()[..]);
+        set_u16(&mut nlmsg, IFI_TYPE, link.hwaddr().hwtype());
+        nlmsg[20..24].copy_from_slice(&link.index().to_ne_bytes());
+        nlmsg[24..28].copy_from_slice(&link.flags().bits().to_ne_bytes());
+        nlmsg
+    }
+
+    pub(crate) fn nlmsg_done() -> NlMsg {
+        new(libc::NLMSG_DONE as u16, libc::NLM_F_MULTI)
+    }
+
+    pub(crate) fn nlmsg_error(errno: libc::c_int) -> NlMsg {
+        let mut nlmsg = new(libc::NLMSG_ERROR as u16, 0);
+        nlmsg[16..20].copy_from_slice(&(-errno).to_ne_bytes());
+        nlmsg
+    }
+
+    // Addresses each message of `nlmsgs` as the kernel's answer to request `seq` of port `pid`.
+    pub(crate) fn nlmsg_reply(nlmsgs: &[NlMsg], seq: u32, pid: u32) -> NlMsg {
+        let mut reply = Vec::new();
+        for nlmsg in nlmsgs {
+            let mut nlmsg = nlmsg.clone();
+            set_seq(&mut nlmsg, seq);
+            set_u32(&mut nlmsg, NLMSG_PID, pid);
+            reply.extend_from_slice(&nlmsg);
+        }
+        reply
+    }
+
+    pub(crate) fn recv_reply(buf: *mut libc::c_void, len: libc::size_t, nlmsg: &NlMsg) -> isize {
+        assert!(nlmsg.len() <= len);
+        unsafe {
+            std::ptr::copy_nonoverlapping(nlmsg.as_ptr(), buf as *mut u8, nlmsg.len());
+        }
+        nlmsg.len() as isize
+    }
+}
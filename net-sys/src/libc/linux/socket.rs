@@ -0,0 +1,1117 @@
+use super::ifaddrs::{self};
+use super::ifindex::{self, IfIndex};
+use super::ifname::IfName;
+use super::ifreq::{self};
+use super::sys::{self, BoxSys};
+use crate::{IfFlags, Link, LinkLevelAddress, Operation, Result};
+use std::fmt::{Debug, Display};
+use std::ops::Deref;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    OpenLocalDgram(libc::c_int, libc::c_int),
+    GetLinkLevelAddress(libc::c_int, IfName, libc::c_int, libc::c_int),
+    SetLinkLevelAddress(
+        libc::c_int,
+        IfName,
+        LinkLevelAddress,
+        libc::c_int,
+        libc::c_int,
+    ),
+    GetPermanentLinkLevelAddress(libc::c_int, IfName, libc::c_int, libc::c_int),
+    GetFlags(libc::c_int, IfName, libc::c_int, libc::c_int),
+    SetFlags(libc::c_int, IfName, IfFlags, libc::c_int, libc::c_int),
+    GetMtu(libc::c_int, IfName, libc::c_int, libc::c_int),
+    SetMtu(libc::c_int, IfName, u32, libc::c_int, libc::c_int),
+    Close(libc::c_int, libc::c_int, libc::c_int),
+    GetIfAddrs(libc::c_int, libc::c_int),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::OpenLocalDgram(ret, errno) => f
+                .debug_struct("Socket::OpenLocalDgramError")
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetLinkLevelAddress(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetLinkLevelAddressError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetLinkLevelAddress(fd, ifname, lladdr, ret, errno) => f
+                .debug_struct("Socket::SetLinkLevelAddressError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("lladdr", lladdr)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetPermanentLinkLevelAddress(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetPermanentLinkLevelAddressError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetFlags(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetFlagsError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetFlags(fd, ifname, flags, ret, errno) => f
+                .debug_struct("Socket::SetFlagsError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("flags", flags)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetMtu(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetMtuError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetMtu(fd, ifname, mtu, ret, errno) => f
+                .debug_struct("Socket::SetMtuError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("mtu", mtu)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::Close(fd, ret, errno) => f
+                .debug_struct("Socket::CloseError")
+                .field("fd", fd)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetIfAddrs(ret, errno) => f
+                .debug_struct("Socket::GetIfAddrsError")
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(error: Error) -> Self {
+        match error.clone() {
+            Error::OpenLocalDgram(_, errno) => Self::from_errno(errno, Operation::Open, error),
+            Error::GetLinkLevelAddress(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetLinkLevelAddress, error).with_ifid(ifname)
+            }
+            Error::SetLinkLevelAddress(_, ifname, lladdr, _, errno) => {
+                Self::from_errno(errno, Operation::SetLinkLevelAddress, error)
+                    .with_ifid(ifname)
+                    .with_lladdr(lladdr)
+            }
+            Error::GetPermanentLinkLevelAddress(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetPermanentLinkLevelAddress, error)
+                    .with_ifid(ifname)
+            }
+            Error::GetFlags(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetFlags, error).with_ifid(ifname)
+            }
+            Error::SetFlags(_, ifname, _, _, errno) => {
+                Self::from_errno(errno, Operation::SetFlags, error).with_ifid(ifname)
+            }
+            Error::GetMtu(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetMtu, error).with_ifid(ifname)
+            }
+            Error::SetMtu(_, ifname, _, _, errno) => {
+                Self::from_errno(errno, Operation::SetMtu, error).with_ifid(ifname)
+            }
+            Error::Close(_, _, errno) => Self::from_errno(errno, Operation::Close, error),
+            Error::GetIfAddrs(_, errno) => Self::from_errno(errno, Operation::GetLinks, error),
+        }
+    }
+}
+
+type SocketResult<'a> = Result<Box<dyn OpenSocket + 'a>>;
+
+pub(super) trait Socket: Debug {
+    fn open_local_dgram(&self) -> SocketResult<'_>;
+    fn get_links(&self) -> Result<Vec<Link>>;
+    fn get_name(&self, ifindex: IfIndex) -> Result<IfName>;
+}
+
+#[derive(Debug, Default)]
+pub(super) struct BoxSocket(pub(super) Box<dyn Socket>);
+
+impl Default for Box<dyn Socket> {
+    fn default() -> Self {
+        Box::new(LibcSocket::default())
+    }
+}
+
+impl Deref for BoxSocket {
+    type Target = Box<dyn Socket>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct LibcSocket(BoxSys);
+
+impl Deref for LibcSocket {
+    type Target = BoxSys;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Socket for LibcSocket {
+    fn open_local_dgram(&self) -> SocketResult<'_> {
+        match self.socket(libc::AF_LOCAL, libc::SOCK_DGRAM, 0) {
+            fd if fd >= 0 => Ok(Box::new(LibcOpenSocket { fd, sys: self })),
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::OpenLocalDgram(ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_links(&self) -> Result<Vec<Link>> {
+        let mut ifap = std::ptr::null_mut();
+        match self.getifaddrs(&mut ifap) {
+            0 => {
+                let links = ifaddrs::get_links(ifap);
+                self.freeifaddrs(ifap);
+                Ok(links)
+            }
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetIfAddrs(ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_name(&self, ifindex: IfIndex) -> Result<IfName> {
+        ifindex::to_name(self, ifindex)
+    }
+}
+
+pub(super) trait OpenSocket: Debug {
+    fn get_lladdr(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn set_lladdr(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn get_flags(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn set_flags(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn get_perm_lladdr(&self, arg: *mut libc::c_void) -> Result<()>;
+}
+
+#[derive(Debug)]
+struct LibcOpenSocket<'a> {
+    fd: libc::c_int,
+    sys: &'a BoxSys,
+}
+
+impl<'a> Deref for LibcOpenSocket<'a> {
+    type Target = &'a BoxSys;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sys
+    }
+}
+
+impl<'a> OpenSocket for LibcOpenSocket<'a> {
+    fn get_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCGIFHWADDR, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetLinkLevelAddress(fd, ifname, ret, errno)).into())
+            }
+        }
+    }
+
+    fn set_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCSIFHWADDR, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let lladdr = ifreq::get_lladdr(ifreq);
+                let errno = self.errno();
+                Err(
+                    crate::Error::from(Error::SetLinkLevelAddress(fd, ifname, lladdr, ret, errno))
+                        .into(),
+                )
+            }
+        }
+    }
+
+    fn get_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCGIFFLAGS, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetFlags(fd, ifname, ret, errno)).into())
+            }
+        }
+    }
+
+    fn set_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCSIFFLAGS, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let flags = ifreq::get_flags(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::SetFlags(fd, ifname, flags, ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCGIFMTU, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetMtu(fd, ifname, ret, errno)).into())
+            }
+        }
+    }
+
+    fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCSIFMTU, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let mtu = ifreq::get_mtu(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::SetMtu(fd, ifname, mtu, ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_perm_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCETHTOOL, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(
+                    crate::Error::from(Error::GetPermanentLinkLevelAddress(fd, ifname, ret, errno))
+                        .into(),
+                )
+            }
+        }
+    }
+}
+
+impl<'a> Drop for LibcOpenSocket<'a> {
+    fn drop(&mut self) {
+        let fd = self.fd;
+        match self.close(fd) {
+            0 => (),
+            ret => {
+                let errno = self.errno();
+                let error = Error::Close(fd, ret, errno);
+                eprintln!("Error: {:?}", error);
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sys::mock::{self, MockSys};
+    use super::{ifreq, BoxSys, IfName, LibcSocket, LinkLevelAddress, Result, Socket};
+    use crate::sys::os::ethtool::mock::{ethtool_get_cmd, ethtool_set_lladdr};
+    use crate::sys::os::ethtool::{self};
+    use crate::sys::os::ifaddrs::mock::{ifaddrs_free, ifaddrs_new};
+    use crate::sys::os::ifreq::mock::{
+        ifreq_get_data, ifreq_get_flags, ifreq_get_lladdr, ifreq_get_mtu, ifreq_get_name,
+        ifreq_set_flags, ifreq_set_lladdr, ifreq_set_mtu,
+    };
+    use crate::{ErrorKind, IfFlags, IfId, Link, Operation};
+    use mockdown::Mockdown;
+    use std::sync::LazyLock;
+
+    impl LibcSocket {
+        fn new(sys: &MockSys) -> LibcSocket {
+            LibcSocket(BoxSys(Box::new(sys.clone())))
+        }
+    }
+
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+    static LINKS: LazyLock<Vec<Link>> =
+        LazyLock::new(|| vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::default())]);
+
+    const MOCK_FD: libc::c_int = 3;
+
+    const RETURN_FD: libc::c_int = MOCK_FD;
+    const RETURN_SUCCESS: libc::c_int = 0;
+    const RETURN_FAILURE: libc::c_int = -1;
+
+    const MOCK_SOCKET: mock::Socket = mock::Socket(libc::AF_LOCAL, libc::SOCK_DGRAM, 0);
+    const MOCK_CLOSE: mock::Close = mock::Close(MOCK_FD);
+
+    #[test]
+    fn test_socket_box_default() {
+        let expected_default = "BoxSocket(LibcSocket(BoxSys(LibcSys)))";
+
+        let box_socket = super::BoxSocket::default();
+
+        assert_eq!(format!("{:?}", box_socket), expected_default);
+    }
+
+    #[test]
+    fn test_socket_box_debug() {
+        let socket = super::LibcSocket::default();
+        let expected_debug = "BoxSocket(LibcSocket(BoxSys(LibcSys)))";
+
+        let box_socket = super::BoxSocket(Box::new(socket));
+
+        assert_eq!(format!("{:?}", box_socket), expected_debug);
+    }
+
+    #[test]
+    fn test_socket_box_deref() {
+        let socket = super::LibcSocket::default();
+        let expected_deref = "LibcSocket(BoxSys(LibcSys))";
+
+        let deref_box_socket = &*super::BoxSocket(Box::new(socket));
+
+        assert_eq!(format!("{:?}", deref_box_socket), expected_deref);
+    }
+
+    #[test]
+    fn test_socket_get_name() {
+        let sys = MockSys::default().expect(|mock::IfIndexToName(ifindex, ifname)| {
+            assert_eq!(ifindex, 2);
+            unsafe { std::ptr::copy_nonoverlapping(c"enx".as_ptr(), ifname, 4) };
+            ifname
+        });
+
+        let ifname = LibcSocket::new(&sys).get_name(2.into()).unwrap();
+
+        assert_eq!(ifname, IFNAME);
+    }
+
+    #[test]
+    fn test_open_socket_box_debug() {
+        let sys = &BoxSys::default();
+        let expected_debug = "LibcOpenSocket { fd: 3, sys: BoxSys(LibcSys) }";
+
+        let box_open_socket: Box<dyn super::OpenSocket> =
+            Box::new(super::LibcOpenSocket { fd: MOCK_FD, sys });
+
+        assert_eq!(format!("{:?}", box_open_socket), expected_debug);
+    }
+
+    #[test]
+    fn test_socket_open_local_dgram() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                10
+            })
+            .expect(|args| {
+                assert_eq!(mock::Close(10), args);
+                RETURN_SUCCESS
+            });
+
+        let expected_open_socket = "LibcOpenSocket { fd: 10, sys: BoxSys(MockSys) }";
+        let socket = LibcSocket::new(&sys);
+
+        let open_socket = socket.open_local_dgram().unwrap();
+
+        assert_eq!(format!("{:?}", open_socket), expected_open_socket);
+    }
+
+    #[test]
+    fn test_socket_open_local_dgram_error() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EPERM);
+
+        let expected_error = "Socket::OpenLocalDgramError { ret: -1, errno: 1, strerror: \"Operation not permitted\" }";
+        let socket = LibcSocket::new(&sys);
+
+        let error = socket.open_local_dgram().unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+    }
+
+    #[test]
+    fn test_open_socket_get_lladdr() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFHWADDR), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap();
+
+        assert_eq!(ifreq::get_lladdr(&ifreq), LLADDR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_lladdr_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFHWADDR), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EBADF)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetLinkLevelAddressError { fd: 3, ifname: \"enx\", ret: -1, errno: 9, strerror: \"Bad file descriptor\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_lladdr() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFHWADDR), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_lladdr(&mut ifreq, &LLADDR);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_lladdr_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFHWADDR), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EINVAL)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::SetLinkLevelAddressError { fd: 3, ifname: \"enx\", lladdr: \"00:11:22:33:44:55\", ret: -1, errno: 22, strerror: \"Invalid argument\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_lladdr(&mut ifreq, &LLADDR);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidArgument);
+        assert_eq!(error.operation(), Operation::SetLinkLevelAddress);
+        assert_eq!(error.ifid(), Some(IfId::from(IFNAME)));
+        assert_eq!(error.lladdr(), Some(LLADDR));
+        assert_eq!(error.errno(), Some(libc::EINVAL));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_perm_lladdr() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCETHTOOL), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                let perm_addr = ifreq_get_data(ifreq);
+                assert_eq!(ethtool_get_cmd(perm_addr), ethtool::ETHTOOL_GPERMADDR);
+                ethtool_set_lladdr(perm_addr, LLADDR);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut perm_addr = ethtool::new();
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_data(&mut ifreq, ethtool::as_mut_ptr(&mut perm_addr));
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_perm_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        assert_eq!(ethtool::get_lladdr(&perm_addr), LLADDR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_perm_lladdr_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCETHTOOL), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EOPNOTSUPP)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetPermanentLinkLevelAddressError { fd: 3, ifname: \"enx\", ret: -1, errno: 95, strerror: \"Operation not supported\" }";
+        let mut perm_addr = ethtool::new();
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_data(&mut ifreq, ethtool::as_mut_ptr(&mut perm_addr));
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_perm_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_flags() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        assert!(ifreq::get_flags(&ifreq).is_up());
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_flags_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::ENXIO)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetFlagsError { fd: 3, ifname: \"enx\", ret: -1, errno: 6, strerror: \"No such device or address\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_flags(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_flags() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert!(ifreq_get_flags(ifreq).is_up());
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_flags(&mut ifreq, IfFlags::from(libc::IFF_UP as u32));
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_mtu() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFMTU), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_mtu(ifreq, 1420);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        assert_eq!(ifreq::get_mtu(&ifreq), 1420);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_mtu_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, _)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFMTU), args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::ENXIO)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetMtuError { fd: 3, ifname: \"enx\", ret: -1, errno: 6, strerror: \"No such device or address\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_mtu() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFMTU), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_mtu(ifreq), 1420);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_mtu(&mut ifreq, 1420);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_mtu_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, _)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFMTU), args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EINVAL)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::SetMtuError { fd: 3, ifname: \"enx\", mtu: 70000, ret: -1, errno: 22, strerror: \"Invalid argument\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_mtu(&mut ifreq, 70000);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_flags_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EPERM)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::SetFlagsError { fd: 3, ifname: \"enx\", flags: \"UP\", ret: -1, errno: 1, strerror: \"Operation not permitted\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_flags(&mut ifreq, IfFlags::from(libc::IFF_UP as u32));
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_flags(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, ErrorKind::PermissionDenied);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_close() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let socket = LibcSocket::new(&sys);
+
+        let open_socket = socket.open_local_dgram().unwrap();
+
+        drop(open_socket);
+    }
+
+    #[test]
+    fn test_open_socket_close_error() {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EINTR);
+
+        let socket = LibcSocket::new(&sys);
+
+        let open_socket = socket.open_local_dgram().unwrap();
+
+        drop(open_socket);
+    }
+
+    #[test]
+    fn test_socket_get_links() {
+        let sys = MockSys::default()
+            .expect(|mock::GetIfAddrs(ifap)| {
+                unsafe { *ifap = ifaddrs_new(&LINKS) };
+                RETURN_SUCCESS
+            })
+            .expect(|mock::FreeIfAddrs(ifa)| ifaddrs_free(ifa));
+
+        let links = LibcSocket::new(&sys).get_links().unwrap();
+
+        assert_eq!(links, *LINKS);
+    }
+
+    #[test]
+    fn test_socket_get_links_error() {
+        let sys = MockSys::default()
+            .expect(|mock::GetIfAddrs(_)| RETURN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::ENOMEM);
+
+        let expected_error =
+            "Socket::GetIfAddrsError { ret: -1, errno: 12, strerror: \"Cannot allocate memory\" }";
+
+        let error = LibcSocket::new(&sys).get_links().unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::{Error, OpenSocket, Socket, SocketResult};
+    use crate::{IfIndex, IfName, Link, Result};
+    use mockdown::{ExpectStore, Mockdown};
+    use std::ops::Deref;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct OpenLocalDgram();
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetLinks();
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetName(pub IfIndex);
+    pub(crate) type ErrNo = Option<i32>;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetLLAddr(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetLLAddr(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetFlags(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetFlags(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetMtu(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetMtu(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetPermLLAddr(pub *mut libc::c_void);
+
+    #[derive(Clone, Debug, Default)]
+    pub(crate) struct MockSocket(ExpectStore);
+
+    impl Mockdown for MockSocket {
+        fn store(&self) -> &ExpectStore {
+            &self.0
+        }
+    }
+
+    impl Socket for MockSocket {
+        fn open_local_dgram(&self) -> SocketResult<'_> {
+            let args = OpenLocalDgram();
+            let on_mock: ErrNo = self.on_mock(args).unwrap();
+            match on_mock {
+                None => Ok(Box::new(MockOpenSocket(self))),
+                Some(errno) => Err(crate::Error::from(Error::OpenLocalDgram(-1, errno)).into()),
+            }
+        }
+
+        fn get_links(&self) -> Result<Vec<Link>> {
+            let args = GetLinks();
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_name(&self, ifindex: IfIndex) -> Result<IfName> {
+            let args = GetName(ifindex);
+            self.on_mock(args).unwrap()
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct MockOpenSocket<'a>(pub &'a MockSocket);
+
+    impl<'a> Deref for MockOpenSocket<'a> {
+        type Target = &'a MockSocket;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<'a> OpenSocket for MockOpenSocket<'a> {
+        fn get_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetLLAddr(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = SetLLAddr(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetFlags(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = SetFlags(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetMtu(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = SetMtu(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_perm_lladdr(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetPermLLAddr(arg);
+            self.on_mock(args).unwrap()
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use libc::{c_char, c_int, c_uint, c_ulong, c_void, ifaddrs};
+#[cfg(feature = "netlink")]
+use libc::{size_t, sockaddr, socklen_t, ssize_t};
+use std::{fmt::Debug, ops::Deref};
+
+// Get hardware address
+// SIOCGIFHWADDR = 0x8927
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCGIFHWADDR: c_ulong = libc::SIOCGIFHWADDR;
+
+// Set hardware address
+// SIOCSIFHWADDR = 0x8924
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCSIFHWADDR: c_ulong = libc::SIOCSIFHWADDR;
+
+// Get flags
+// SIOCGIFFLAGS = 0x8913
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCGIFFLAGS: c_ulong = libc::SIOCGIFFLAGS;
+
+// Set flags
+// SIOCSIFFLAGS = 0x8914
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCSIFFLAGS: c_ulong = libc::SIOCSIFFLAGS;
+
+// Get MTU
+// SIOCGIFMTU = 0x8921
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCGIFMTU: c_ulong = libc::SIOCGIFMTU;
+
+// Set MTU
+// SIOCSIFMTU = 0x8922
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCSIFMTU: c_ulong = libc::SIOCSIFMTU;
+
+// Ethtool interface
+// SIOCETHTOOL = 0x8946
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/sockios.h
+pub(super) const SIOCETHTOOL: c_ulong = libc::SIOCETHTOOL;
+
+pub(super) fn strerror(errno: c_int) -> String {
+    let ptr = unsafe { libc::strerror(errno) };
+    let c_str = unsafe { std::ffi::CStr::from_ptr(ptr) };
+    c_str.to_bytes().escape_ascii().to_string()
+}
+
+pub(super) trait Sys: Debug {
+    fn socket(&self, domain: c_int, ty: c_int, protocol: c_int) -> c_int;
+    fn ioctl(&self, fd: c_int, request: c_ulong, arg: *mut c_void) -> c_int;
+    #[cfg(feature = "netlink")]
+    fn bind(&self, fd: c_int, addr: *const sockaddr, len: socklen_t) -> c_int;
+    #[cfg(feature = "netlink")]
+    fn getsockname(&self, fd: c_int, addr: *mut sockaddr, len: *mut socklen_t) -> c_int;
+    #[cfg(feature = "netlink")]
+    fn send(&self, fd: c_int, buf: *const c_void, len: size_t, flags: c_int) -> ssize_t;
+    #[cfg(feature = "netlink")]
+    fn recv(&self, fd: c_int, buf: *mut c_void, len: size_t, flags: c_int) -> ssize_t;
+    fn close(&self, fd: c_int) -> c_int;
+    fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int;
+    fn freeifaddrs(&self, ifa: *mut ifaddrs);
+    fn if_nametoindex(&self, ifname: *const c_char) -> c_uint;
+    fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char;
+    fn errno(&self) -> c_int;
+}
+
+#[derive(Debug, Default)]
+pub(super) struct BoxSys(pub(super) Box<dyn Sys>);
+
+impl Default for Box<dyn Sys> {
+    fn default() -> Self {
+        Box::new(LibcSys::default())
+    }
+}
+
+impl Deref for BoxSys {
+    type Target = Box<dyn Sys>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct LibcSys {}
+
+#[cfg(not(tarpaulin_include))]
+impl Sys for LibcSys {
+    fn socket(&self, domain: c_int, ty: c_int, protocol: c_int) -> c_int {
+        unsafe { libc::socket(domain, ty, protocol) }
+    }
+
+    fn ioctl(&self, fd: c_int, request: c_ulong, arg: *mut c_void) -> c_int {
+        // glibc takes the request as c_ulong, musl as c_int
+        unsafe { libc::ioctl(fd, request as libc::Ioctl, arg) }
+    }
+
+    #[cfg(feature = "netlink")]
+    fn bind(&self, fd: c_int, addr: *const sockaddr, len: socklen_t) -> c_int {
+        unsafe { libc::bind(fd, addr, len) }
+    }
+
+    #[cfg(feature = "netlink")]
+    fn getsockname(&self, fd: c_int, addr: *mut sockaddr, len: *mut socklen_t) -> c_int {
+        unsafe { libc::getsockname(fd, addr, len) }
+    }
+
+    #[cfg(feature = "netlink")]
+    fn send(&self, fd: c_int, buf: *const c_void, len: size_t, flags: c_int) -> ssize_t {
+        unsafe { libc::send(fd, buf, len, flags) }
+    }
+
+    #[cfg(feature = "netlink")]
+    fn recv(&self, fd: c_int, buf: *mut c_void, len: size_t, flags: c_int) -> ssize_t {
+        unsafe { libc::recv(fd, buf, len, flags) }
+    }
+
+    fn close(&self, fd: c_int) -> c_int {
+        unsafe { libc::close(fd) }
+    }
+
+    fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int {
+        unsafe { libc::getifaddrs(ifap) }
+    }
+
+    fn freeifaddrs(&self, ifa: *mut ifaddrs) {
+        unsafe { libc::freeifaddrs(ifa) }
+    }
+
+    fn if_nametoindex(&self, ifname: *const c_char) -> c_uint {
+        unsafe { libc::if_nametoindex(ifname) }
+    }
+
+    fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char {
+        unsafe { libc::if_indextoname(ifindex, ifname) }
+    }
+
+    fn errno(&self) -> c_int {
+        unsafe { *libc::__errno_location() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_get_hardware_addr() {
+        assert_eq!(super::SIOCGIFHWADDR, 0x8927)
+    }
+
+    #[test]
+    fn test_set_hardware_addr() {
+        assert_eq!(super::SIOCSIFHWADDR, 0x8924)
+    }
+
+    #[test]
+    fn test_get_flags() {
+        assert_eq!(super::SIOCGIFFLAGS, 0x8913)
+    }
+
+    #[test]
+    fn test_set_flags() {
+        assert_eq!(super::SIOCSIFFLAGS, 0x8914)
+    }
+
+    #[test]
+    fn test_get_mtu() {
+        assert_eq!(super::SIOCGIFMTU, 0x8921)
+    }
+
+    #[test]
+    fn test_set_mtu() {
+        assert_eq!(super::SIOCSIFMTU, 0x8922)
+    }
+
+    #[test]
+    fn test_ethtool() {
+        assert_eq!(super::SIOCETHTOOL, 0x8946)
+    }
+
+    #[test]
+    fn test_sys_strerror() {
+        let errno = 1;
+
+        let strerror = super::strerror(errno);
+
+        assert_eq!(strerror, "Operation not permitted");
+    }
+
+    #[test]
+    fn test_sys_strerror_undefined_errno() {
+        let errno = 0;
+
+        let strerror = super::strerror(errno);
+
+        assert_eq!(strerror, "Success");
+    }
+
+    #[test]
+    fn test_sys_strerror_unknown_errno() {
+        let errno = -1;
+
+        let strerror = super::strerror(errno);
+
+        assert_eq!(strerror, "Unknown error -1");
+    }
+
+    #[test]
+    fn test_sys_box_default() {
+        let expected_default = "BoxSys(LibcSys)";
+
+        let box_sys = super::BoxSys::default();
+
+        assert_eq!(format!("{:?}", box_sys), expected_default);
+    }
+
+    #[test]
+    fn test_sys_box_debug() {
+        let sys = super::LibcSys::default();
+        let expected_debug = "BoxSys(LibcSys)";
+
+        let box_sys = super::BoxSys(Box::new(sys));
+
+        assert_eq!(format!("{:?}", box_sys), expected_debug);
+    }
+
+    #[test]
+    fn test_sys_box_deref() {
+        let sys = super::LibcSys::default();
+        let expected_deref = "LibcSys";
+
+        let deref_box_sys = &*super::BoxSys(Box::new(sys));
+
+        assert_eq!(format!("{:?}", deref_box_sys), expected_deref);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::Sys;
+    use libc::{c_char, c_int, c_uint, c_ulong, c_void, ifaddrs};
+    #[cfg(feature = "netlink")]
+    use libc::{size_t, sockaddr, socklen_t, ssize_t};
+    use mockdown::{ExpectStore, Mockdown};
+    use std::fmt::Debug;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Socket(pub libc::c_int, pub libc::c_int, pub libc::c_int);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct IoCtl(pub (libc::c_int, libc::c_ulong), pub *mut libc::c_void);
+    // Only netlink sockets are bound, so the address is reduced to nl_groups.
+    #[cfg(feature = "netlink")]
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Bind(pub libc::c_int, pub u32);
+    // Answered with (ret, nl_pid), the port id the socket got bound to.
+    #[cfg(feature = "netlink")]
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetSockName(pub libc::c_int);
+    #[cfg(feature = "netlink")]
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Send(pub libc::c_int, pub Vec<u8>, pub libc::c_int);
+    #[cfg(feature = "netlink")]
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Recv(
+        pub (libc::c_int, libc::c_int),
+        pub *mut libc::c_void,
+        pub libc::size_t,
+    );
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Close(pub libc::c_int);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetIfAddrs(pub *mut *mut libc::ifaddrs);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct FreeIfAddrs(pub *mut libc::ifaddrs);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct IfNameToIndex(pub String);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct IfIndexToName(pub libc::c_uint, pub *mut libc::c_char);
+    #[derive(Debug)]
+    pub(crate) struct ErrNo();
+
+    #[derive(Clone, Default)]
+    pub(crate) struct MockSys(ExpectStore);
+
+    impl Debug for MockSys {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("MockSys").finish()
+        }
+    }
+
+    impl Mockdown for MockSys {
+        fn store(&self) -> &ExpectStore {
+            &self.0
+        }
+    }
+
+    impl Sys for MockSys {
+        fn socket(&self, domain: c_int, ty: c_int, protocol: c_int) -> c_int {
+            let args = Socket(domain, ty, protocol);
+            self.on_mock(args).unwrap()
+        }
+
+        fn ioctl(&self, fd: c_int, request: c_ulong, arg: *mut c_void) -> c_int {
+            let args = IoCtl((fd, request), arg);
+            self.on_mock(args).unwrap()
+        }
+
+        #[cfg(feature = "netlink")]
+        fn bind(&self, fd: c_int, addr: *const sockaddr, len: socklen_t) -> c_int {
+            assert_eq!(len as usize, std::mem::size_of::<libc::sockaddr_nl>());
+            let addr = unsafe { &*(addr as *const libc::sockaddr_nl) };
+            let args = Bind(fd, addr.nl_groups);
+            self.on_mock(args).unwrap()
+        }
+
+        #[cfg(feature = "netlink")]
+        fn getsockname(&self, fd: c_int, addr: *mut sockaddr, len: *mut socklen_t) -> c_int {
+            assert_eq!(
+                unsafe { *len } as usize,
+                std::mem::size_of::<libc::sockaddr_nl>()
+            );
+            let args = GetSockName(fd);
+            let (ret, pid): (c_int, u32) = self.on_mock(args).unwrap();
+            let addr = unsafe { &mut *(addr as *mut libc::sockaddr_nl) };
+            addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+            addr.nl_pid = pid;
+            ret
+        }
+
+        #[cfg(feature = "netlink")]
+        fn send(&self, fd: c_int, buf: *const c_void, len: size_t, flags: c_int) -> ssize_t {
+            let buf = unsafe { std::slice::from_raw_parts(buf as *const u8, len) };
+            let args = Send(fd, buf.to_vec(), flags);
+            self.on_mock(args).unwrap()
+        }
+
+        #[cfg(feature = "netlink")]
+        fn recv(&self, fd: c_int, buf: *mut c_void, len: size_t, flags: c_int) -> ssize_t {
+            let args = Recv((fd, flags), buf, len);
+            self.on_mock(args).unwrap()
+        }
+
+        fn close(&self, fd: c_int) -> c_int {
+            let args = Close(fd);
+            self.on_mock(args).unwrap()
+        }
+
+        fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int {
+            let args = GetIfAddrs(ifap);
+            self.on_mock(args).unwrap()
+        }
+
+        fn freeifaddrs(&self, ifa: *mut ifaddrs) {
+            let args = FreeIfAddrs(ifa);
+            self.on_mock(args).unwrap()
+        }
+
+        fn if_nametoindex(&self, ifname: *const c_char) -> c_uint {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(ifname) };
+            let args = IfNameToIndex(c_str.to_string_lossy().into_owned());
+            self.on_mock(args).unwrap()
+        }
+
+        fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char {
+            let args = IfIndexToName(ifindex, ifname);
+            self.on_mock(args).unwrap()
+        }
+
+        fn errno(&self) -> c_int {
+            let args = ErrNo();
+            self.on_mock(args).unwrap()
+        }
+    }
+}
@@ -0,0 +1,703 @@
+use super::ifindex::IfIndex;
+use super::ifname::IfName;
+use crate::{ErrorKind, HardwareAddress, IfFlags, IfId, Link, LinkLevelAddress, Operation, Result};
+use core::fmt::{Debug, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-class-net
+const SYSFS_CLASS_NET: &str = "/sys/class/net";
+
+enum Error {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, String),
+    NoSuchIndex(PathBuf, IfIndex),
+    Unsupported(Operation, IfId),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Read(path, error) => f
+                .debug_struct("Sysfs::ReadError")
+                .field("path", path)
+                .field("error", &error.to_string())
+                .finish(),
+            Error::Parse(path, value) => f
+                .debug_struct("Sysfs::ParseError")
+                .field("path", path)
+                .field("value", value)
+                .finish(),
+            Error::NoSuchIndex(root, ifindex) => f
+                .debug_struct("Sysfs::NoSuchIndexError")
+                .field("root", root)
+                .field("ifindex", ifindex)
+                .finish(),
+            Error::Unsupported(op, ifid) => f
+                .debug_struct("Sysfs::UnsupportedError")
+                .field("op", op)
+                .field("ifid", ifid)
+                .finish(),
+        }
+    }
+}
+
+/// How the kernel came by the current address, from `addr_assign_type`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum AddrAssignType {
+    Permanent,
+    Random,
+    Stolen,
+    Set,
+    Unknown(u8),
+}
+
+impl From<u8> for AddrAssignType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Permanent,
+            1 => Self::Random,
+            2 => Self::Stolen,
+            3 => Self::Set,
+            value => Self::Unknown(value),
+        }
+    }
+}
+
+/// RFC 2863 operational state, from `operstate`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum OperState {
+    Unknown,
+    NotPresent,
+    Down,
+    LowerLayerDown,
+    Testing,
+    Dormant,
+    Up,
+}
+
+impl From<&str> for OperState {
+    fn from(value: &str) -> Self {
+        match value {
+            "notpresent" => Self::NotPresent,
+            "down" => Self::Down,
+            "lowerlayerdown" => Self::LowerLayerDown,
+            "testing" => Self::Testing,
+            "dormant" => Self::Dormant,
+            "up" => Self::Up,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LinkInfo {
+    ifname: IfName,
+    index: IfIndex,
+    lladdr: LinkLevelAddress,
+    hwaddr: HardwareAddress,
+    addr_assign_type: AddrAssignType,
+    link_type: u16,
+    operstate: OperState,
+    carrier: Option<bool>,
+    driver: Option<String>,
+}
+
+impl LinkInfo {
+    pub fn ifname(&self) -> &IfName {
+        &self.ifname
+    }
+
+    pub fn index(&self) -> IfIndex {
+        self.index
+    }
+
+    pub fn lladdr(&self) -> &LinkLevelAddress {
+        &self.lladdr
+    }
+
+    /// The address at its full length, for links that are not Ethernet.
+    pub fn hwaddr(&self) -> &HardwareAddress {
+        &self.hwaddr
+    }
+
+    pub fn addr_assign_type(&self) -> AddrAssignType {
+        self.addr_assign_type
+    }
+
+    /// The `ARPHRD_*` hardware type.
+    pub fn link_type(&self) -> u16 {
+        self.link_type
+    }
+
+    pub fn operstate(&self) -> OperState {
+        self.operstate
+    }
+
+    /// `None` while the interface is administratively down.
+    pub fn carrier(&self) -> Option<bool> {
+        self.carrier
+    }
+
+    /// `None` for virtual interfaces, which are not bound to a driver.
+    pub fn driver(&self) -> Option<&str> {
+        self.driver.as_deref()
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Sysfs {
+    root: PathBuf,
+}
+
+impl Default for Sysfs {
+    fn default() -> Self {
+        Sysfs::new(SYSFS_CLASS_NET)
+    }
+}
+
+impl Sysfs {
+    pub(super) fn new(root: impl Into<PathBuf>) -> Sysfs {
+        Sysfs { root: root.into() }
+    }
+
+    fn path(&self, ifname: &IfName, attr: &str) -> PathBuf {
+        self.root.join(ifname.to_string()).join(attr)
+    }
+
+    // A missing interface directory means there is no such interface.
+    fn read_error(
+        &self,
+        op: Operation,
+        path: &Path,
+        ifname: Option<&IfName>,
+        error: io::Error,
+    ) -> crate::Error {
+        let errno = error.raw_os_error();
+        let error = Error::Read(path.to_path_buf(), error);
+        let error = match (errno, ifname) {
+            (Some(libc::ENOENT), Some(ifname)) if !self.root.join(ifname.to_string()).exists() => {
+                crate::Error::from_errno(libc::ENODEV, op, error)
+            }
+            (Some(errno), _) => crate::Error::from_errno(errno, op, error),
+            (None, _) => crate::Error::new(ErrorKind::Other, op, error),
+        };
+        match ifname {
+            Some(ifname) => error.with_ifid(*ifname),
+            None => error,
+        }
+    }
+
+    fn read(&self, op: Operation, ifname: &IfName, attr: &str) -> Result<String> {
+        let path = self.path(ifname, attr);
+        match std::fs::read_to_string(&path) {
+            Ok(value) => Ok(value.trim_end().to_string()),
+            Err(error) => Err(self.read_error(op, &path, Some(ifname), error).into()),
+        }
+    }
+
+    fn parse<T: FromStr>(&self, op: Operation, ifname: &IfName, attr: &str) -> Result<T> {
+        let value = self.read(op, ifname, attr)?;
+        let path = self.path(ifname, attr);
+        value.parse().map_err(|_| Error::Parse(path, value).into())
+    }
+
+    fn parse_flags(&self, op: Operation, ifname: &IfName) -> Result<IfFlags> {
+        let value = self.read(op, ifname, "flags")?;
+        let path = self.path(ifname, "flags");
+        match u32::from_str_radix(value.trim_start_matches("0x"), 16) {
+            Ok(flags) => Ok(IfFlags::from(flags)),
+            Err(_) => Err(Error::Parse(path, value).into()),
+        }
+    }
+
+    // Devices without an address, such as tunnels, read back empty.
+    fn parse_lladdr(&self, ifname: &IfName) -> LinkLevelAddress {
+        self.parse(Operation::GetLinkLevelAddress, ifname, "address")
+            .unwrap_or(LinkLevelAddress::from(&[0; 6]))
+    }
+
+    fn parse_hwaddr(&self, op: Operation, ifname: &IfName) -> Result<HardwareAddress> {
+        let hwtype = self.parse(op, ifname, "type")?;
+        let value = self.read(op, ifname, "address")?;
+        let path = self.path(ifname, "address");
+        HardwareAddress::parse(hwtype, &value).map_err(|_| Error::Parse(path, value).into())
+    }
+
+    fn get_driver(&self, ifname: &IfName) -> Option<String> {
+        let path = self.path(ifname, "device/driver");
+        let driver = std::fs::read_link(path).ok()?;
+        Some(driver.file_name()?.to_string_lossy().into_owned())
+    }
+
+    fn get_ifnames(&self) -> Result<Vec<IfName>> {
+        let entries = std::fs::read_dir(&self.root)
+            .map_err(|error| self.read_error(Operation::GetLinks, &self.root, None, error))?;
+        // Skip stray files such as `bonding_masters`, links are directories
+        // with an `ifindex`.
+        let mut ifnames: Vec<IfName> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().join("ifindex").is_file())
+            .map(|entry| IfName::from_bytes(entry.file_name().as_encoded_bytes()))
+            .collect();
+        ifnames.sort_by_key(|ifname| self.get_index(ifname).unwrap_or(u32::MAX));
+        Ok(ifnames)
+    }
+
+    pub(super) fn get_name(&self, ifid: IfId) -> Result<IfName> {
+        match ifid {
+            IfId::Name(ifname) => Ok(ifname),
+            IfId::Index(ifindex) => self
+                .get_ifnames()?
+                .into_iter()
+                .find(|ifname| self.get_index(ifname).ok() == Some(*ifindex))
+                .ok_or_else(|| {
+                    let error = Error::NoSuchIndex(self.root.clone(), ifindex);
+                    crate::Error::new(ErrorKind::NoSuchDevice, Operation::ResolveName, error)
+                        .with_ifid(ifindex)
+                        .into()
+                }),
+        }
+    }
+
+    fn get_index(&self, ifname: &IfName) -> Result<u32> {
+        self.parse(Operation::ResolveName, ifname, "ifindex")
+    }
+
+    pub(super) fn get_lladdr(&self, ifid: IfId) -> Result<LinkLevelAddress> {
+        let op = Operation::GetLinkLevelAddress;
+        self.parse(op, &self.get_name(ifid)?, "address")
+    }
+
+    pub(super) fn get_flags(&self, ifid: IfId) -> Result<IfFlags> {
+        self.parse_flags(Operation::GetFlags, &self.get_name(ifid)?)
+    }
+
+    pub(super) fn get_hwaddr(&self, ifid: IfId) -> Result<HardwareAddress> {
+        self.parse_hwaddr(Operation::GetLinkLevelAddress, &self.get_name(ifid)?)
+    }
+
+    pub(super) fn get_mtu(&self, ifid: IfId) -> Result<u32> {
+        self.parse(Operation::GetMtu, &self.get_name(ifid)?, "mtu")
+    }
+
+    pub(super) fn get_link(&self, ifid: IfId) -> Result<Link> {
+        let ifname = self.get_name(ifid)?;
+        let link = Link::new(
+            ifname,
+            IfIndex::from(self.parse::<u32>(Operation::GetLinks, &ifname, "ifindex")?),
+            self.parse_lladdr(&ifname),
+            self.parse_flags(Operation::GetLinks, &ifname)?,
+        );
+        Ok(match self.parse_hwaddr(Operation::GetLinks, &ifname) {
+            Ok(hwaddr) => link.with_hwaddr(hwaddr),
+            Err(_) => link,
+        })
+    }
+
+    pub(super) fn get_links(&self) -> Result<Vec<Link>> {
+        self.get_ifnames()?
+            .into_iter()
+            .map(|ifname| self.get_link(IfId::from(ifname)))
+            .collect()
+    }
+
+    pub(super) fn get_info(&self, ifid: IfId) -> Result<LinkInfo> {
+        let op = Operation::GetInfo;
+        let ifname = self.get_name(ifid)?;
+        Ok(LinkInfo {
+            ifname,
+            index: IfIndex::from(self.parse::<u32>(op, &ifname, "ifindex")?),
+            lladdr: self.parse_lladdr(&ifname),
+            hwaddr: self.parse_hwaddr(op, &ifname)?,
+            addr_assign_type: AddrAssignType::from(self.parse::<u8>(
+                op,
+                &ifname,
+                "addr_assign_type",
+            )?),
+            link_type: self.parse(op, &ifname, "type")?,
+            operstate: OperState::from(self.read(op, &ifname, "operstate")?.as_str()),
+            carrier: self
+                .parse::<u8>(op, &ifname, "carrier")
+                .ok()
+                .map(|carrier| carrier != 0),
+            driver: self.get_driver(&ifname),
+        })
+    }
+}
+
+/// Sysfs is read-only; the `Nic` methods that change a link end up here.
+pub(super) fn unsupported<T>(op: Operation, ifid: IfId) -> Result<T> {
+    let error = Error::Unsupported(op, ifid);
+    Err(crate::Error::new(ErrorKind::Unsupported, op, error)
+        .with_ifid(ifid)
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::SysfsTree;
+    use super::{AddrAssignType, OperState, Sysfs};
+    use crate::{
+        ErrorKind, HardwareAddress, IfFlags, IfId, IfIndex, IfName, Link, LinkLevelAddress,
+        Operation,
+    };
+
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+
+    #[test]
+    fn test_sysfs_default() {
+        assert_eq!(
+            format!("{:?}", Sysfs::default()),
+            "Sysfs { root: \"/sys/class/net\" }"
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_lladdr() {
+        let tree = SysfsTree::new("get_lladdr").with_link("enx", 2, "00:11:22:33:44:55");
+
+        let lladdr = Sysfs::new(tree.root())
+            .get_lladdr(IfId::from(IFNAME))
+            .unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_sysfs_get_lladdr_by_index() {
+        let tree = SysfsTree::new("get_lladdr_by_index")
+            .with_link("lo0", 1, "00:00:00:00:00:00")
+            .with_link("enx", 2, "00:11:22:33:44:55");
+
+        let lladdr = Sysfs::new(tree.root())
+            .get_lladdr(IfId::from(IfIndex::from(2)))
+            .unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_sysfs_get_lladdr_no_such_index() {
+        let tree = SysfsTree::new("no_such_index").with_link("enx", 2, "00:11:22:33:44:55");
+
+        let error = Sysfs::new(tree.root())
+            .get_lladdr(IfId::from(IfIndex::from(7)))
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            format!(
+                "Sysfs::NoSuchIndexError {{ root: {:?}, ifindex: 7 }}",
+                tree.root()
+            )
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_lladdr_read_error() {
+        let tree = SysfsTree::new("read_error");
+
+        let error = Sysfs::new(tree.root())
+            .get_lladdr(IfId::from(IFNAME))
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            format!(
+                "Sysfs::ReadError {{ path: {:?}, error: \"No such file or directory (os error 2)\" }}",
+                tree.root().join("enx/address")
+            )
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_lladdr_no_such_device() {
+        let tree = SysfsTree::new("no_such_device").with_link("eth0", 2, "00:11:22:33:44:55");
+
+        let error = Sysfs::new(tree.root())
+            .get_lladdr(IfId::from(IFNAME))
+            .unwrap_err();
+
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::NoSuchDevice);
+        assert_eq!(error.operation(), Operation::GetLinkLevelAddress);
+        assert_eq!(error.ifid(), Some(IfId::from(IFNAME)));
+    }
+
+    #[test]
+    fn test_sysfs_get_mtu_missing_attr() {
+        let tree = SysfsTree::new("missing_attr")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .without_attr("enx", "mtu");
+
+        let error = Sysfs::new(tree.root())
+            .get_mtu(IfId::from(IFNAME))
+            .unwrap_err();
+
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Other);
+        assert_eq!(error.errno(), Some(libc::ENOENT));
+    }
+
+    #[test]
+    fn test_sysfs_get_flags() {
+        let tree = SysfsTree::new("get_flags")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_attr("enx", "flags", "0x1003\n");
+
+        let flags = Sysfs::new(tree.root())
+            .get_flags(IfId::from(IFNAME))
+            .unwrap();
+
+        assert_eq!(flags, IfFlags::from(0x1003));
+    }
+
+    #[test]
+    fn test_sysfs_get_flags_parse_error() {
+        let tree = SysfsTree::new("parse_error")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_attr("enx", "flags", "0xzz\n");
+
+        let error = Sysfs::new(tree.root())
+            .get_flags(IfId::from(IFNAME))
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            format!(
+                "Sysfs::ParseError {{ path: {:?}, value: \"0xzz\" }}",
+                tree.root().join("enx/flags")
+            )
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_hwaddr() {
+        let tree = SysfsTree::new("get_hwaddr")
+            .with_link(
+                "ib0",
+                2,
+                "80:00:02:08:fe:80:00:00:00:00:00:00:00:02:c9:03:00:0c:73:61",
+            )
+            .with_attr("ib0", "type", "32\n");
+
+        let hwaddr = Sysfs::new(tree.root())
+            .get_hwaddr(IfId::from(IfName::try_from("ib0").unwrap()))
+            .unwrap();
+
+        assert_eq!(hwaddr.hwtype(), 32);
+        assert_eq!(hwaddr.len(), 20);
+    }
+
+    #[test]
+    fn test_sysfs_get_hwaddr_parse_error() {
+        let tree = SysfsTree::new("get_hwaddr_parse_error").with_link("enx", 2, "00:11:zz");
+
+        let error = Sysfs::new(tree.root())
+            .get_hwaddr(IfId::from(IFNAME))
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            format!(
+                "Sysfs::ParseError {{ path: {:?}, value: \"00:11:zz\" }}",
+                tree.root().join("enx/address")
+            )
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_mtu() {
+        let tree = SysfsTree::new("get_mtu")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_attr("enx", "mtu", "1420\n");
+
+        let mtu = Sysfs::new(tree.root()).get_mtu(IfId::from(IFNAME)).unwrap();
+
+        assert_eq!(mtu, 1420);
+    }
+
+    #[test]
+    fn test_sysfs_get_links() {
+        let tree = SysfsTree::new("get_links")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_link("lo0", 1, "")
+            .with_attr("lo0", "flags", "0x9\n")
+            .with_attr("lo0", "type", "772\n");
+
+        let links = Sysfs::new(tree.root()).get_links().unwrap();
+
+        assert_eq!(
+            links,
+            vec![
+                Link::new(
+                    "lo0".try_into().unwrap(),
+                    1.into(),
+                    LinkLevelAddress::from(&[0; 6]),
+                    IfFlags::from(0x9)
+                )
+                .with_hwaddr(HardwareAddress::new(libc::ARPHRD_LOOPBACK, &[]).unwrap()),
+                Link::new(IFNAME, 2.into(), LLADDR, IfFlags::from(0x1003)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_links_skips_non_links() {
+        let tree = SysfsTree::new("get_links_skips_non_links")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_file("bonding_masters", "bond0\n")
+            .with_link("bond0", 3, "00:11:22:33:44:66")
+            .without_attr("bond0", "ifindex");
+
+        let links = Sysfs::new(tree.root()).get_links().unwrap();
+
+        assert_eq!(
+            links,
+            vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::from(0x1003))]
+        );
+    }
+
+    #[test]
+    fn test_sysfs_get_info() {
+        let tree = SysfsTree::new("get_info")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_driver("enx", "virtio_net");
+
+        let info = Sysfs::new(tree.root())
+            .get_info(IfId::from(IFNAME))
+            .unwrap();
+
+        assert_eq!(info.ifname(), &IFNAME);
+        assert_eq!(info.index(), IfIndex::from(2));
+        assert_eq!(info.lladdr(), &LLADDR);
+        assert_eq!(info.hwaddr().to_string(), "00:11:22:33:44:55");
+        assert_eq!(info.addr_assign_type(), AddrAssignType::Set);
+        assert_eq!(info.link_type(), 1);
+        assert_eq!(info.operstate(), OperState::Up);
+        assert_eq!(info.carrier(), Some(true));
+        assert_eq!(info.driver(), Some("virtio_net"));
+    }
+
+    #[test]
+    fn test_sysfs_get_info_virtual_link_down() {
+        let tree = SysfsTree::new("get_info_virtual")
+            .with_link("enx", 2, "00:11:22:33:44:55")
+            .with_attr("enx", "addr_assign_type", "1\n")
+            .with_attr("enx", "operstate", "down\n")
+            .without_attr("enx", "carrier");
+
+        let info = Sysfs::new(tree.root())
+            .get_info(IfId::from(IFNAME))
+            .unwrap();
+
+        assert_eq!(info.addr_assign_type(), AddrAssignType::Random);
+        assert_eq!(info.operstate(), OperState::Down);
+        assert_eq!(info.carrier(), None);
+        assert_eq!(info.driver(), None);
+    }
+
+    #[test]
+    fn test_sysfs_unsupported() {
+        let error = super::unsupported::<()>(Operation::SetLinkLevelAddress, IfId::from(IFNAME))
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "Sysfs::UnsupportedError { op: SetLinkLevelAddress, ifid: Name(\"enx\") }"
+        );
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.ifid(), Some(IfId::from(IFNAME)));
+    }
+
+    #[test]
+    fn test_addr_assign_type_from_u8() {
+        assert_eq!(AddrAssignType::from(0), AddrAssignType::Permanent);
+        assert_eq!(AddrAssignType::from(2), AddrAssignType::Stolen);
+        assert_eq!(AddrAssignType::from(9), AddrAssignType::Unknown(9));
+    }
+
+    #[test]
+    fn test_operstate_from_str() {
+        assert_eq!(OperState::from("lowerlayerdown"), OperState::LowerLayerDown);
+        assert_eq!(OperState::from("dormant"), OperState::Dormant);
+        assert_eq!(OperState::from("bogus"), OperState::Unknown);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use std::path::{Path, PathBuf};
+
+    /// A fake `/sys/class/net` under the temp dir, removed on drop.
+    pub(crate) struct SysfsTree(PathBuf);
+
+    impl SysfsTree {
+        pub(crate) fn new(name: &str) -> SysfsTree {
+            let root =
+                std::env::temp_dir().join(format!("net-sys-sysfs-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&root);
+            std::fs::create_dir_all(&root).unwrap();
+            SysfsTree(root)
+        }
+
+        pub(crate) fn root(&self) -> &Path {
+            &self.0
+        }
+
+        /// Adds an up, carrier-on ethernet link with a user-set address.
+        pub(crate) fn with_link(self, ifname: &str, ifindex: u32, lladdr: &str) -> SysfsTree {
+            std::fs::create_dir_all(self.0.join(ifname)).unwrap();
+            self.with_attr(ifname, "ifindex", &format!("{}\n", ifindex))
+                .with_attr(ifname, "address", &format!("{}\n", lladdr))
+                .with_attr(ifname, "flags", "0x1003\n")
+                .with_attr(ifname, "addr_assign_type", "3\n")
+                .with_attr(ifname, "type", "1\n")
+                .with_attr(ifname, "operstate", "up\n")
+                .with_attr(ifname, "carrier", "1\n")
+                .with_attr(ifname, "mtu", "1500\n")
+        }
+
+        pub(crate) fn with_attr(self, ifname: &str, attr: &str, value: &str) -> SysfsTree {
+            std::fs::write(self.0.join(ifname).join(attr), value).unwrap();
+            self
+        }
+
+        /// Adds a regular file next to the links.
+        pub(crate) fn with_file(self, name: &str, value: &str) -> SysfsTree {
+            std::fs::write(self.0.join(name), value).unwrap();
+            self
+        }
+
+        pub(crate) fn without_attr(self, ifname: &str, attr: &str) -> SysfsTree {
+            std::fs::remove_file(self.0.join(ifname).join(attr)).unwrap();
+            self
+        }
+
+        pub(crate) fn with_driver(self, ifname: &str, driver: &str) -> SysfsTree {
+            let drivers = self.0.join("drivers").join(driver);
+            std::fs::create_dir_all(&drivers).unwrap();
+            std::fs::create_dir_all(self.0.join(ifname).join("device")).unwrap();
+            std::os::unix::fs::symlink(drivers, self.0.join(ifname).join("device/driver")).unwrap();
+            self
+        }
+    }
+
+    impl Drop for SysfsTree {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use super::ifindex::IfIndex;
+use super::netlink::{BoxNetlink, OpenNetlink};
+use super::nlmsg::{self};
+use crate::{ErrorKind, Link, LinkEvent, Operation, Result};
+use core::fmt::{Debug, Display};
+use std::collections::{HashMap, VecDeque};
+
+// Multicast group carrying RTM_NEWLINK / RTM_DELLINK notifications
+// RTMGRP_LINK = 1 << (RTNLGRP_LINK - 1)
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/rtnetlink.h
+const RTMGRP_LINK: u32 = libc::RTMGRP_LINK as u32;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    UnsupportedBackend(&'static str),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnsupportedBackend(backend) => f
+                .debug_struct("Watch::UnsupportedBackendError")
+                .field("backend", backend)
+                .finish(),
+        }
+    }
+}
+
+/// Blocking iterator over link events, returned by `Nic::watch`.
+///
+/// The kernel only says that a link changed, not what changed, so the
+/// watcher keeps the last state of every link and reports the difference.
+/// When the kernel drops notifications because the socket buffer overflowed,
+/// the links are dumped again and the difference reported all the same.
+#[derive(Debug)]
+pub struct Watch<'a> {
+    route: &'a BoxNetlink,
+    netlink: Box<dyn OpenNetlink + 'a>,
+    links: HashMap<IfIndex, Link>,
+    events: VecDeque<LinkEvent>,
+}
+
+impl<'a> Watch<'a> {
+    pub(super) fn new(netlink: &'a BoxNetlink) -> Result<Watch<'a>> {
+        // Subscribe before the dump, so no change can slip in between.
+        let events = netlink.open_route_groups(RTMGRP_LINK)?;
+
+        Ok(Watch {
+            route: netlink,
+            netlink: events,
+            links: get_links(netlink)?
+                .into_iter()
+                .map(|link| (link.index(), link))
+                .collect(),
+            events: VecDeque::new(),
+        })
+    }
+
+    fn update(&mut self, nlmsg: &[u8]) {
+        // Bridge ports are also announced with AF_BRIDGE, for the same link
+        if nlmsg::get_family(nlmsg) != libc::AF_UNSPEC as u8 {
+            return;
+        }
+        let link = nlmsg::get_link(nlmsg);
+        match nlmsg::get_type(nlmsg) {
+            libc::RTM_DELLINK => {
+                self.links.remove(&link.index());
+                self.events.push_back(LinkEvent::Removed(link));
+            }
+            _ => {
+                let previous = self.links.insert(link.index(), link);
+                self.events.extend(diff(previous.as_ref(), &link));
+            }
+        }
+    }
+
+    fn resync(&mut self) -> Result<()> {
+        let links = get_links(self.route)?;
+
+        let mut removed: Vec<Link> = self
+            .links
+            .values()
+            .filter(|previous| !links.iter().any(|link| link.index() == previous.index()))
+            .copied()
+            .collect();
+        removed.sort_by_key(|link| link.index());
+        self.events
+            .extend(removed.into_iter().map(LinkEvent::Removed));
+
+        for link in &links {
+            self.events
+                .extend(diff(self.links.get(&link.index()), link));
+        }
+        self.links = links.into_iter().map(|link| (link.index(), link)).collect();
+        Ok(())
+    }
+}
+
+impl Iterator for Watch<'_> {
+    type Item = Result<LinkEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            match self.netlink.recv_links() {
+                Ok(nlmsgs) => nlmsgs.iter().for_each(|nlmsg| self.update(nlmsg)),
+                Err(error) if is_overrun(&*error) => {
+                    if let Err(error) = self.resync() {
+                        return Some(Err(error));
+                    }
+                }
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+fn get_links(netlink: &BoxNetlink) -> Result<Vec<Link>> {
+    let nlmsg = nlmsg::new(libc::RTM_GETLINK, libc::NLM_F_REQUEST | libc::NLM_F_DUMP);
+    Ok(netlink
+        .open_route()?
+        .get_links(&nlmsg)?
+        .iter()
+        .filter(|nlmsg| nlmsg::get_family(nlmsg) == libc::AF_UNSPEC as u8)
+        .map(|nlmsg| nlmsg::get_link(nlmsg))
+        .collect())
+}
+
+// ENOBUFS on a multicast socket: notifications were lost, the socket
+// itself is still good.
+fn is_overrun(error: &(dyn std::error::Error + 'static)) -> bool {
+    error
+        .downcast_ref::<crate::Error>()
+        .and_then(|error| error.errno())
+        == Some(libc::ENOBUFS)
+}
+
+pub(super) fn unsupported<T>(backend: &'static str) -> Result<T> {
+    let error = Error::UnsupportedBackend(backend);
+    Err(crate::Error::new(ErrorKind::Unsupported, Operation::Watch, error).into())
+}
+
+fn diff(previous: Option<&Link>, link: &Link) -> Vec<LinkEvent> {
+    let previous = match previous {
+        Some(previous) => previous,
+        None => return vec![LinkEvent::Added(*link)],
+    };
+
+    let mut events = Vec::new();
+    if previous.ifname() != link.ifname() {
+        let from = *previous.ifname();
+        events.push(LinkEvent::Renamed { link: *link, from });
+    }
+    if previous.lladdr() != link.lladdr() {
+        let from = *previous.lladdr();
+        events.push(LinkEvent::AddressChanged { link: *link, from });
+    }
+    match (previous.flags().is_up(), link.flags().is_up()) {
+        (false, true) => events.push(LinkEvent::AdminUp(*link)),
+        (true, false) => events.push(LinkEvent::AdminDown(*link)),
+        _ => (),
+    }
+    let carrier = |link: &Link| link.flags().contains(libc::IFF_LOWER_UP);
+    match (carrier(previous), carrier(link)) {
+        (false, true) => events.push(LinkEvent::CarrierUp(*link)),
+        (true, false) => events.push(LinkEvent::CarrierDown(*link)),
+        _ => (),
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinkEvent, Watch};
+    use crate::sys::os::netlink::mock::{self, recv_error, ErrNo, MockNetlink};
+    use crate::sys::os::netlink::BoxNetlink;
+    use crate::sys::os::nlmsg::mock::nlmsg_from_link;
+    use crate::sys::os::nlmsg::{self};
+    use crate::{IfFlags, IfName, Link, LinkLevelAddress, Result};
+    use mockdown::Mockdown;
+
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+
+    const UP: u32 = libc::IFF_UP as u32;
+    const LOWER_UP: u32 = libc::IFF_LOWER_UP as u32;
+
+    fn link(flags: u32) -> Link {
+        Link::new(IFNAME, 2.into(), LLADDR, IfFlags::from(flags))
+    }
+
+    fn del_link(link: &Link) -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg_from_link(link);
+        nlmsg[4..6].copy_from_slice(&libc::RTM_DELLINK.to_ne_bytes());
+        nlmsg
+    }
+
+    fn wlan() -> Link {
+        let ifname = "wlan0".try_into().unwrap();
+        Link::new(ifname, 3.into(), LLADDR, IfFlags::default())
+    }
+
+    fn bridge_link(link: &Link) -> nlmsg::NlMsg {
+        let mut nlmsg = nlmsg_from_link(link);
+        nlmsg[16] = libc::AF_BRIDGE as u8;
+        nlmsg
+    }
+
+    fn expect_watch(netlink: MockNetlink) -> MockNetlink {
+        netlink
+            .expect(|mock::OpenRouteGroups(groups)| {
+                assert_eq!(groups, libc::RTMGRP_LINK as u32);
+                ErrNo::None
+            })
+            .expect(|mock::OpenRoute()| ErrNo::None)
+            .expect(|mock::GetLinks(_)| Result::Ok(vec![nlmsg_from_link(&link(UP))]))
+    }
+
+    #[test]
+    fn test_diff_added() {
+        let events = super::diff(None, &link(UP));
+
+        assert_eq!(events, vec![LinkEvent::Added(link(UP))]);
+    }
+
+    #[test]
+    fn test_diff_unchanged() {
+        let events = super::diff(Some(&link(UP)), &link(UP));
+
+        assert_eq!(events, vec![]);
+    }
+
+    #[test]
+    fn test_diff_admin() {
+        assert_eq!(
+            super::diff(Some(&link(0)), &link(UP)),
+            vec![LinkEvent::AdminUp(link(UP))]
+        );
+        assert_eq!(
+            super::diff(Some(&link(UP)), &link(0)),
+            vec![LinkEvent::AdminDown(link(0))]
+        );
+    }
+
+    #[test]
+    fn test_diff_carrier() {
+        assert_eq!(
+            super::diff(Some(&link(UP)), &link(UP | LOWER_UP)),
+            vec![LinkEvent::CarrierUp(link(UP | LOWER_UP))]
+        );
+        assert_eq!(
+            super::diff(Some(&link(UP | LOWER_UP)), &link(0)),
+            vec![
+                LinkEvent::AdminDown(link(0)),
+                LinkEvent::CarrierDown(link(0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_address_changed() {
+        let lladdr = "66:77:88:99:aa:bb".parse().unwrap();
+        let changed = Link::new(IFNAME, 2.into(), lladdr, IfFlags::from(UP));
+
+        let events = super::diff(Some(&link(UP)), &changed);
+
+        assert_eq!(
+            events,
+            vec![LinkEvent::AddressChanged {
+                link: changed,
+                from: LLADDR
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_renamed() {
+        let ifname = "wlan0".try_into().unwrap();
+        let renamed = Link::new(ifname, 2.into(), LLADDR, IfFlags::from(UP));
+
+        let events = super::diff(Some(&link(UP)), &renamed);
+
+        assert_eq!(
+            events,
+            vec![LinkEvent::Renamed {
+                link: renamed,
+                from: IFNAME
+            }]
+        );
+    }
+
+    #[test]
+    fn test_link_event_link() {
+        let event = LinkEvent::Renamed {
+            link: link(UP),
+            from: IFNAME,
+        };
+
+        assert_eq!(event.link(), &link(UP));
+    }
+
+    #[test]
+    fn test_watch() {
+        let netlink = expect_watch(MockNetlink::default())
+            .expect(|mock::RecvLinks()| Result::Ok(vec![nlmsg_from_link(&link(UP | LOWER_UP))]))
+            .expect(|mock::RecvLinks()| {
+                let added = Link::new(
+                    "wlan0".try_into().unwrap(),
+                    3.into(),
+                    LLADDR,
+                    IfFlags::default(),
+                );
+                Result::Ok(vec![nlmsg_from_link(&added), del_link(&link(0))])
+            });
+        let netlink = BoxNetlink(Box::new(netlink));
+
+        let events: Vec<LinkEvent> = Watch::new(&netlink)
+            .unwrap()
+            .take(3)
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let added = Link::new(
+            "wlan0".try_into().unwrap(),
+            3.into(),
+            LLADDR,
+            IfFlags::default(),
+        );
+        assert_eq!(
+            events,
+            vec![
+                LinkEvent::CarrierUp(link(UP | LOWER_UP)),
+                LinkEvent::Added(added),
+                LinkEvent::Removed(link(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_watch_skips_bridge_family() {
+        let netlink = expect_watch(MockNetlink::default()).expect(|mock::RecvLinks()| {
+            Result::Ok(vec![
+                bridge_link(&link(0)),
+                nlmsg_from_link(&link(UP | LOWER_UP)),
+            ])
+        });
+        let netlink = BoxNetlink(Box::new(netlink));
+
+        let event = Watch::new(&netlink).unwrap().next().unwrap().unwrap();
+
+        assert_eq!(event, LinkEvent::CarrierUp(link(UP | LOWER_UP)));
+    }
+
+    #[test]
+    fn test_watch_resync_on_overrun() {
+        let netlink = expect_watch(MockNetlink::default())
+            .expect(|mock::RecvLinks()| Result::<Vec<nlmsg::NlMsg>>::Err(recv_error(libc::ENOBUFS)))
+            .expect(|mock::OpenRoute()| ErrNo::None)
+            .expect(|mock::GetLinks(_)| Result::Ok(vec![nlmsg_from_link(&wlan())]));
+        let netlink = BoxNetlink(Box::new(netlink));
+
+        let events: Vec<LinkEvent> = Watch::new(&netlink)
+            .unwrap()
+            .take(2)
+            .collect::<Result<_>>()
+            .unwrap();
+
+        assert_eq!(
+            events,
+            vec![LinkEvent::Removed(link(UP)), LinkEvent::Added(wlan())]
+        );
+    }
+
+    #[test]
+    fn test_watch_recv_error() {
+        let netlink = expect_watch(MockNetlink::default())
+            .expect(|mock::RecvLinks()| Result::<Vec<nlmsg::NlMsg>>::Err("RecvError".into()));
+        let netlink = BoxNetlink(Box::new(netlink));
+
+        let error = Watch::new(&netlink).unwrap().next().unwrap().unwrap_err();
+
+        assert_eq!(format!("{}", error), "RecvError");
+    }
+
+    #[test]
+    fn test_watch_subscribe_error() {
+        let netlink =
+            MockNetlink::default().expect(|mock::OpenRouteGroups(_)| ErrNo::Some(libc::EPERM));
+        let netlink = BoxNetlink(Box::new(netlink));
+
+        let error = Watch::new(&netlink).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "Netlink::BindError { fd: -1, groups: 1, ret: -1, errno: 1, strerror: \"Operation not permitted\" }"
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let error = super::unsupported::<()>("Socket").unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "Watch::UnsupportedBackendError { backend: \"Socket\" }"
+        );
+    }
+}
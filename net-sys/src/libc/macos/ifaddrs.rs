@@ -0,0 +1,235 @@
+use super::ifname::IfName;
+use crate::{HardwareAddress, IfFlags, IfIndex, Link, LinkLevelAddress};
+use libc::{ifaddrs, sockaddr_dl};
+use std::ffi::CStr;
+
+// sdl_type values
+// https://github.com/apple-oss-distributions/xnu/blob/main/bsd/net/if_types.h
+const IFT_ETHER: u8 = 0x06;
+const IFT_LOOP: u8 = 0x18;
+
+// HardwareAddress carries the Linux ARPHRD_* types
+// https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_arp.h
+const ARPHRD_ETHER: u16 = 1;
+const ARPHRD_LOOPBACK: u16 = 772;
+const ARPHRD_NONE: u16 = 0xfffe;
+
+pub(super) fn get_name(ifaddrs: &ifaddrs) -> IfName {
+    let c_str = unsafe { CStr::from_ptr(ifaddrs.ifa_name) };
+    IfName::from_bytes(c_str.to_bytes())
+}
+
+fn get_sockaddr_dl(ifaddrs: &ifaddrs) -> Option<&sockaddr_dl> {
+    let addr = unsafe { ifaddrs.ifa_addr.as_ref() }?;
+    match addr.sa_family as libc::c_int {
+        libc::AF_LINK => Some(unsafe { &*(addr as *const _ as *const sockaddr_dl) }),
+        _ => None,
+    }
+}
+
+fn get_hwtype(sdl: &sockaddr_dl) -> u16 {
+    match sdl.sdl_type {
+        IFT_ETHER => ARPHRD_ETHER,
+        IFT_LOOP => ARPHRD_LOOPBACK,
+        _ => ARPHRD_NONE,
+    }
+}
+
+pub(super) fn get_link(ifaddrs: &ifaddrs) -> Option<Link> {
+    let sdl = get_sockaddr_dl(ifaddrs)?;
+    // LLADDR(sdl): the address follows the name in sdl_data
+    let octets = unsafe {
+        let data = (sdl.sdl_data.as_ptr() as *const u8).add(sdl.sdl_nlen as usize);
+        std::slice::from_raw_parts(data, sdl.sdl_alen as usize)
+    };
+    let mut lladdr = [0u8; 6];
+    let len = usize::min(octets.len(), lladdr.len());
+    lladdr[..len].copy_from_slice(&octets[..len]);
+    let link = Link::new(
+        get_name(ifaddrs),
+        IfIndex::from(sdl.sdl_index as u32),
+        LinkLevelAddress::from(&lladdr),
+        IfFlags::from(ifaddrs.ifa_flags),
+    );
+    Some(match HardwareAddress::new(get_hwtype(sdl), octets) {
+        Ok(hwaddr) => link.with_hwaddr(hwaddr),
+        Err(_) => link,
+    })
+}
+
+pub(super) fn get_links(ifap: *mut ifaddrs) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut ifa = ifap;
+    while let Some(ifaddrs) = unsafe { ifa.as_ref() } {
+        links.extend(get_link(ifaddrs));
+        ifa = ifaddrs.ifa_next;
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{ifaddrs_free, ifaddrs_new};
+    use super::ARPHRD_LOOPBACK;
+    use crate::{HardwareAddress, IfFlags, IfName, Link, LinkLevelAddress};
+    use std::sync::LazyLock;
+
+    static LINKS: LazyLock<Vec<Link>> = LazyLock::new(|| {
+        let flags = IfFlags::from((libc::IFF_UP | libc::IFF_RUNNING) as u32);
+        vec![
+            Link::new(
+                "enx".try_into().unwrap(),
+                2.into(),
+                "00:11:22:33:44:55".parse().unwrap(),
+                flags,
+            ),
+            Link::new(
+                "wlan0".try_into().unwrap(),
+                3.into(),
+                "66:77:88:99:aa:bb".parse().unwrap(),
+                IfFlags::default(),
+            ),
+        ]
+    });
+
+    #[test]
+    fn test_ifaddrs_get_name() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let ifname = super::get_name(unsafe { &*ifap });
+
+        assert_eq!(ifname, IfName::try_from("enx").unwrap());
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_link() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let link = super::get_link(unsafe { &*ifap });
+
+        assert_eq!(link, Some(LINKS[0]));
+        assert_eq!(
+            link.unwrap().lladdr(),
+            &"00:11:22:33:44:55".parse::<LinkLevelAddress>().unwrap()
+        );
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_link_loopback() {
+        let hwaddr = HardwareAddress::new(ARPHRD_LOOPBACK, &[]).unwrap();
+        let lo = Link::new(
+            "lo0".try_into().unwrap(),
+            1.into(),
+            LinkLevelAddress::from(&[0; 6]),
+            IfFlags::from((libc::IFF_UP | libc::IFF_LOOPBACK) as u32),
+        )
+        .with_hwaddr(hwaddr);
+        let ifap = ifaddrs_new(&[lo]);
+
+        let link = super::get_link(unsafe { &*ifap }).unwrap();
+
+        assert_eq!(link, lo);
+        assert_eq!(link.hwaddr().len(), 0);
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_links() {
+        let ifap = ifaddrs_new(&LINKS);
+
+        let links = super::get_links(ifap);
+
+        assert_eq!(links, *LINKS);
+        ifaddrs_free(ifap);
+    }
+
+    #[test]
+    fn test_ifaddrs_get_links_empty() {
+        let links = super::get_links(std::ptr::null_mut());
+
+        assert_eq!(links, vec![]);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod mock {
+    use super::{ARPHRD_ETHER, ARPHRD_LOOPBACK, IFT_ETHER, IFT_LOOP};
+    use crate::Link;
+    use libc::{ifaddrs, sockaddr, sockaddr_dl, sockaddr_storage};
+    use std::ffi::CString;
+
+    fn new_entry(link: &Link, addr: Option<sockaddr_storage>, next: *mut ifaddrs) -> *mut ifaddrs {
+        let mut ifa: ifaddrs = unsafe { std::mem::zeroed() };
+        ifa.ifa_next = next;
+        ifa.ifa_name = CString::new(String::from(link.ifname()))
+            .unwrap()
+            .into_raw();
+        ifa.ifa_flags = link.flags().bits();
+        if let Some(addr) = addr {
+            ifa.ifa_addr = Box::into_raw(Box::new(addr)) as *mut sockaddr;
+        }
+        Box::into_raw(Box::new(ifa))
+    }
+
+    fn new_sockaddr_dl(link: &Link) -> sockaddr_storage {
+        let mut addr: sockaddr_storage = unsafe { std::mem::zeroed() };
+        let name = String::from(link.ifname());
+        let sdl = unsafe { &mut *(&mut addr as *mut _ as *mut sockaddr_dl) };
+        sdl.sdl_len = std::mem::size_of::<sockaddr_dl>() as u8;
+        sdl.sdl_family = libc::AF_LINK as u8;
+        sdl.sdl_index = *link.index() as u16;
+        sdl.sdl_type = match link.hwaddr().hwtype() {
+            ARPHRD_ETHER => IFT_ETHER,
+            ARPHRD_LOOPBACK => IFT_LOOP,
+            _ => 0,
+        };
+        sdl.sdl_nlen = name.len() as u8;
+        sdl.sdl_alen = link.hwaddr().len() as u8;
+        // sdl_data is only 12 bytes long, name and address may spill over
+        // into the rest of the sockaddr_storage
+        unsafe {
+            let data = sdl.sdl_data.as_mut_ptr() as *mut u8;
+            std::ptr::copy_nonoverlapping(name.as_ptr(), data, name.len());
+            std::ptr::copy_nonoverlapping(
+                link.hwaddr().as_ptr(),
+                data.add(name.len()),
+                link.hwaddr().len(),
+            );
+        }
+        addr
+    }
+
+    fn new_sockaddr_in() -> sockaddr_storage {
+        let mut addr: sockaddr_storage = unsafe { std::mem::zeroed() };
+        addr.ss_family = libc::AF_INET as u8;
+        addr
+    }
+
+    // Builds a getifaddrs(3) like list, with an AF_INET and an address-less
+    // entry after the AF_LINK ones, so callers must filter them out.
+    pub(crate) fn ifaddrs_new(links: &[Link]) -> *mut ifaddrs {
+        let mut head = std::ptr::null_mut();
+        if let Some(link) = links.first() {
+            head = new_entry(link, None, head);
+            head = new_entry(link, Some(new_sockaddr_in()), head);
+        }
+        for link in links.iter().rev() {
+            head = new_entry(link, Some(new_sockaddr_dl(link)), head);
+        }
+        head
+    }
+
+    pub(crate) fn ifaddrs_free(ifap: *mut ifaddrs) {
+        let mut ifa = ifap;
+        while !ifa.is_null() {
+            let entry = unsafe { Box::from_raw(ifa) };
+            drop(unsafe { CString::from_raw(entry.ifa_name) });
+            if !entry.ifa_addr.is_null() {
+                drop(unsafe { Box::from_raw(entry.ifa_addr as *mut sockaddr_storage) });
+            }
+            ifa = entry.ifa_next;
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::ifname::IfName;
+use super::sys::{self, BoxSys};
+use crate::{Operation, Result};
+use core::fmt::{Debug, Display};
+use std::ops::Deref;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    NameToIndex(IfName, libc::c_int),
+    IndexToName(IfIndex, libc::c_int),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NameToIndex(ifname, errno) => f
+                .debug_struct("IfIndex::NameToIndexError")
+                .field("ifname", ifname)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Self::IndexToName(ifindex, errno) => f
+                .debug_struct("IfIndex::IndexToNameError")
+                .field("ifindex", ifindex)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NameToIndex(ifname, errno) => {
+                Self::from_errno(errno, Operation::ResolveName, error).with_ifid(ifname)
+            }
+            Error::IndexToName(ifindex, errno) => {
+                Self::from_errno(errno, Operation::ResolveName, error).with_ifid(ifindex)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IfIndex(libc::c_uint);
+
+impl Deref for IfIndex {
+    type Target = libc::c_uint;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for IfIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Display for IfIndex {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.0)
+    }
+}
+
+impl From<libc::c_uint> for IfIndex {
+    fn from(value: libc::c_uint) -> Self {
+        Self(value)
+    }
+}
+
+impl From<IfIndex> for libc::c_uint {
+    fn from(value: IfIndex) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<&IfName> for IfIndex {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(value: &IfName) -> Result<Self> {
+        to_index(&BoxSys::default(), value)
+    }
+}
+
+impl TryFrom<IfIndex> for IfName {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(value: IfIndex) -> Result<Self> {
+        to_name(&BoxSys::default(), value)
+    }
+}
+
+pub(super) fn to_index(sys: &BoxSys, ifname: &IfName) -> Result<IfIndex> {
+    match sys.if_nametoindex(ifname.as_ptr()) {
+        0 => Err(crate::Error::from(Error::NameToIndex(*ifname, sys.errno())).into()),
+        index => Ok(IfIndex(index)),
+    }
+}
+
+pub(super) fn to_name(sys: &BoxSys, ifindex: IfIndex) -> Result<IfName> {
+    let mut ifname = [0 as libc::c_char; libc::IFNAMSIZ];
+    match sys.if_indextoname(*ifindex, ifname.as_mut_ptr()) {
+        ptr if ptr.is_null() => {
+            Err(crate::Error::from(Error::IndexToName(ifindex, sys.errno())).into())
+        }
+        _ => Ok(IfName::from(&ifname)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sys::mock::{self, MockSys};
+    use super::{BoxSys, IfIndex, IfName};
+    use crate::{ErrorKind, IfId, Operation};
+    use mockdown::Mockdown;
+
+    const RETURN_INDEX: libc::c_uint = 7;
+    const RETURN_FAILURE: libc::c_uint = 0;
+
+    impl BoxSys {
+        fn new(sys: &MockSys) -> BoxSys {
+            BoxSys(Box::new(sys.clone()))
+        }
+    }
+
+    #[test]
+    fn test_ifindex_from_u32() {
+        let ifindex = IfIndex::from(7);
+
+        assert_eq!(*ifindex, 7);
+        assert_eq!(u32::from(ifindex), 7);
+    }
+
+    #[test]
+    fn test_ifindex_debug_and_display() {
+        let ifindex = IfIndex::from(7);
+
+        assert_eq!(format!("{:?}", ifindex), "7");
+        assert_eq!(format!("{}", ifindex), "7");
+    }
+
+    #[test]
+    fn test_ifindex_ord() {
+        assert!(IfIndex::from(2) < IfIndex::from(3));
+    }
+
+    #[test]
+    fn test_ifindex_to_index() {
+        let sys = MockSys::default().expect(|mock::IfNameToIndex(ifname)| {
+            assert_eq!(ifname, "enx");
+            RETURN_INDEX
+        });
+        let ifname = IfName::try_from("enx").unwrap();
+
+        let ifindex = super::to_index(&BoxSys::new(&sys), &ifname).unwrap();
+
+        assert_eq!(ifindex, IfIndex::from(7));
+    }
+
+    #[test]
+    fn test_ifindex_to_index_error() {
+        let sys = MockSys::default()
+            .expect(|mock::IfNameToIndex(_)| RETURN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::ENODEV);
+        let ifname = IfName::try_from("enx").unwrap();
+
+        let error = super::to_index(&BoxSys::new(&sys), &ifname).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "IfIndex::NameToIndexError { ifname: \"enx\", errno: 19, strerror: \"Operation not supported by device\" }"
+        );
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::NoSuchDevice);
+        assert_eq!(error.operation(), Operation::ResolveName);
+        assert_eq!(error.ifid(), Some(IfId::from(ifname)));
+    }
+
+    #[test]
+    fn test_ifindex_to_name() {
+        let sys = MockSys::default().expect(|mock::IfIndexToName(ifindex, ifname)| {
+            assert_eq!(ifindex, 7);
+            unsafe { std::ptr::copy_nonoverlapping(c"enx".as_ptr(), ifname, 4) };
+            ifname
+        });
+
+        let ifname = super::to_name(&BoxSys::new(&sys), IfIndex::from(7)).unwrap();
+
+        assert_eq!(ifname, IfName::try_from("enx").unwrap());
+    }
+
+    #[test]
+    fn test_ifindex_to_name_error() {
+        let sys = MockSys::default()
+            .expect(|mock::IfIndexToName(_, _)| std::ptr::null_mut::<libc::c_char>())
+            .expect(|_: mock::ErrNo| libc::ENXIO);
+
+        let error = super::to_name(&BoxSys::new(&sys), IfIndex::from(7)).unwrap_err();
+
+        assert_eq!(
+            format!("{:?}", error.source().unwrap()),
+            "IfIndex::IndexToNameError { ifindex: 7, errno: 6, strerror: \"Device not configured\" }"
+        );
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::NoSuchDevice);
+        assert_eq!(error.ifid(), Some(IfId::from(IfIndex::from(7))));
+    }
+}
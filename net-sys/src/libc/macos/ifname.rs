@@ -7,42 +7,83 @@ const IF_NAME_MAX: libc::size_t = IF_NAME_SIZE - 1;
 
 type IfNameType = [libc::c_char; IF_NAME_SIZE];
 
-#[derive(Clone, PartialEq, Eq)]
-enum Error {
-    TooSmall(String),
-    TooLarge(String),
-    InvalidCString(String, String),
+// Same refusals as dev_valid_name() on Linux, so a name is valid on both systems.
+const fn is_invalid_char(byte: u8) -> bool {
+    matches!(byte, b'/' | b':' | b' ' | b'\t'..=b'\r' | 0xa0)
 }
 
-impl std::error::Error for Error {}
+const fn is_reserved(bytes: &[u8]) -> bool {
+    matches!(bytes, [b'.'] | [b'.', b'.'])
+}
 
-impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+/// What made [`ParseIfNameError`] reject its input.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIfNameErrorKind {
+    /// Shorter than 3 bytes, the token is empty at the end of the input.
+    TooSmall,
+    /// Longer than `IFNAMSIZ - 1` bytes, the token is what does not fit.
+    TooLarge,
+    /// The token is a NUL byte.
+    NulByte,
+    /// The token is a byte the kernel refuses in a name: `/`, `:` or a space.
+    InvalidChar,
+    /// `.` or `..`, the token is the whole input.
+    Reserved,
+}
+
+/// Error returned when building an [`IfName`] from a string.
+///
+/// `offset` is the byte position of `token` within `value`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParseIfNameError {
+    kind: ParseIfNameErrorKind,
+    value: String,
+    offset: usize,
+    token: String,
+}
+
+impl ParseIfNameError {
+    fn new(kind: ParseIfNameErrorKind, value: &str, offset: usize) -> Self {
+        let token = match kind {
+            ParseIfNameErrorKind::TooSmall => &[][..],
+            ParseIfNameErrorKind::TooLarge => &value.as_bytes()[offset..],
+            ParseIfNameErrorKind::NulByte | ParseIfNameErrorKind::InvalidChar => {
+                &value.as_bytes()[offset..offset + 1]
+            }
+            ParseIfNameErrorKind::Reserved => value.as_bytes(),
+        };
+        ParseIfNameError {
+            kind,
+            value: value.to_string(),
+            offset,
+            token: String::from_utf8_lossy(token).into_owned(),
+        }
+    }
+
+    pub fn kind(&self) -> ParseIfNameErrorKind {
+        self.kind
+    }
+
+    /// The whole input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
     }
 }
 
-impl Debug for Error {
+impl std::error::Error for ParseIfNameError {}
+
+impl Display for ParseIfNameError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::TooSmall(value) => f
-                .debug_struct("IfName::TooSmallError")
-                .field("value", value)
-                .field("len", &value.len())
-                .field("min", &IF_NAME_MIN)
-                .finish(),
-            Self::TooLarge(value) => f
-                .debug_struct("IfName::TooLargeError")
-                .field("value", value)
-                .field("len", &value.len())
-                .field("max", &IF_NAME_MAX)
-                .finish(),
-            Self::InvalidCString(value, error) => f
-                .debug_struct("IfName::InvalidCStringError")
-                .field("value", value)
-                .field("error", error)
-                .finish(),
-        }
+        write!(f, "{:?}", self)
     }
 }
 
@@ -82,38 +123,129 @@ impl From<&IfNameType> for IfName {
     }
 }
 
-impl TryFrom<&str> for IfName {
-    type Error = Box<dyn std::error::Error>;
+/// Builds an [`IfName`] from a string literal, checked at compile time.
+///
+/// ```
+/// const IFNAME: net_sys::IfName = net_sys::ifname!("en0");
+/// ```
+///
+/// ```compile_fail
+/// let ifname = net_sys::ifname!("a-name-way-too-long");
+/// ```
+#[macro_export]
+macro_rules! ifname {
+    ($value:literal) => {{
+        const IFNAME: $crate::IfName = match $crate::IfName::parse_const($value) {
+            Some(ifname) => ifname,
+            None => panic!(concat!("invalid interface name: ", $value)),
+        };
+        IFNAME
+    }};
+}
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        String::from(value).try_into()
+impl IfName {
+    /// `const` counterpart of `try_from`.
+    pub const fn parse_const(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() < IF_NAME_MIN || bytes.len() > IF_NAME_MAX || is_reserved(bytes) {
+            return None;
+        }
+        let mut ifname: IfNameType = [0; IF_NAME_SIZE];
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 || is_invalid_char(bytes[i]) {
+                return None;
+            }
+            ifname[i] = bytes[i] as libc::c_char;
+            i += 1;
+        }
+        Some(Self(ifname))
+    }
+
+    pub(crate) fn from_bytes(value: &[u8]) -> Self {
+        let len = value.iter().position(|&c| c == 0).unwrap_or(value.len());
+        let mut ifname: IfNameType = unsafe { std::mem::zeroed() };
+        for (c, &byte) in ifname.iter_mut().zip(&value[..len.min(IF_NAME_MAX)]) {
+            *c = byte as libc::c_char;
+        }
+        Self::from(&ifname)
     }
 }
 
-impl TryFrom<String> for IfName {
-    type Error = Box<dyn std::error::Error>;
+impl TryFrom<&str> for IfName {
+    type Error = ParseIfNameError;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        let value = match value.len() {
-            len if len < IF_NAME_MIN => return Err(Error::TooSmall(value).into()),
-            len if len > IF_NAME_MAX => return Err(Error::TooLarge(value).into()),
-            _ => CString::new(value.clone())
-                .map_err(|error| Error::InvalidCString(value, error.to_string()))?,
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let c_value = match value.len() {
+            len if len < IF_NAME_MIN => {
+                return Err(ParseIfNameError::new(
+                    ParseIfNameErrorKind::TooSmall,
+                    value,
+                    len,
+                ))
+            }
+            len if len > IF_NAME_MAX => {
+                return Err(ParseIfNameError::new(
+                    ParseIfNameErrorKind::TooLarge,
+                    value,
+                    IF_NAME_MAX,
+                ))
+            }
+            _ => CString::new(value).map_err(|error| {
+                ParseIfNameError::new(ParseIfNameErrorKind::NulByte, value, error.nul_position())
+            })?,
         };
+        if let Some(offset) = value.bytes().position(is_invalid_char) {
+            return Err(ParseIfNameError::new(
+                ParseIfNameErrorKind::InvalidChar,
+                value,
+                offset,
+            ));
+        }
+        if is_reserved(value.as_bytes()) {
+            return Err(ParseIfNameError::new(
+                ParseIfNameErrorKind::Reserved,
+                value,
+                0,
+            ));
+        }
 
         let mut ifname: IfNameType = unsafe { std::mem::zeroed() };
         unsafe {
-            ptr::copy_nonoverlapping(value.as_ptr(), ifname.as_mut_ptr(), value.as_bytes().len());
+            ptr::copy_nonoverlapping(c_value.as_ptr(), ifname.as_mut_ptr(), value.len());
         }
         Ok(Self::from(&ifname))
     }
 }
 
+impl TryFrom<String> for IfName {
+    type Error = ParseIfNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for IfName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IfName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        IfName::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use super::{IfName, IfNameType};
+    use super::{IfName, IfNameType, ParseIfNameErrorKind};
 
     const IF_NAME_SIZE: usize = 16;
     const IF_NAME: IfNameType = [
@@ -154,6 +286,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_ifname_clone() {
         let ifname = IfName(IF_NAME);
 
@@ -240,6 +373,26 @@ mod tests {
         assert_eq!(ifname, expected);
     }
 
+    #[test]
+    fn test_ifname_from_bytes() {
+        let source = b"0123456789ABCDE\0garbage";
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::from_bytes(source);
+
+        assert_eq!(ifname, expected);
+    }
+
+    #[test]
+    fn test_ifname_from_bytes_truncated() {
+        let source = b"0123456789ABCDEF";
+        let expected = IfName(IF_NAME);
+
+        let ifname = IfName::from_bytes(source);
+
+        assert_eq!(ifname, expected);
+    }
+
     #[test]
     fn test_ifname_from_str() {
         let source = "0123456789ABCDE";
@@ -253,7 +406,8 @@ mod tests {
     #[test]
     fn test_ifname_from_str_length_too_small() {
         let source = "en";
-        let expected_error = "IfName::TooSmallError { value: \"en\", len: 2, min: 3 }";
+        let expected_error =
+            "ParseIfNameError { kind: TooSmall, value: \"en\", offset: 2, token: \"\" }";
 
         let error = IfName::try_from(source).unwrap_err();
 
@@ -264,8 +418,7 @@ mod tests {
     #[test]
     fn test_ifname_from_str_length_too_large() {
         let source = "0123456789ABCDEF";
-        let expected_error =
-            "IfName::TooLargeError { value: \"0123456789ABCDEF\", len: 16, max: 15 }";
+        let expected_error = "ParseIfNameError { kind: TooLarge, value: \"0123456789ABCDEF\", offset: 15, token: \"F\" }";
 
         let error = IfName::try_from(source).unwrap_err();
 
@@ -276,7 +429,8 @@ mod tests {
     #[test]
     fn test_ifname_from_str_empty() {
         let source = "";
-        let expected_error = "IfName::TooSmallError { value: \"\", len: 0, min: 3 }";
+        let expected_error =
+            "ParseIfNameError { kind: TooSmall, value: \"\", offset: 0, token: \"\" }";
 
         let error = IfName::try_from(source).unwrap_err();
 
@@ -287,11 +441,100 @@ mod tests {
     #[test]
     fn test_ifname_from_str_nul_error() {
         let source = "0123456\089ABCDE";
-        let expected_error = "IfName::InvalidCStringError { value: \"0123456\\089ABCDE\", error: \"nul byte found in provided data at position: 7\" }";
+        let expected_error = "ParseIfNameError { kind: NulByte, value: \"0123456\\089ABCDE\", offset: 7, token: \"\\0\" }";
 
         let error = IfName::try_from(source).unwrap_err();
 
         assert_eq!(format!("{}", error), expected_error);
         assert_eq!(format!("{:?}", error), expected_error);
     }
+
+    #[test]
+    fn test_ifname_from_str_invalid_char() {
+        let source = "eth0:1";
+        let expected_error =
+            "ParseIfNameError { kind: InvalidChar, value: \"eth0:1\", offset: 4, token: \":\" }";
+
+        let error = IfName::try_from(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+        assert_eq!(format!("{:?}", error), expected_error);
+    }
+
+    #[test]
+    fn test_ifname_from_str_invalid_chars() {
+        for (source, offset) in [
+            ("en/0", 2),
+            ("en 0", 2),
+            ("en0\t", 3),
+            ("\nen0", 0),
+            ("enà", 3),
+        ] {
+            let error = IfName::try_from(source).unwrap_err();
+
+            assert_eq!(error.kind(), ParseIfNameErrorKind::InvalidChar);
+            assert_eq!(error.offset(), offset);
+        }
+    }
+
+    #[test]
+    fn test_ifname_from_str_error_fields() {
+        let error = IfName::try_from("usb-c-ethernet-dongle").unwrap_err();
+
+        assert_eq!(error.kind(), ParseIfNameErrorKind::TooLarge);
+        assert_eq!(error.value(), "usb-c-ethernet-dongle");
+        assert_eq!(error.offset(), 15);
+        assert_eq!(error.token(), "dongle");
+    }
+
+    #[test]
+    fn test_ifname_from_str_too_large_inside_char() {
+        let error = IfName::try_from("0123456789ABCDé").unwrap_err();
+
+        assert_eq!(error.kind(), ParseIfNameErrorKind::TooLarge);
+        assert_eq!(error.offset(), 15);
+        assert_eq!(error.token(), "\u{fffd}");
+    }
+
+    #[test]
+    fn test_ifname_parse_const() {
+        let ifname = IfName::parse_const("enx");
+
+        assert_eq!(ifname, Some(IfName::try_from("enx").unwrap()));
+    }
+
+    #[test]
+    fn test_ifname_parse_const_invalid() {
+        for source in ["en", "0123456789ABCDEF", "en\0x", "en/0", "en 0", "en0:1"] {
+            assert_eq!(IfName::parse_const(source), None);
+        }
+    }
+
+    #[test]
+    fn test_ifname_macro() {
+        const IFNAME: IfName = crate::ifname!("0123456789ABCDE");
+
+        assert_eq!(IFNAME, IfName(IF_NAME));
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::IfName;
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        #[test]
+        fn test_ifname_serde() {
+            let ifname = IfName::try_from("enx").unwrap();
+
+            assert_tokens(&ifname, &[Token::Str("enx")]);
+        }
+
+        #[test]
+        fn test_ifname_serde_error() {
+            assert_de_tokens_error::<IfName>(
+                &[Token::Str("en")],
+                "ParseIfNameError { kind: TooSmall, value: \"en\", offset: 2, token: \"\" }",
+            );
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use super::ifname::IfName;
-use crate::LinkLevelAddress;
+use crate::{IfFlags, LinkLevelAddress};
 use libc::{c_void, ifreq};
 use std::ptr;
 
@@ -40,6 +40,22 @@ pub(super) fn get_lladdr(ifreq: &ifreq) -> LinkLevelAddress {
     LinkLevelAddress::from(sa_data)
 }
 
+pub(super) fn set_flags(ifreq: &mut ifreq, flags: IfFlags) {
+    ifreq.ifr_ifru.ifru_flags = flags.bits() as libc::c_short;
+}
+
+pub(super) fn get_flags(ifreq: &ifreq) -> IfFlags {
+    IfFlags::from(unsafe { ifreq.ifr_ifru.ifru_flags } as u16 as u32)
+}
+
+pub(super) fn set_mtu(ifreq: &mut ifreq, mtu: u32) {
+    ifreq.ifr_ifru.ifru_mtu = mtu as libc::c_int;
+}
+
+pub(super) fn get_mtu(ifreq: &ifreq) -> u32 {
+    unsafe { ifreq.ifr_ifru.ifru_mtu as u32 }
+}
+
 #[cfg(test)]
 mod tests {
     use libc::{c_char, c_void};
@@ -53,6 +69,8 @@ mod tests {
     ];
     const LADDR_SIZE: usize = 6;
     const LLADDR: [u8; LADDR_SIZE] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    const FLAGS: libc::c_short = (libc::IFF_UP | libc::IFF_BROADCAST) as libc::c_short;
+    const MTU: u32 = 1420;
 
     struct IfReq<'a>(&'a libc::ifreq);
 
@@ -176,13 +194,54 @@ mod tests {
 
         assert_eq!(*lladdr, LLADDR);
     }
+
+    #[test]
+    fn test_ifreq_set_flags() {
+        let mut ifreq = super::new();
+
+        super::set_flags(&mut ifreq, super::IfFlags::from(FLAGS as u32));
+
+        assert_eq!(unsafe { ifreq.ifr_ifru.ifru_flags }, FLAGS);
+    }
+
+    #[test]
+    fn test_ifreq_get_flags() {
+        let mut ifreq = super::new();
+        ifreq.ifr_ifru.ifru_flags = FLAGS;
+
+        let flags = super::get_flags(&ifreq);
+
+        assert_eq!(flags.bits(), FLAGS as u32);
+        assert!(flags.is_up());
+    }
+
+    #[test]
+    fn test_ifreq_set_mtu() {
+        let mut ifreq = super::new();
+
+        super::set_mtu(&mut ifreq, MTU);
+
+        assert_eq!(unsafe { ifreq.ifr_ifru.ifru_mtu }, MTU as libc::c_int);
+    }
+
+    #[test]
+    fn test_ifreq_get_mtu() {
+        let mut ifreq = super::new();
+        ifreq.ifr_ifru.ifru_mtu = MTU as libc::c_int;
+
+        let mtu = super::get_mtu(&ifreq);
+
+        assert_eq!(mtu, MTU);
+    }
 }
 
 #[cfg(test)]
 pub(super) mod mock {
-    use crate::{IfName, LinkLevelAddress};
+    use crate::{IfFlags, IfName, LinkLevelAddress};
 
-    use super::{from_mut_ptr, get_lladdr, get_name, set_lladdr};
+    use super::{
+        from_mut_ptr, get_flags, get_lladdr, get_mtu, get_name, set_flags, set_lladdr, set_mtu,
+    };
 
     pub(crate) fn ifreq_get_name(arg: *mut libc::c_void) -> IfName {
         let ifreq = from_mut_ptr(arg);
@@ -198,4 +257,24 @@ pub(super) mod mock {
         let ifreq = from_mut_ptr(arg);
         set_lladdr(ifreq, &lladdr);
     }
+
+    pub(crate) fn ifreq_get_flags(arg: *mut libc::c_void) -> IfFlags {
+        let ifreq = from_mut_ptr(arg);
+        get_flags(ifreq)
+    }
+
+    pub(crate) fn ifreq_set_flags(arg: *mut libc::c_void, flags: IfFlags) {
+        let ifreq = from_mut_ptr(arg);
+        set_flags(ifreq, flags);
+    }
+
+    pub(crate) fn ifreq_get_mtu(arg: *mut libc::c_void) -> u32 {
+        let ifreq = from_mut_ptr(arg);
+        get_mtu(ifreq)
+    }
+
+    pub(crate) fn ifreq_set_mtu(arg: *mut libc::c_void, mtu: u32) {
+        let ifreq = from_mut_ptr(arg);
+        set_mtu(ifreq, mtu);
+    }
 }
@@ -1,60 +1,334 @@
 use super::ifname::IfName;
 use super::ifreq::{self};
-use super::socket::BoxSocket;
-use crate::{LinkLevelAddress, Result};
+use super::socket::{BoxSocket, OpenSocket};
+use crate::{
+    BounceError, HardwareAddress, IfFlags, IfId, Link, LinkLevelAddress, NicBackend, Result,
+    Transaction,
+};
+
+#[derive(Debug)]
+enum Backend {
+    Socket(BoxSocket),
+    Custom(Box<dyn NicBackend>),
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Socket(BoxSocket::default())
+    }
+}
 
 #[derive(Debug, Default)]
 pub struct Nic {
-    socket: BoxSocket,
+    backend: Backend,
 }
 
 impl Nic {
-    pub fn get_lladd(&self, ifname: &IfName) -> Result<LinkLevelAddress> {
-        let mut ifreq = ifreq::new();
-        ifreq::set_name(&mut ifreq, ifname);
+    /// Returns a `Nic` that hands every operation to `backend`, such as a
+    /// fake for tests.
+    pub fn with_backend(backend: impl NicBackend + 'static) -> Nic {
+        Nic {
+            backend: Backend::Custom(Box::new(backend)),
+        }
+    }
+
+    pub fn get_lladd(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_lladdr(&ifreq))
+            }
+            Backend::Custom(backend) => backend.get_lladd(ifid),
+        }
+    }
+
+    /// The address at its own length, as `getifaddrs(3)` lists it. An
+    /// interface it does not list falls back to `get_lladd`, which reports
+    /// why.
+    pub fn get_hwaddr(&self, ifid: impl Into<IfId>) -> Result<HardwareAddress> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let ifname = get_name(socket, ifid)?;
+                let link = socket
+                    .get_links()?
+                    .into_iter()
+                    .find(|link| *link.ifname() == ifname);
+
+                match link {
+                    Some(link) => Ok(*link.hwaddr()),
+                    None => Ok(HardwareAddress::from(&self.get_lladd(ifid)?)),
+                }
+            }
+            Backend::Custom(backend) => backend.get_hwaddr(ifid),
+        }
+    }
+
+    pub fn set_lladd(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        self.set_lladd_unchecked(ifid.into(), lladdr)
+    }
+
+    // Also puts back an address the interface had, which may not be one
+    // `check_assignable` lets through.
+    pub(crate) fn set_lladd_unchecked(&self, ifid: IfId, lladdr: &LinkLevelAddress) -> Result<()> {
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_lladdr(&mut ifreq, lladdr);
+
+                socket
+                    .open_local_dgram()?
+                    .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            Backend::Custom(backend) => backend.set_lladd(ifid, lladdr),
+        }
+    }
+
+    /// Changes the address with the interface taken down first, as many
+    /// drivers reject the change while the link is up. The previous admin
+    /// state is restored whether or not the change succeeds, failing with a
+    /// [`BounceError`] when both the change and the restore fail.
+    pub fn set_lladd_bounce(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        self.set_lladd_bounce_unchecked(ifid.into(), lladdr)
+    }
+
+    pub(crate) fn set_lladd_bounce_unchecked(
+        &self,
+        ifid: IfId,
+        lladdr: &LinkLevelAddress,
+    ) -> Result<()> {
+        if !self.get_flags(ifid)?.is_up() {
+            return self.set_lladd_unchecked(ifid, lladdr);
+        }
+
+        self.set_down(ifid)?;
+        let result = self.set_lladd_unchecked(ifid, lladdr);
+        match (result, self.set_up(ifid)) {
+            (Ok(()), restore) => restore,
+            (Err(error), Ok(())) => Err(error),
+            (Err(error), Err(restore_error)) => {
+                Err(BounceError::new(ifid, error, restore_error).into())
+            }
+        }
+    }
+
+    pub fn get_flags(&self, ifid: impl Into<IfId>) -> Result<IfFlags> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_flags(&ifreq))
+            }
+            Backend::Custom(backend) => backend.get_flags(ifid),
+        }
+    }
+
+    pub fn get_mtu(&self, ifid: impl Into<IfId>) -> Result<u32> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                socket
+                    .open_local_dgram()?
+                    .get_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_mtu(&ifreq))
+            }
+            Backend::Custom(backend) => backend.get_mtu(ifid),
+        }
+    }
+
+    pub fn set_mtu(&self, ifid: impl Into<IfId>, mtu: u32) -> Result<()> {
+        let ifid = ifid.into();
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_mtu(&mut ifreq, mtu);
+
+                socket
+                    .open_local_dgram()?
+                    .set_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            Backend::Custom(backend) => backend.set_mtu(ifid, mtu),
+        }
+    }
+
+    pub fn set_up(&self, ifid: impl Into<IfId>) -> Result<()> {
+        self.set_admin(ifid.into(), true)
+    }
+
+    pub fn set_down(&self, ifid: impl Into<IfId>) -> Result<()> {
+        self.set_admin(ifid.into(), false)
+    }
+
+    fn set_admin(&self, ifid: IfId, up: bool) -> Result<()> {
+        match &self.backend {
+            Backend::Socket(socket) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                let socket = socket.open_local_dgram()?;
+                socket.get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                let flags = ifreq::get_flags(&ifreq).bits();
+                let flags = match up {
+                    true => flags | libc::IFF_UP as u32,
+                    false => flags & !(libc::IFF_UP as u32),
+                };
+                ifreq::set_flags(&mut ifreq, IfFlags::from(flags));
+
+                socket.set_flags(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            Backend::Custom(backend) => backend.set_admin(ifid, up),
+        }
+    }
+
+    pub fn list(&self) -> Result<Vec<Link>> {
+        match &self.backend {
+            Backend::Socket(socket) => socket.get_links(),
+            Backend::Custom(backend) => backend.list(),
+        }
+    }
 
-        self.socket
-            .open_local_dgram()?
-            .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+    /// Opens a session that runs every call over one socket, for callers
+    /// that touch many interfaces in a row. The socket backend opens its
+    /// descriptor here and closes it when the session is dropped, other
+    /// backends behave as the `Nic` does.
+    pub fn session(&self) -> Result<Session<'_>> {
+        let socket = match &self.backend {
+            Backend::Socket(socket) => Some(socket.open_local_dgram()?),
+            _ => None,
+        };
+        Ok(Session { nic: self, socket })
+    }
 
-        Ok(ifreq::get_lladdr(&ifreq))
+    /// Starts a batch of address changes that are undone if one of them
+    /// fails, see [`Transaction`].
+    pub fn transaction(&self) -> Transaction<'_> {
+        Transaction::new(self)
     }
 
-    pub fn set_lladd(&self, ifname: &IfName, lladdr: &LinkLevelAddress) -> Result<()> {
-        let mut ifreq = ifreq::new();
-        ifreq::set_name(&mut ifreq, ifname);
-        ifreq::set_lladdr(&mut ifreq, lladdr);
+    /// Turns a user-supplied name into an `IfId`. macOS has no alternative
+    /// interface names, so this only validates the name.
+    pub fn resolve(&self, name: &str) -> Result<IfId> {
+        Ok(IfId::from(IfName::try_from(name)?))
+    }
+}
+
+/// Batch of `Nic` calls sharing one socket, returned by [`Nic::session`].
+#[derive(Debug)]
+pub struct Session<'a> {
+    nic: &'a Nic,
+    socket: Option<Box<dyn OpenSocket + 'a>>,
+}
+
+impl Session<'_> {
+    pub fn get_lladd(&self, ifid: impl Into<IfId>) -> Result<LinkLevelAddress> {
+        let ifid = ifid.into();
+        match (&self.nic.backend, &self.socket) {
+            (Backend::Socket(socket), Some(open_socket)) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+
+                open_socket.get_lladdr(ifreq::as_mut_ptr(&mut ifreq))?;
+
+                Ok(ifreq::get_lladdr(&ifreq))
+            }
+            _ => self.nic.get_lladd(ifid),
+        }
+    }
+
+    pub fn set_lladd(&self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Result<()> {
+        lladdr.check_assignable()?;
+        let ifid = ifid.into();
+        match (&self.nic.backend, &self.socket) {
+            (Backend::Socket(socket), Some(open_socket)) => {
+                let mut ifreq = ifreq::new();
+                ifreq::set_name(&mut ifreq, &get_name(socket, ifid)?);
+                ifreq::set_lladdr(&mut ifreq, lladdr);
+
+                open_socket.set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+            }
+            _ => self.nic.set_lladd(ifid, lladdr),
+        }
+    }
+
+    /// Reads the address of every interface, in order. Each interface gets
+    /// its own result, as links may come and go while polling.
+    pub fn get_many(&self, ifnames: &[IfName]) -> Vec<Result<LinkLevelAddress>> {
+        ifnames
+            .iter()
+            .map(|ifname| self.get_lladd(ifname))
+            .collect()
+    }
+
+    /// Sets every address in order and stops at the first failure, leaving
+    /// the earlier changes in place. All addresses are checked to be
+    /// assignable before any is set.
+    pub fn apply(&self, changes: &[(IfName, LinkLevelAddress)]) -> Result<()> {
+        for (_, lladdr) in changes {
+            lladdr.check_assignable()?;
+        }
+        for (ifname, lladdr) in changes {
+            self.set_lladd(ifname, lladdr)?;
+        }
+        Ok(())
+    }
+}
 
-        self.socket
-            .open_local_dgram()?
-            .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
+fn get_name(socket: &BoxSocket, ifid: IfId) -> Result<IfName> {
+    match ifid {
+        IfId::Name(ifname) => Ok(ifname),
+        IfId::Index(ifindex) => socket.get_name(ifindex),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{BoxSocket, IfName, Nic};
-    use crate::sys::os::ifreq::mock::{ifreq_get_lladdr, ifreq_get_name, ifreq_set_lladdr};
+    use super::{Backend, BoxSocket, IfName, Nic};
+    use crate::sys::os::ifreq::mock::{
+        ifreq_get_flags, ifreq_get_lladdr, ifreq_get_mtu, ifreq_get_name, ifreq_set_flags,
+        ifreq_set_lladdr, ifreq_set_mtu,
+    };
     use crate::sys::os::socket::mock::{self, ErrNo, MockSocket};
-    use crate::{LinkLevelAddress, Result};
+    use crate::{
+        BounceError, ErrorKind, IfFlags, IfId, IfIndex, Link, LinkLevelAddress, Operation, Result,
+    };
     use mockdown::Mockdown;
-    use std::sync::LazyLock;
 
     impl Nic {
         fn new(socket: &MockSocket) -> Nic {
             Nic {
-                socket: BoxSocket(Box::new(socket.clone())),
+                backend: Backend::Socket(BoxSocket(Box::new(socket.clone()))),
             }
         }
     }
 
-    static IFNAME: LazyLock<IfName> = LazyLock::new(|| "enx".try_into().unwrap());
-    static LLADDR: LazyLock<LinkLevelAddress> =
-        LazyLock::new(|| "00:11:22:33:44:55".parse().unwrap());
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
 
     #[test]
     fn test_nic_default() {
-        let expected_default = "Nic { socket: BoxSocket(LibcSocket(BoxSys(LibcSys))) }";
+        let expected_default = "Nic { backend: Socket(BoxSocket(LibcSocket(BoxSys(LibcSys)))) }";
 
         let nic = super::Nic::default();
 
@@ -64,34 +338,382 @@ mod tests {
     #[test]
     fn test_get_lladd() {
         let socket = MockSocket::default()
-            .expect(|mock::OpenLocalDgram()| {
-                assert!(true);
-                ErrNo::None
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::new(&socket).get_lladd(IFNAME).unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_get_lladd_by_index() {
+        let socket = MockSocket::default()
+            .expect(|mock::GetName(ifindex)| {
+                assert_eq!(ifindex, IfIndex::from(2));
+                Result::Ok(IFNAME)
             })
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
             .expect(|mock::GetLLAddr(ifreq)| {
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
-                ifreq_set_lladdr(ifreq, *LLADDR);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
                 Result::Ok(())
             });
 
-        let lladdr = Nic::new(&socket).get_lladd(&IFNAME).unwrap();
+        let lladdr = Nic::new(&socket).get_lladd(IfIndex::from(2)).unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_get_lladd_by_index_error() {
+        let socket = MockSocket::default()
+            .expect(|mock::GetName(_)| Result::<IfName>::Err("IndexToNameError".into()));
+
+        let error = Nic::new(&socket).get_lladd(IfIndex::from(2)).unwrap_err();
+
+        assert_eq!(format!("{}", error), "IndexToNameError");
+    }
+
+    #[test]
+    fn test_list() {
+        let socket = MockSocket::default().expect(|mock::GetLinks()| {
+            Result::Ok(vec![Link::new(
+                IFNAME,
+                2.into(),
+                LLADDR,
+                IfFlags::default(),
+            )])
+        });
+
+        let links = Nic::new(&socket).list().unwrap();
+
+        assert_eq!(
+            links,
+            vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::default())]
+        );
+    }
+
+    #[test]
+    fn test_get_hwaddr() {
+        let socket = MockSocket::default().expect(|mock::GetLinks()| {
+            Result::Ok(vec![Link::new(
+                IFNAME,
+                2.into(),
+                LLADDR,
+                IfFlags::default(),
+            )])
+        });
+
+        let hwaddr = Nic::new(&socket).get_hwaddr(IFNAME).unwrap();
+
+        assert!(hwaddr.is_ether());
+        assert_eq!(hwaddr[..], LLADDR[..]);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let ifid = Nic::new(&MockSocket::default()).resolve("enx").unwrap();
 
-        assert_eq!(lladdr, *LLADDR);
+        assert_eq!(ifid, IfId::from(IFNAME));
     }
 
     #[test]
     fn test_set_lladd() {
         let socket = MockSocket::default()
-            .expect(|mock::OpenLocalDgram()| {
-                assert!(true);
-                ErrNo::None
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_lladd(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_multicast_error() {
+        let socket = MockSocket::default();
+        let lladdr = "01:00:5e:00:00:01".parse().unwrap();
+
+        let error = Nic::new(&socket).set_lladd(IFNAME, &lladdr).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "LinkLevelAddress::UnassignableError { lladdr: \"01:00:5e:00:00:01\", reason: \"multicast\" }"
+        );
+    }
+
+    #[test]
+    fn test_get_flags() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                Result::Ok(())
+            });
+
+        let flags = Nic::new(&socket).get_flags(IFNAME).unwrap();
+
+        assert!(flags.is_up());
+    }
+
+    #[test]
+    fn test_get_mtu() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetMtu(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_mtu(ifreq, 1420);
+                Result::Ok(())
+            });
+
+        let mtu = Nic::new(&socket).get_mtu(IFNAME).unwrap();
+
+        assert_eq!(mtu, 1420);
+    }
+
+    #[test]
+    fn test_set_mtu() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetMtu(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_mtu(ifreq), 1420);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_mtu(IFNAME, 1420).unwrap();
+    }
+
+    #[test]
+    fn test_set_up() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_BROADCAST as u32));
+                Result::Ok(())
+            })
+            .expect(|mock::SetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                let flags = (libc::IFF_UP | libc::IFF_BROADCAST) as u32;
+                assert_eq!(ifreq_get_flags(ifreq), IfFlags::from(flags));
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_up(IFNAME).unwrap();
+    }
+
+    #[test]
+    fn test_set_down() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetFlags(ifreq)| {
+                let flags = (libc::IFF_UP | libc::IFF_BROADCAST) as u32;
+                ifreq_set_flags(ifreq, IfFlags::from(flags));
+                Result::Ok(())
+            })
+            .expect(|mock::SetFlags(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                let flags = libc::IFF_BROADCAST as u32;
+                assert_eq!(ifreq_get_flags(ifreq), IfFlags::from(flags));
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_down(IFNAME).unwrap();
+    }
+
+    fn expect_flags(socket: MockSocket, up: bool) -> MockSocket {
+        let socket = socket.expect(|mock::OpenLocalDgram()| ErrNo::None);
+        match up {
+            true => socket.expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                Result::Ok(())
+            }),
+            false => socket.expect(|mock::GetFlags(ifreq)| {
+                ifreq_set_flags(ifreq, IfFlags::default());
+                Result::Ok(())
+            }),
+        }
+    }
+
+    #[test]
+    fn test_set_lladd_bounce() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(ifreq)| {
+                assert!(!ifreq_get_flags(ifreq).is_up());
+                Result::Ok(())
+            })
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+        let socket = expect_flags(socket, false).expect(|mock::SetFlags(ifreq)| {
+            assert!(ifreq_get_flags(ifreq).is_up());
+            Result::Ok(())
+        });
+
+        Nic::new(&socket).set_lladd_bounce(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_when_down() {
+        let socket = expect_flags(MockSocket::default(), false)
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            });
+
+        Nic::new(&socket).set_lladd_bounce(IFNAME, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_restores_on_error() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(_)| Result::Ok(()))
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| Result::<()>::Err("SetLinkLevelAddressError".into()));
+        let socket = expect_flags(socket, false).expect(|mock::SetFlags(ifreq)| {
+            assert!(ifreq_get_flags(ifreq).is_up());
+            Result::Ok(())
+        });
+
+        let error = Nic::new(&socket)
+            .set_lladd_bounce(IFNAME, &LLADDR)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error), "SetLinkLevelAddressError");
+    }
+
+    #[test]
+    fn test_set_lladd_bounce_restore_error() {
+        let socket = expect_flags(MockSocket::default(), true);
+        let socket = expect_flags(socket, true)
+            .expect(|mock::SetFlags(_)| Result::Ok(()))
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| Result::<()>::Err("SetLinkLevelAddressError".into()));
+        let socket = expect_flags(socket, false)
+            .expect(|mock::SetFlags(_)| Result::<()>::Err("SetFlagsError".into()));
+
+        let error = Nic::new(&socket)
+            .set_lladd_bounce(IFNAME, &LLADDR)
+            .unwrap_err();
+
+        let error = error.downcast_ref::<BounceError>().unwrap();
+        assert_eq!(error.ifid(), IfId::from(IFNAME));
+        assert_eq!(error.error().kind(), ErrorKind::Other);
+        assert_eq!(error.error().operation(), Operation::SetLinkLevelAddress);
+        assert_eq!(
+            format!("{}", std::error::Error::source(error.error()).unwrap()),
+            "SetLinkLevelAddressError"
+        );
+        assert_eq!(format!("{}", error.restore_error()), "SetFlagsError");
+    }
+
+    #[test]
+    fn test_session_get_many() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::GetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
             })
+            .expect(|mock::GetLLAddr(_)| -> Result<()> { Err("GetLinkLevelAddressError".into()) })
+            .expect(|mock::GetLLAddr(ifreq)| {
+                ifreq_set_lladdr(ifreq, LLADDR);
+                Result::Ok(())
+            });
+        let nic = Nic::new(&socket);
+
+        let lladdrs = nic.session().unwrap().get_many(&[IFNAME; 3]);
+
+        assert_eq!(lladdrs.len(), 3);
+        assert_eq!(*lladdrs[0].as_ref().unwrap(), LLADDR);
+        assert_eq!(
+            format!("{}", lladdrs[1].as_ref().unwrap_err()),
+            "GetLinkLevelAddressError"
+        );
+        assert_eq!(*lladdrs[2].as_ref().unwrap(), LLADDR);
+    }
+
+    #[test]
+    fn test_session_apply() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
             .expect(|mock::SetLLAddr(ifreq)| {
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
-                assert_eq!(ifreq_get_lladdr(ifreq), *LLADDR);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
+                Result::Ok(())
+            })
+            .expect(|mock::GetName(ifindex)| {
+                assert_eq!(ifindex, IfIndex::from(2));
+                Result::Ok(IFNAME)
+            })
+            .expect(|mock::SetLLAddr(ifreq)| {
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), crate::lladdr!("66:77:88:99:aa:bb"));
                 Result::Ok(())
             });
+        let nic = Nic::new(&socket);
+        let session = nic.session().unwrap();
+
+        session.apply(&[(IFNAME, LLADDR)]).unwrap();
+        session
+            .set_lladd(IfIndex::from(2), &crate::lladdr!("66:77:88:99:aa:bb"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_session_apply_stops_at_error() {
+        let socket = MockSocket::default()
+            .expect(|mock::OpenLocalDgram()| ErrNo::None)
+            .expect(|mock::SetLLAddr(_)| -> Result<()> { Err("SetLinkLevelAddressError".into()) });
+        let nic = Nic::new(&socket);
+
+        let error = nic
+            .session()
+            .unwrap()
+            .apply(&[
+                (IFNAME, LLADDR),
+                (IFNAME, crate::lladdr!("66:77:88:99:aa:bb")),
+            ])
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error), "SetLinkLevelAddressError");
+    }
+
+    #[test]
+    fn test_session_apply_unassignable() {
+        let socket = MockSocket::default().expect(|mock::OpenLocalDgram()| ErrNo::None);
+        let nic = Nic::new(&socket);
+        let multicast = crate::lladdr!("01:00:5e:00:00:01");
+
+        let error = nic
+            .session()
+            .unwrap()
+            .apply(&[(IFNAME, LLADDR), (IFNAME, multicast)])
+            .unwrap_err();
+
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, crate::ErrorKind::InvalidAddress);
+    }
+
+    #[test]
+    fn test_session_open_error() {
+        let socket = MockSocket::default().expect(|mock::OpenLocalDgram()| Some(libc::EMFILE));
+
+        let error = Nic::new(&socket).session().unwrap_err();
 
-        Nic::new(&socket).set_lladd(&IFNAME, &LLADDR).unwrap();
+        let operation = error.downcast_ref::<crate::Error>().unwrap().operation();
+        assert_eq!(operation, crate::Operation::Open);
     }
 }
@@ -1,7 +1,9 @@
+use super::ifaddrs::{self};
+use super::ifindex::{self, IfIndex};
 use super::ifname::IfName;
 use super::ifreq::{self};
 use super::sys::{self, BoxSys};
-use crate::{LinkLevelAddress, Result};
+use crate::{IfFlags, Link, LinkLevelAddress, Operation, Result};
 use std::fmt::{Debug, Display};
 use std::ops::Deref;
 
@@ -16,7 +18,12 @@ enum Error {
         libc::c_int,
         libc::c_int,
     ),
+    GetFlags(libc::c_int, IfName, libc::c_int, libc::c_int),
+    SetFlags(libc::c_int, IfName, IfFlags, libc::c_int, libc::c_int),
+    GetMtu(libc::c_int, IfName, libc::c_int, libc::c_int),
+    SetMtu(libc::c_int, IfName, u32, libc::c_int, libc::c_int),
     Close(libc::c_int, libc::c_int, libc::c_int),
+    GetIfAddrs(libc::c_int, libc::c_int),
 }
 
 impl std::error::Error for Error {}
@@ -53,6 +60,40 @@ impl Debug for Error {
                 .field("errno", errno)
                 .field("strerror", &sys::strerror(*errno))
                 .finish(),
+            Error::GetFlags(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetFlagsError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetFlags(fd, ifname, flags, ret, errno) => f
+                .debug_struct("Socket::SetFlagsError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("flags", flags)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::GetMtu(fd, ifname, ret, errno) => f
+                .debug_struct("Socket::GetMtuError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+            Error::SetMtu(fd, ifname, mtu, ret, errno) => f
+                .debug_struct("Socket::SetMtuError")
+                .field("fd", fd)
+                .field("ifname", ifname)
+                .field("mtu", mtu)
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
             Error::Close(fd, ret, errno) => f
                 .debug_struct("Socket::CloseError")
                 .field("fd", fd)
@@ -60,6 +101,42 @@ impl Debug for Error {
                 .field("errno", errno)
                 .field("strerror", &sys::strerror(*errno))
                 .finish(),
+            Error::GetIfAddrs(ret, errno) => f
+                .debug_struct("Socket::GetIfAddrsError")
+                .field("ret", ret)
+                .field("errno", errno)
+                .field("strerror", &sys::strerror(*errno))
+                .finish(),
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(error: Error) -> Self {
+        match error.clone() {
+            Error::OpenLocalDgram(_, errno) => Self::from_errno(errno, Operation::Open, error),
+            Error::GetLinkLevelAddress(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetLinkLevelAddress, error).with_ifid(ifname)
+            }
+            Error::SetLinkLevelAddress(_, ifname, lladdr, _, errno) => {
+                Self::from_errno(errno, Operation::SetLinkLevelAddress, error)
+                    .with_ifid(ifname)
+                    .with_lladdr(lladdr)
+            }
+            Error::GetFlags(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetFlags, error).with_ifid(ifname)
+            }
+            Error::SetFlags(_, ifname, _, _, errno) => {
+                Self::from_errno(errno, Operation::SetFlags, error).with_ifid(ifname)
+            }
+            Error::GetMtu(_, ifname, _, errno) => {
+                Self::from_errno(errno, Operation::GetMtu, error).with_ifid(ifname)
+            }
+            Error::SetMtu(_, ifname, _, _, errno) => {
+                Self::from_errno(errno, Operation::SetMtu, error).with_ifid(ifname)
+            }
+            Error::Close(_, _, errno) => Self::from_errno(errno, Operation::Close, error),
+            Error::GetIfAddrs(_, errno) => Self::from_errno(errno, Operation::GetLinks, error),
         }
     }
 }
@@ -67,7 +144,9 @@ impl Debug for Error {
 type SocketResult<'a> = Result<Box<dyn OpenSocket + 'a>>;
 
 pub(super) trait Socket: Debug {
-    fn open_local_dgram(&self) -> SocketResult;
+    fn open_local_dgram(&self) -> SocketResult<'_>;
+    fn get_links(&self) -> Result<Vec<Link>>;
+    fn get_name(&self, ifindex: IfIndex) -> Result<IfName>;
 }
 
 #[derive(Debug, Default)]
@@ -99,20 +178,43 @@ impl Deref for LibcSocket {
 }
 
 impl Socket for LibcSocket {
-    fn open_local_dgram(&self) -> SocketResult {
+    fn open_local_dgram(&self) -> SocketResult<'_> {
         match self.socket(libc::AF_LOCAL, libc::SOCK_DGRAM, 0) {
             fd if fd >= 0 => Ok(Box::new(LibcOpenSocket { fd, sys: self })),
             ret => {
                 let errno = self.errno();
-                Err(Error::OpenLocalDgram(ret, errno).into())
+                Err(crate::Error::from(Error::OpenLocalDgram(ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_links(&self) -> Result<Vec<Link>> {
+        let mut ifap = std::ptr::null_mut();
+        match self.getifaddrs(&mut ifap) {
+            0 => {
+                let links = ifaddrs::get_links(ifap);
+                self.freeifaddrs(ifap);
+                Ok(links)
+            }
+            ret => {
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetIfAddrs(ret, errno)).into())
             }
         }
     }
+
+    fn get_name(&self, ifindex: IfIndex) -> Result<IfName> {
+        ifindex::to_name(self, ifindex)
+    }
 }
 
 pub(super) trait OpenSocket: Debug {
     fn get_lladdr(&self, arg: *mut libc::c_void) -> Result<()>;
     fn set_lladdr(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn get_flags(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn set_flags(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()>;
+    fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()>;
 }
 
 #[derive(Debug)]
@@ -138,7 +240,7 @@ impl<'a> OpenSocket for LibcOpenSocket<'a> {
                 let ifreq = ifreq::from_mut_ptr(arg);
                 let ifname = ifreq::get_name(ifreq);
                 let errno = self.errno();
-                Err(Error::GetLinkLevelAddress(fd, ifname, ret, errno).into())
+                Err(crate::Error::from(Error::GetLinkLevelAddress(fd, ifname, ret, errno)).into())
             }
         }
     }
@@ -152,7 +254,64 @@ impl<'a> OpenSocket for LibcOpenSocket<'a> {
                 let ifname = ifreq::get_name(ifreq);
                 let lladdr = ifreq::get_lladdr(ifreq);
                 let errno = self.errno();
-                Err(Error::SetLinkLevelAddress(fd, ifname, lladdr, ret, errno).into())
+                Err(
+                    crate::Error::from(Error::SetLinkLevelAddress(fd, ifname, lladdr, ret, errno))
+                        .into(),
+                )
+            }
+        }
+    }
+
+    fn get_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCGIFFLAGS, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetFlags(fd, ifname, ret, errno)).into())
+            }
+        }
+    }
+
+    fn set_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCSIFFLAGS, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let flags = ifreq::get_flags(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::SetFlags(fd, ifname, flags, ret, errno)).into())
+            }
+        }
+    }
+
+    fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCGIFMTU, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::GetMtu(fd, ifname, ret, errno)).into())
+            }
+        }
+    }
+
+    fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+        let fd = self.fd;
+        match self.ioctl(fd, sys::SIOCSIFMTU, arg) {
+            0 => Ok(()),
+            ret => {
+                let ifreq = ifreq::from_mut_ptr(arg);
+                let ifname = ifreq::get_name(ifreq);
+                let mtu = ifreq::get_mtu(ifreq);
+                let errno = self.errno();
+                Err(crate::Error::from(Error::SetMtu(fd, ifname, mtu, ret, errno)).into())
             }
         }
     }
@@ -176,7 +335,12 @@ impl<'a> Drop for LibcOpenSocket<'a> {
 mod tests {
     use super::super::sys::mock::{self, MockSys};
     use super::{ifreq, BoxSys, IfName, LibcSocket, LinkLevelAddress, Result, Socket};
-    use crate::sys::os::ifreq::mock::{ifreq_get_lladdr, ifreq_get_name, ifreq_set_lladdr};
+    use crate::sys::os::ifaddrs::mock::{ifaddrs_free, ifaddrs_new};
+    use crate::sys::os::ifreq::mock::{
+        ifreq_get_flags, ifreq_get_lladdr, ifreq_get_mtu, ifreq_get_name, ifreq_set_flags,
+        ifreq_set_lladdr, ifreq_set_mtu,
+    };
+    use crate::{IfFlags, Link};
     use mockdown::Mockdown;
     use std::sync::LazyLock;
 
@@ -186,9 +350,10 @@ mod tests {
         }
     }
 
-    static IFNAME: LazyLock<IfName> = LazyLock::new(|| "enx".try_into().unwrap());
-    static LLADDR: LazyLock<LinkLevelAddress> =
-        LazyLock::new(|| "00:11:22:33:44:55".parse().unwrap());
+    const IFNAME: IfName = crate::ifname!("enx");
+    const LLADDR: LinkLevelAddress = crate::lladdr!("00:11:22:33:44:55");
+    static LINKS: LazyLock<Vec<Link>> =
+        LazyLock::new(|| vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::default())]);
 
     const MOCK_FD: libc::c_int = 3;
 
@@ -228,6 +393,19 @@ mod tests {
         assert_eq!(format!("{:?}", deref_box_socket), expected_deref);
     }
 
+    #[test]
+    fn test_socket_get_name() {
+        let sys = MockSys::default().expect(|mock::IfIndexToName(ifindex, ifname)| {
+            assert_eq!(ifindex, 2);
+            unsafe { std::ptr::copy_nonoverlapping(c"enx".as_ptr(), ifname, 4) };
+            ifname
+        });
+
+        let ifname = LibcSocket::new(&sys).get_name(2.into()).unwrap();
+
+        assert_eq!(ifname, IFNAME);
+    }
+
     #[test]
     fn test_open_socket_box_debug() {
         let sys = &BoxSys::default();
@@ -266,18 +444,15 @@ mod tests {
                 assert_eq!(MOCK_SOCKET, args);
                 RETURN_FAILURE
             })
-            .expect(|_: mock::ErrNo| {
-                assert!(true);
-                libc::EPERM
-            });
+            .expect(|_: mock::ErrNo| libc::EPERM);
 
         let expected_error = "Socket::OpenLocalDgramError { ret: -1, errno: 1, strerror: \"Operation not permitted\" }";
         let socket = LibcSocket::new(&sys);
 
         let error = socket.open_local_dgram().unwrap_err();
 
-        assert_eq!(format!("{}", error), expected_error);
-        assert_eq!(format!("{:?}", error), expected_error);
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
     }
 
     #[test]
@@ -289,8 +464,8 @@ mod tests {
             })
             .expect(|mock::IoCtl(args, ifreq)| {
                 assert_eq!((MOCK_FD, super::sys::SIOCGIFLLADDR), args);
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
-                ifreq_set_lladdr(ifreq, *LLADDR);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_lladdr(ifreq, LLADDR);
                 RETURN_SUCCESS
             })
             .expect(|args| {
@@ -306,7 +481,7 @@ mod tests {
             .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))
             .unwrap();
 
-        assert_eq!(ifreq::get_lladdr(&ifreq), *LLADDR);
+        assert_eq!(ifreq::get_lladdr(&ifreq), LLADDR);
         Ok(())
     }
 
@@ -319,13 +494,10 @@ mod tests {
             })
             .expect(|mock::IoCtl(args, ifreq)| {
                 assert_eq!((MOCK_FD, super::sys::SIOCGIFLLADDR), args);
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
                 RETURN_FAILURE
             })
-            .expect(|_: mock::ErrNo| {
-                assert!(true);
-                libc::EBADF
-            })
+            .expect(|_: mock::ErrNo| libc::EBADF)
             .expect(|args| {
                 assert_eq!(MOCK_CLOSE, args);
                 RETURN_SUCCESS
@@ -340,8 +512,8 @@ mod tests {
             .get_lladdr(ifreq::as_mut_ptr(&mut ifreq))
             .unwrap_err();
 
-        assert_eq!(format!("{}", error), expected_error);
-        assert_eq!(format!("{:?}", error), expected_error);
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
 
         Ok(())
     }
@@ -355,8 +527,8 @@ mod tests {
             })
             .expect(|mock::IoCtl(args, ifreq)| {
                 assert_eq!((MOCK_FD, super::sys::SIOCSIFLLADDR), args);
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
-                assert_eq!(ifreq_get_lladdr(ifreq), *LLADDR);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
                 RETURN_SUCCESS
             })
             .expect(|args| {
@@ -384,14 +556,11 @@ mod tests {
             })
             .expect(|mock::IoCtl(args, ifreq)| {
                 assert_eq!((MOCK_FD, super::sys::SIOCSIFLLADDR), args);
-                assert_eq!(ifreq_get_name(ifreq), *IFNAME);
-                assert_eq!(ifreq_get_lladdr(ifreq), *LLADDR);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_lladdr(ifreq), LLADDR);
                 RETURN_FAILURE
             })
-            .expect(|_: mock::ErrNo| {
-                assert!(true);
-                libc::EINVAL
-            })
+            .expect(|_: mock::ErrNo| libc::EINVAL)
             .expect(|args| {
                 assert_eq!(MOCK_CLOSE, args);
                 RETURN_SUCCESS
@@ -407,8 +576,256 @@ mod tests {
             .set_lladdr(ifreq::as_mut_ptr(&mut ifreq))
             .unwrap_err();
 
-        assert_eq!(format!("{}", error), expected_error);
-        assert_eq!(format!("{:?}", error), expected_error);
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_flags() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_flags(ifreq, IfFlags::from(libc::IFF_UP as u32));
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        assert!(ifreq::get_flags(&ifreq).is_up());
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_flags_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::ENXIO)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetFlagsError { fd: 3, ifname: \"enx\", ret: -1, errno: 6, strerror: \"Device not configured\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_flags(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_flags() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert!(ifreq_get_flags(ifreq).is_up());
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_flags(&mut ifreq, IfFlags::from(libc::IFF_UP as u32));
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_flags(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_mtu() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFMTU), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                ifreq_set_mtu(ifreq, 1420);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        assert_eq!(ifreq::get_mtu(&ifreq), 1420);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_get_mtu_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, _)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCGIFMTU), args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::ENXIO)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::GetMtuError { fd: 3, ifname: \"enx\", ret: -1, errno: 6, strerror: \"Device not configured\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .get_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_mtu() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFMTU), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                assert_eq!(ifreq_get_mtu(ifreq), 1420);
+                RETURN_SUCCESS
+            })
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_mtu(&mut ifreq, 1420);
+
+        LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_mtu(ifreq::as_mut_ptr(&mut ifreq))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_mtu_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, _)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFMTU), args);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EINVAL)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::SetMtuError { fd: 3, ifname: \"enx\", mtu: 70000, ret: -1, errno: 22, strerror: \"Invalid argument\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_mtu(&mut ifreq, 70000);
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_mtu(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_socket_set_flags_error() -> Result<()> {
+        let sys = MockSys::default()
+            .expect(|args| {
+                assert_eq!(MOCK_SOCKET, args);
+                RETURN_FD
+            })
+            .expect(|mock::IoCtl(args, ifreq)| {
+                assert_eq!((MOCK_FD, super::sys::SIOCSIFFLAGS), args);
+                assert_eq!(ifreq_get_name(ifreq), IFNAME);
+                RETURN_FAILURE
+            })
+            .expect(|_: mock::ErrNo| libc::EPERM)
+            .expect(|args| {
+                assert_eq!(MOCK_CLOSE, args);
+                RETURN_SUCCESS
+            });
+
+        let expected_error = "Socket::SetFlagsError { fd: 3, ifname: \"enx\", flags: \"UP\", ret: -1, errno: 1, strerror: \"Operation not permitted\" }";
+        let mut ifreq = ifreq::new();
+        ifreq::set_name(&mut ifreq, &IFNAME);
+        ifreq::set_flags(&mut ifreq, IfFlags::from(libc::IFF_UP as u32));
+
+        let error = LibcSocket::new(&sys)
+            .open_local_dgram()?
+            .set_flags(ifreq::as_mut_ptr(&mut ifreq))
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
 
         Ok(())
     }
@@ -443,10 +860,7 @@ mod tests {
                 assert_eq!(MOCK_CLOSE, args);
                 RETURN_FAILURE
             })
-            .expect(|_: mock::ErrNo| {
-                assert!(true);
-                libc::EINTR
-            });
+            .expect(|_: mock::ErrNo| libc::EINTR);
 
         let socket = LibcSocket::new(&sys);
 
@@ -454,23 +868,64 @@ mod tests {
 
         drop(open_socket);
     }
+
+    #[test]
+    fn test_socket_get_links() {
+        let sys = MockSys::default()
+            .expect(|mock::GetIfAddrs(ifap)| {
+                unsafe { *ifap = ifaddrs_new(&LINKS) };
+                RETURN_SUCCESS
+            })
+            .expect(|mock::FreeIfAddrs(ifa)| ifaddrs_free(ifa));
+
+        let links = LibcSocket::new(&sys).get_links().unwrap();
+
+        assert_eq!(links, *LINKS);
+    }
+
+    #[test]
+    fn test_socket_get_links_error() {
+        let sys = MockSys::default()
+            .expect(|mock::GetIfAddrs(_)| RETURN_FAILURE)
+            .expect(|_: mock::ErrNo| libc::ENOMEM);
+
+        let expected_error =
+            "Socket::GetIfAddrsError { ret: -1, errno: 12, strerror: \"Cannot allocate memory\" }";
+
+        let error = LibcSocket::new(&sys).get_links().unwrap_err();
+
+        assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        assert_eq!(format!("{:?}", error.source().unwrap()), expected_error);
+    }
 }
 
 #[cfg(test)]
 pub(super) mod mock {
     use super::{Error, OpenSocket, Socket, SocketResult};
-    use crate::Result;
+    use crate::{IfIndex, IfName, Link, Result};
     use mockdown::{ExpectStore, Mockdown};
     use std::ops::Deref;
 
     #[derive(Debug, PartialEq)]
     pub(crate) struct OpenLocalDgram();
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetLinks();
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetName(pub IfIndex);
     pub(crate) type ErrNo = Option<i32>;
 
     #[derive(Debug, PartialEq)]
     pub(crate) struct GetLLAddr(pub *mut libc::c_void);
     #[derive(Debug, PartialEq)]
     pub(crate) struct SetLLAddr(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetFlags(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetFlags(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetMtu(pub *mut libc::c_void);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct SetMtu(pub *mut libc::c_void);
 
     #[derive(Clone, Debug, Default)]
     pub(crate) struct MockSocket(ExpectStore);
@@ -482,14 +937,24 @@ pub(super) mod mock {
     }
 
     impl Socket for MockSocket {
-        fn open_local_dgram(&self) -> SocketResult {
+        fn open_local_dgram(&self) -> SocketResult<'_> {
             let args = OpenLocalDgram();
             let on_mock: ErrNo = self.on_mock(args).unwrap();
             match on_mock {
                 None => Ok(Box::new(MockOpenSocket(self))),
-                Some(errno) => Err(Error::OpenLocalDgram(-1, errno).into()),
+                Some(errno) => Err(crate::Error::from(Error::OpenLocalDgram(-1, errno)).into()),
             }
         }
+
+        fn get_links(&self) -> Result<Vec<Link>> {
+            let args = GetLinks();
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_name(&self, ifindex: IfIndex) -> Result<IfName> {
+            let args = GetName(ifindex);
+            self.on_mock(args).unwrap()
+        }
     }
 
     #[derive(Debug)]
@@ -513,5 +978,25 @@ pub(super) mod mock {
             let args = SetLLAddr(arg);
             self.on_mock(args).unwrap()
         }
+
+        fn get_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetFlags(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_flags(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = SetFlags(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn get_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = GetMtu(arg);
+            self.on_mock(args).unwrap()
+        }
+
+        fn set_mtu(&self, arg: *mut libc::c_void) -> Result<()> {
+            let args = SetMtu(arg);
+            self.on_mock(args).unwrap()
+        }
     }
 }
@@ -1,4 +1,4 @@
-use libc::{c_int, c_ulong, c_void};
+use libc::{c_char, c_int, c_uint, c_ulong, c_void, ifaddrs};
 use std::{fmt::Debug, ops::Deref};
 
 mod ioccom {
@@ -49,6 +49,26 @@ pub(super) const SIOCGIFLLADDR: c_ulong = ioccom::iorw(ioccom::I, 158, IFREQ_SIZ
 // https://github.com/apple/darwin-xnu/blob/2ff845c2e033bd0ff64b5b6aa6063a1f8f65aa32/bsd/sys/sockio.h#L146
 pub(super) const SIOCSIFLLADDR: c_ulong = ioccom::iow(ioccom::I, 60, IFREQ_SIZE);
 
+// Get ifnet flags
+// SIOCGIFFLAGS = (0x80000000 |0x40000000) | 32 << 16 | (105 << 8) | 17 = 0xc0206911
+// https://github.com/apple/darwin-xnu/blob/2ff845c2e033bd0ff64b5b6aa6063a1f8f65aa32/bsd/sys/sockio.h
+pub(super) const SIOCGIFFLAGS: c_ulong = ioccom::iorw(ioccom::I, 17, IFREQ_SIZE);
+
+// Set ifnet flags
+// SIOCSIFFLAGS = 0x80000000 | 32 << 16 | (105 << 8) | 16 = 0x80206910
+// https://github.com/apple/darwin-xnu/blob/2ff845c2e033bd0ff64b5b6aa6063a1f8f65aa32/bsd/sys/sockio.h
+pub(super) const SIOCSIFFLAGS: c_ulong = ioccom::iow(ioccom::I, 16, IFREQ_SIZE);
+
+// Get ifnet mtu
+// SIOCGIFMTU = (0x80000000 |0x40000000) | 32 << 16 | (105 << 8) | 51 = 0xc0206933
+// https://github.com/apple/darwin-xnu/blob/2ff845c2e033bd0ff64b5b6aa6063a1f8f65aa32/bsd/sys/sockio.h
+pub(super) const SIOCGIFMTU: c_ulong = ioccom::iorw(ioccom::I, 51, IFREQ_SIZE);
+
+// Set ifnet mtu
+// SIOCSIFMTU = 0x80000000 | 32 << 16 | (105 << 8) | 52 = 0x80206934
+// https://github.com/apple/darwin-xnu/blob/2ff845c2e033bd0ff64b5b6aa6063a1f8f65aa32/bsd/sys/sockio.h
+pub(super) const SIOCSIFMTU: c_ulong = ioccom::iow(ioccom::I, 52, IFREQ_SIZE);
+
 pub(super) fn strerror(errno: c_int) -> String {
     let ptr = unsafe { libc::strerror(errno) };
     let c_str = unsafe { std::ffi::CStr::from_ptr(ptr) };
@@ -59,6 +79,10 @@ pub(super) trait Sys: Debug {
     fn socket(&self, domain: c_int, ty: c_int, protocol: c_int) -> c_int;
     fn ioctl(&self, fd: c_int, request: c_ulong, arg: *mut c_void) -> c_int;
     fn close(&self, fd: c_int) -> c_int;
+    fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int;
+    fn freeifaddrs(&self, ifa: *mut ifaddrs);
+    fn if_nametoindex(&self, ifname: *const c_char) -> c_uint;
+    fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char;
     fn errno(&self) -> c_int;
 }
 
@@ -96,6 +120,22 @@ impl Sys for LibcSys {
         unsafe { libc::close(fd) }
     }
 
+    fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int {
+        unsafe { libc::getifaddrs(ifap) }
+    }
+
+    fn freeifaddrs(&self, ifa: *mut ifaddrs) {
+        unsafe { libc::freeifaddrs(ifa) }
+    }
+
+    fn if_nametoindex(&self, ifname: *const c_char) -> c_uint {
+        unsafe { libc::if_nametoindex(ifname) }
+    }
+
+    fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char {
+        unsafe { libc::if_indextoname(ifindex, ifname) }
+    }
+
     fn errno(&self) -> c_int {
         unsafe { *libc::__error() }
     }
@@ -122,6 +162,26 @@ mod tests {
         assert_eq!(super::SIOCSIFLLADDR, 0x8020693c)
     }
 
+    #[test]
+    fn test_get_flags() {
+        assert_eq!(super::SIOCGIFFLAGS, 0xc0206911)
+    }
+
+    #[test]
+    fn test_set_flags() {
+        assert_eq!(super::SIOCSIFFLAGS, 0x80206910)
+    }
+
+    #[test]
+    fn test_get_mtu() {
+        assert_eq!(super::SIOCGIFMTU, 0xc0206933)
+    }
+
+    #[test]
+    fn test_set_mtu() {
+        assert_eq!(super::SIOCSIFMTU, 0x80206934)
+    }
+
     #[test]
     fn test_sys_strerror() {
         let errno = 1;
@@ -182,7 +242,7 @@ mod tests {
 #[cfg(test)]
 pub(super) mod mock {
     use super::Sys;
-    use libc::{c_int, c_ulong, c_void};
+    use libc::{c_char, c_int, c_uint, c_ulong, c_void, ifaddrs};
     use mockdown::{Mockdown, ExpectStore};
     use std::fmt::Debug;
 
@@ -192,6 +252,14 @@ pub(super) mod mock {
     pub(crate) struct IoCtl(pub (libc::c_int, libc::c_ulong), pub *mut libc::c_void);
     #[derive(Debug, PartialEq)]
     pub(crate) struct Close(pub libc::c_int);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct GetIfAddrs(pub *mut *mut libc::ifaddrs);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct FreeIfAddrs(pub *mut libc::ifaddrs);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct IfNameToIndex(pub String);
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct IfIndexToName(pub libc::c_uint, pub *mut libc::c_char);
     #[derive(Debug)]
     pub(crate) struct ErrNo();
 
@@ -226,6 +294,27 @@ pub(super) mod mock {
             self.on_mock(args).unwrap()
         }
 
+        fn getifaddrs(&self, ifap: *mut *mut ifaddrs) -> c_int {
+            let args = GetIfAddrs(ifap);
+            self.on_mock(args).unwrap()
+        }
+
+        fn freeifaddrs(&self, ifa: *mut ifaddrs) {
+            let args = FreeIfAddrs(ifa);
+            self.on_mock(args).unwrap()
+        }
+
+        fn if_nametoindex(&self, ifname: *const c_char) -> c_uint {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(ifname) };
+            let args = IfNameToIndex(c_str.to_string_lossy().into_owned());
+            self.on_mock(args).unwrap()
+        }
+
+        fn if_indextoname(&self, ifindex: c_uint, ifname: *mut c_char) -> *mut c_char {
+            let args = IfIndexToName(ifindex, ifname);
+            self.on_mock(args).unwrap()
+        }
+
         fn errno(&self) -> c_int {
             let args = ErrNo();
             self.on_mock(args).unwrap()
@@ -0,0 +1,228 @@
+use crate::{HardwareAddress, IfIndex, IfName, LinkLevelAddress};
+use core::fmt::Debug;
+
+const IF_FLAGS: [(libc::c_int, &str); 8] = [
+    (libc::IFF_UP, "UP"),
+    (libc::IFF_BROADCAST, "BROADCAST"),
+    (libc::IFF_LOOPBACK, "LOOPBACK"),
+    (libc::IFF_POINTOPOINT, "POINTOPOINT"),
+    (libc::IFF_RUNNING, "RUNNING"),
+    (libc::IFF_NOARP, "NOARP"),
+    (libc::IFF_PROMISC, "PROMISC"),
+    (libc::IFF_MULTICAST, "MULTICAST"),
+];
+
+#[derive(Copy, Clone, Default, Hash, PartialEq, Eq)]
+pub struct IfFlags(u32);
+
+impl IfFlags {
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(&self, flag: libc::c_int) -> bool {
+        self.0 & flag as u32 != 0
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.contains(libc::IFF_UP)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.contains(libc::IFF_RUNNING)
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        self.contains(libc::IFF_LOOPBACK)
+    }
+}
+
+impl From<u32> for IfFlags {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl Debug for IfFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = IF_FLAGS
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect();
+        write!(f, "{:?}", names.join("|"))
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Link {
+    ifname: IfName,
+    index: IfIndex,
+    lladdr: LinkLevelAddress,
+    hwaddr: HardwareAddress,
+    flags: IfFlags,
+}
+
+impl Link {
+    /// Mostly for [`crate::NicBackend`] implementations, `Nic::list` is
+    /// where links normally come from. The link is taken for Ethernet, see
+    /// [`Link::with_hwaddr`] for the others.
+    pub fn new(ifname: IfName, index: IfIndex, lladdr: LinkLevelAddress, flags: IfFlags) -> Self {
+        Self {
+            ifname,
+            index,
+            lladdr,
+            hwaddr: HardwareAddress::from(&lladdr),
+            flags,
+        }
+    }
+
+    /// Sets the address as the link really has it, for links whose address
+    /// is not 6 octets or that are not Ethernet.
+    pub fn with_hwaddr(self, hwaddr: HardwareAddress) -> Self {
+        Self { hwaddr, ..self }
+    }
+
+    pub fn ifname(&self) -> &IfName {
+        &self.ifname
+    }
+
+    pub fn index(&self) -> IfIndex {
+        self.index
+    }
+
+    /// The first 6 octets of the address, zero padded when it is shorter.
+    /// Only the whole address for Ethernet-like links, check
+    /// [`Link::hwaddr`] for the others.
+    pub fn lladdr(&self) -> &LinkLevelAddress {
+        &self.lladdr
+    }
+
+    /// The address at its own length, with its `ARPHRD_*` type.
+    pub fn hwaddr(&self) -> &HardwareAddress {
+        &self.hwaddr
+    }
+
+    pub fn flags(&self) -> IfFlags {
+        self.flags
+    }
+}
+
+impl Debug for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("Link");
+        debug
+            .field("ifname", &self.ifname)
+            .field("index", &self.index)
+            .field("lladdr", &self.lladdr);
+        // Only when it tells more than the lladdr
+        if self.hwaddr != HardwareAddress::from(&self.lladdr) {
+            debug
+                .field("hwaddr", &self.hwaddr)
+                .field("hwtype", &self.hwaddr.hwtype());
+        }
+        debug.field("flags", &self.flags).finish()
+    }
+}
+
+/// A change to a link, as reported by `Nic::watch` or queued by the `sim`
+/// backend.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum LinkEvent {
+    Added(Link),
+    Removed(Link),
+    CarrierUp(Link),
+    CarrierDown(Link),
+    AdminUp(Link),
+    AdminDown(Link),
+    AddressChanged { link: Link, from: LinkLevelAddress },
+    Renamed { link: Link, from: IfName },
+}
+
+impl LinkEvent {
+    /// The link as it stands after the event.
+    pub fn link(&self) -> &Link {
+        match self {
+            Self::Added(link)
+            | Self::Removed(link)
+            | Self::CarrierUp(link)
+            | Self::CarrierDown(link)
+            | Self::AdminUp(link)
+            | Self::AdminDown(link)
+            | Self::AddressChanged { link, .. }
+            | Self::Renamed { link, .. } => link,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IfFlags, Link};
+    use crate::{HardwareAddress, IfName, LinkLevelAddress};
+
+    const FLAGS: u32 = (libc::IFF_UP | libc::IFF_BROADCAST | libc::IFF_RUNNING) as u32;
+
+    #[test]
+    fn test_if_flags() {
+        let flags = IfFlags::from(FLAGS);
+
+        assert_eq!(flags.bits(), FLAGS);
+        assert!(flags.is_up());
+        assert!(flags.is_running());
+        assert!(!flags.is_loopback());
+        assert!(flags.contains(libc::IFF_BROADCAST));
+    }
+
+    #[test]
+    fn test_if_flags_debug() {
+        let flags = IfFlags::from(FLAGS);
+
+        assert_eq!(format!("{:?}", flags), "\"UP|BROADCAST|RUNNING\"");
+    }
+
+    #[test]
+    fn test_if_flags_default() {
+        let flags = IfFlags::default();
+
+        assert!(!flags.is_up());
+        assert_eq!(format!("{:?}", flags), "\"\"");
+    }
+
+    #[test]
+    fn test_link_debug() {
+        let ifname = IfName::try_from("enx").unwrap();
+        let lladdr: LinkLevelAddress = "00:11:22:33:44:55".parse().unwrap();
+
+        let link = Link::new(ifname, 2.into(), lladdr, IfFlags::from(FLAGS));
+
+        assert_eq!(link.ifname(), &ifname);
+        assert_eq!(link.index(), 2.into());
+        assert_eq!(link.lladdr(), &lladdr);
+        assert!(link.hwaddr().is_ether());
+        assert_eq!(link.flags(), IfFlags::from(FLAGS));
+        assert_eq!(
+            format!("{:?}", link),
+            "Link { ifname: \"enx\", index: 2, lladdr: \"00:11:22:33:44:55\", flags: \"UP|BROADCAST|RUNNING\" }"
+        );
+    }
+
+    #[test]
+    fn test_link_with_hwaddr() {
+        let ifname = IfName::try_from("ib0").unwrap();
+        let octets: Vec<u8> = (0..20).collect();
+        let hwaddr = HardwareAddress::new(libc::ARPHRD_INFINIBAND, &octets).unwrap();
+        let lladdr = LinkLevelAddress::from(&[0, 1, 2, 3, 4, 5]);
+
+        let link = Link::new(ifname, 4.into(), lladdr, IfFlags::default()).with_hwaddr(hwaddr);
+
+        assert_eq!(link.lladdr(), &lladdr);
+        assert_eq!(link.hwaddr(), &hwaddr);
+        assert!(!link.hwaddr().is_ether());
+        assert_eq!(
+            format!("{:?}", link),
+            "Link { ifname: \"ib0\", index: 4, lladdr: \"00:01:02:03:04:05\", \
+             hwaddr: \"00:01:02:03:04:05:06:07:08:09:0a:0b:0c:0d:0e:0f:10:11:12:13\", hwtype: 32, \
+             flags: \"\" }"
+        );
+    }
+}
@@ -1,14 +1,30 @@
+use crate::{ErrorKind, IfId, Operation};
 use core::fmt::{Debug, Display};
-use std::{ops::Deref, result::Result, str::FromStr};
+use std::{io::Read, net::Ipv6Addr, ops::Deref, result::Result, str::FromStr};
 
 const OCTETS_SIZE: usize = 6;
 
+// Bits of the first octet
+// https://standards.ieee.org/wp-content/uploads/import/documents/tutorials/macgrp.pdf
+const MULTICAST_BIT: u8 = 0x01;
+const LOCAL_BIT: u8 = 0x02;
+
+// Largest value a 48 bit address holds
+const MAX: u64 = (1 << 48) - 1;
+
+const BROADCAST: OctetsType = [0xff; OCTETS_SIZE];
+const ZERO: OctetsType = [0x00; OCTETS_SIZE];
+
 type OctetsType = [u8; OCTETS_SIZE];
 
 #[derive(Clone, PartialEq, Eq)]
 enum Error {
+    #[cfg(feature = "serde")]
     WrongNumberOfOctets(String, usize),
-    InvalidOctet(String, String, String),
+    Unassignable(LinkLevelAddress, &'static str),
+    NoPermanentAddress(IfId, LinkLevelAddress),
+    NotEui64LinkLocal(Ipv6Addr),
+    OutOfRange(u64),
 }
 
 impl std::error::Error for Error {}
@@ -22,27 +38,395 @@ impl Display for Error {
 impl Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            #[cfg(feature = "serde")]
             Self::WrongNumberOfOctets(value, octets_len) => f
                 .debug_struct("LinkLevelAddress::WrongNumberOfOctetsError")
                 .field("value", value)
                 .field("value_octets", octets_len)
                 .field("expected_octets", &OCTETS_SIZE)
                 .finish(),
-            Self::InvalidOctet(value, octet, error) => f
-                .debug_struct("LinkLevelAddress::InvalidOctetError")
-                .field("value", value)
-                .field("octet", octet)
-                .field("error", error)
+            Self::Unassignable(lladdr, reason) => f
+                .debug_struct("LinkLevelAddress::UnassignableError")
+                .field("lladdr", lladdr)
+                .field("reason", reason)
+                .finish(),
+            Self::NoPermanentAddress(ifid, lladdr) => f
+                .debug_struct("LinkLevelAddress::NoPermanentAddressError")
+                .field("ifid", ifid)
+                .field("lladdr", lladdr)
+                .finish(),
+            Self::NotEui64LinkLocal(addr) => f
+                .debug_struct("LinkLevelAddress::NotEui64LinkLocalError")
+                .field("addr", addr)
+                .finish(),
+            Self::OutOfRange(value) => f
+                .debug_struct("LinkLevelAddress::OutOfRangeError")
+                .field("value", &format_args!("{:#x}", value))
+                .field("max", &format_args!("{:#x}", MAX))
                 .finish(),
         }
     }
 }
 
+/// What made [`ParseLLAddrError`] reject its input.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLLAddrErrorKind {
+    /// The input is empty.
+    Empty,
+    /// The token holds something other than hex digits.
+    InvalidDigit,
+    /// The token has the wrong number of digits for the layout, e.g.
+    /// `300` between colons or `011` between dots.
+    InvalidOctetLength,
+    /// More or fewer than 6 octets. The token is the first extra one,
+    /// or empty at the end of the input when octets are missing.
+    WrongNumberOfOctets,
+}
+
+/// Error returned when parsing a [`LinkLevelAddress`] from a string.
+///
+/// `offset` is the byte position of `token` within `value`, enough to point
+/// a caret at the culprit.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParseLLAddrError {
+    kind: ParseLLAddrErrorKind,
+    value: String,
+    offset: usize,
+    token: String,
+}
+
+impl ParseLLAddrError {
+    fn new(kind: ParseLLAddrErrorKind, value: &str, offset: usize, token: &str) -> Self {
+        ParseLLAddrError {
+            kind,
+            value: value.to_string(),
+            offset,
+            token: token.to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> ParseLLAddrErrorKind {
+        self.kind
+    }
+
+    /// The whole input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl std::error::Error for ParseLLAddrError {}
+
+impl Display for ParseLLAddrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Textual layouts of a link level address.
+///
+/// `FromStr` detects the layout on its own, `LinkLevelAddress::format`
+/// renders one explicitly. `Display` uses `Colon`.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum MacFormat {
+    /// `00:11:22:aa:bb:cc`, as printed by `ip link`.
+    #[default]
+    Colon,
+    /// `00-11-22-aa-bb-cc`, as printed by Windows.
+    Dash,
+    /// `0011.22aa.bbcc`, as printed by Cisco switches.
+    Dotted,
+    /// `001122aabbcc`, as found in DHCP logs.
+    Bare,
+    /// `00:11:22:AA:BB:CC`.
+    Upper,
+}
+
+impl MacFormat {
+    fn detect(value: &str) -> Self {
+        if value.contains(':') {
+            Self::Colon
+        } else if value.contains('-') {
+            Self::Dash
+        } else if value.contains('.') {
+            Self::Dotted
+        } else if value.len() == 2 * OCTETS_SIZE {
+            Self::Bare
+        } else {
+            Self::Colon
+        }
+    }
+}
+
+/// Source of random bytes for `LinkLevelAddress::random_*`.
+///
+/// Tests inject a fixed source, everything else uses `OsRandom`.
+pub trait RandomSource {
+    fn fill(&mut self, bytes: &mut [u8]) -> crate::Result<()>;
+}
+
+/// Reads `/dev/urandom`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OsRandom;
+
+impl RandomSource for OsRandom {
+    fn fill(&mut self, bytes: &mut [u8]) -> crate::Result<()> {
+        std::fs::File::open("/dev/urandom")?.read_exact(bytes)?;
+        Ok(())
+    }
+}
+
 pub type LLAddr = LinkLevelAddress;
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+/// Orders like the 48 bit big-endian integer it stands for.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LinkLevelAddress(OctetsType);
 
+/// Builds a [`LinkLevelAddress`] from a string literal, checked at compile
+/// time. Takes the formats [`LinkLevelAddress::parse_const`] does.
+///
+/// ```
+/// const LLADDR: net_sys::LinkLevelAddress = net_sys::lladdr!("00:11:22:33:44:55");
+/// ```
+///
+/// ```compile_fail
+/// let lladdr = net_sys::lladdr!("00:11:22:33:44");
+/// ```
+#[macro_export]
+macro_rules! lladdr {
+    ($value:literal) => {{
+        const LLADDR: $crate::LinkLevelAddress = match $crate::LinkLevelAddress::parse_const($value)
+        {
+            Some(lladdr) => lladdr,
+            None => panic!(concat!("invalid link level address: ", $value)),
+        };
+        LLADDR
+    }};
+}
+
+impl LinkLevelAddress {
+    /// `const` counterpart of `from_str`. Only takes the colon, dash,
+    /// dotted and bare formats with two hex digits per octet.
+    pub const fn parse_const(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        let (group, separator) = match bytes.len() {
+            17 if bytes[2] == b':' || bytes[2] == b'-' => (2, bytes[2]),
+            14 => (4, b'.'),
+            12 => (12, 0),
+            _ => return None,
+        };
+        let mut octets = [0; OCTETS_SIZE];
+        let mut nibbles = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if i % (group + 1) == group {
+                if bytes[i] != separator {
+                    return None;
+                }
+            } else {
+                let nibble = match hex_digit(bytes[i]) {
+                    Some(nibble) => nibble,
+                    None => return None,
+                };
+                octets[nibbles / 2] = octets[nibbles / 2] << 4 | nibble;
+                nibbles += 1;
+            }
+            i += 1;
+        }
+        Some(Self(octets))
+    }
+
+    /// Random address with the locally administered bit set and the
+    /// multicast bit cleared, the kind used for privacy rotation.
+    pub fn random_local_unicast() -> crate::Result<Self> {
+        Self::random_local_unicast_from(&mut OsRandom)
+    }
+
+    pub fn random_local_unicast_from(random: &mut impl RandomSource) -> crate::Result<Self> {
+        let mut octets = [0; OCTETS_SIZE];
+        random.fill(&mut octets)?;
+        Ok(Self(octets)
+            .with_locally_administered(true)
+            .with_multicast(false))
+    }
+
+    /// Random address within a vendor OUI. The OUI is kept as given,
+    /// except for the multicast bit, which is cleared.
+    pub fn random_with_oui(oui: &[u8; 3]) -> crate::Result<Self> {
+        Self::random_with_oui_from(oui, &mut OsRandom)
+    }
+
+    pub fn random_with_oui_from(
+        oui: &[u8; 3],
+        random: &mut impl RandomSource,
+    ) -> crate::Result<Self> {
+        let mut octets = [0; OCTETS_SIZE];
+        octets[..3].copy_from_slice(oui);
+        random.fill(&mut octets[3..])?;
+        Ok(Self(octets).with_multicast(false))
+    }
+
+    /// Group address, which covers broadcast too.
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & MULTICAST_BIT != 0
+    }
+
+    pub fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    pub fn is_broadcast(&self) -> bool {
+        self.0 == BROADCAST
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == ZERO
+    }
+
+    pub fn is_locally_administered(&self) -> bool {
+        self.0[0] & LOCAL_BIT != 0
+    }
+
+    /// Address assigned by the vendor out of its OUI.
+    pub fn is_universal(&self) -> bool {
+        !self.is_locally_administered()
+    }
+
+    pub fn with_multicast(self, multicast: bool) -> Self {
+        self.with_bit(MULTICAST_BIT, multicast)
+    }
+
+    pub fn with_locally_administered(self, local: bool) -> Self {
+        self.with_bit(LOCAL_BIT, local)
+    }
+
+    fn with_bit(mut self, bit: u8, set: bool) -> Self {
+        match set {
+            true => self.0[0] |= bit,
+            false => self.0[0] &= !bit,
+        }
+        self
+    }
+
+    /// Rejects the addresses no interface may carry, before the kernel
+    /// turns them into a bare `EINVAL`.
+    pub(crate) fn check_assignable(&self) -> crate::Result<()> {
+        let reason = match self {
+            lladdr if lladdr.is_zero() => "zero",
+            lladdr if lladdr.is_broadcast() => "broadcast",
+            lladdr if lladdr.is_multicast() => "multicast",
+            _ => return Ok(()),
+        };
+        let error = Error::Unassignable(*self, reason);
+        Err(crate::Error::new(
+            ErrorKind::InvalidAddress,
+            Operation::SetLinkLevelAddress,
+            error,
+        )
+        .with_lladdr(*self)
+        .into())
+    }
+
+    /// Rejects what drivers without a permanent address report instead,
+    /// usually all zeros, before `Nic::reset_lladdr` tries to set it.
+    pub(crate) fn check_permanent(&self, ifid: IfId) -> crate::Result<()> {
+        if self.check_assignable().is_ok() {
+            return Ok(());
+        }
+        let error = Error::NoPermanentAddress(ifid, *self);
+        Err(crate::Error::new(
+            ErrorKind::Unsupported,
+            Operation::GetPermanentLinkLevelAddress,
+            error,
+        )
+        .with_ifid(ifid)
+        .with_lladdr(*self)
+        .into())
+    }
+
+    /// Modified EUI-64 interface identifier, as used by SLAAC: `ff:fe` in
+    /// the middle and the universal/local bit flipped (RFC 4291, appendix A).
+    pub fn to_eui64(&self) -> [u8; 8] {
+        let [a, b, c, d, e, f] = self.0;
+        [a ^ LOCAL_BIT, b, c, 0xff, 0xfe, d, e, f]
+    }
+
+    /// The `fe80::/64` address SLAAC derives from this address when
+    /// privacy extensions and stable-privacy are off.
+    pub fn to_ipv6_link_local(&self) -> Ipv6Addr {
+        let mut octets = [0; 16];
+        octets[..2].copy_from_slice(&[0xfe, 0x80]);
+        octets[8..].copy_from_slice(&self.to_eui64());
+        Ipv6Addr::from(octets)
+    }
+
+    /// Recovers the address behind an EUI-64 derived link-local address.
+    pub fn from_ipv6_link_local(addr: &Ipv6Addr) -> crate::Result<Self> {
+        match addr.octets() {
+            [0xfe, 0x80, 0, 0, 0, 0, 0, 0, a, b, c, 0xff, 0xfe, d, e, f] => {
+                Ok(Self([a ^ LOCAL_BIT, b, c, d, e, f]))
+            }
+            _ => Err(Error::NotEui64LinkLocal(*addr).into()),
+        }
+    }
+
+    pub const fn to_be_bytes(&self) -> OctetsType {
+        self.0
+    }
+
+    pub const fn from_be_bytes(octets: OctetsType) -> Self {
+        Self(octets)
+    }
+
+    /// The address `offset` places further, or `None` past `ff:ff:ff:ff:ff:ff`.
+    pub fn checked_add(&self, offset: u64) -> Option<Self> {
+        match u64::from(*self).checked_add(offset) {
+            Some(value) if value <= MAX => Some(Self::from_u48(value)),
+            _ => None,
+        }
+    }
+
+    /// The address `offset` places back, or `None` before `00:00:00:00:00:00`.
+    pub fn checked_sub(&self, offset: u64) -> Option<Self> {
+        u64::from(*self).checked_sub(offset).map(Self::from_u48)
+    }
+
+    fn from_u48(value: u64) -> Self {
+        let mut octets = [0; OCTETS_SIZE];
+        octets.copy_from_slice(&value.to_be_bytes()[2..]);
+        Self(octets)
+    }
+
+    pub fn format(&self, format: MacFormat) -> String {
+        let octets = |separator: &str| {
+            self.iter()
+                .map(|octet| format!("{:02x}", octet))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        match format {
+            MacFormat::Colon => octets(":"),
+            MacFormat::Dash => octets("-"),
+            MacFormat::Dotted => self
+                .chunks(2)
+                .map(|pair| format!("{:02x}{:02x}", pair[0], pair[1]))
+                .collect::<Vec<_>>()
+                .join("."),
+            MacFormat::Bare => octets(""),
+            MacFormat::Upper => octets(":").to_uppercase(),
+        }
+    }
+}
+
 impl Deref for LinkLevelAddress {
     type Target = OctetsType;
 
@@ -65,10 +449,26 @@ impl Display for LinkLevelAddress {
 
 impl From<&LinkLevelAddress> for String {
     fn from(value: &LinkLevelAddress) -> Self {
-        format!(
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            value[0], value[1], value[2], value[3], value[4], value[5]
-        )
+        value.format(MacFormat::Colon)
+    }
+}
+
+impl From<LinkLevelAddress> for u64 {
+    fn from(value: LinkLevelAddress) -> Self {
+        value
+            .iter()
+            .fold(0, |value, octet| value << 8 | *octet as u64)
+    }
+}
+
+impl TryFrom<u64> for LinkLevelAddress {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            value if value <= MAX => Ok(Self::from_u48(value)),
+            _ => Err(Error::OutOfRange(value).into()),
+        }
     }
 }
 
@@ -78,56 +478,156 @@ impl From<&OctetsType> for LinkLevelAddress {
     }
 }
 
-struct OctetsVec(Vec<u8>);
+// Octets of `value`, in the layout `MacFormat::detect` finds
+fn parse_octets(value: &str) -> Result<OctetsType, ParseLLAddrError> {
+    use ParseLLAddrErrorKind::*;
+    let error = |kind, offset, token: &str| ParseLLAddrError::new(kind, value, offset, token);
 
-impl Deref for OctetsVec {
-    type Target = Vec<u8>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    if value.is_empty() {
+        return Err(error(Empty, 0, ""));
+    }
+    if let Some((offset, c)) = value.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(error(
+            InvalidDigit,
+            offset,
+            &value[offset..offset + c.len_utf8()],
+        ));
+    }
+    let (separator, digits) = match MacFormat::detect(value) {
+        MacFormat::Colon | MacFormat::Upper => (Some(':'), 1..=2),
+        MacFormat::Dash => (Some('-'), 1..=2),
+        MacFormat::Dotted => (Some('.'), 4..=4),
+        MacFormat::Bare => (None, 12..=12),
+    };
+
+    let mut octets = [0; OCTETS_SIZE];
+    let mut len = 0;
+    let mut offset = 0;
+    for group in value.split(|c| Some(c) == separator) {
+        if len == OCTETS_SIZE {
+            return Err(error(WrongNumberOfOctets, offset, group));
+        }
+        if !digits.contains(&group.len()) {
+            return Err(error(InvalidOctetLength, offset, group));
+        }
+        for start in (0..group.len()).step_by(2) {
+            let pair = &group[start..group.len().min(start + 2)];
+            octets[len] = pair
+                .bytes()
+                .try_fold(0, |octet, c| Some(octet << 4 | hex_digit(c)?))
+                .ok_or_else(|| error(InvalidDigit, offset + start, pair))?;
+            len += 1;
+        }
+        offset += group.len() + 1;
     }
+    if len < OCTETS_SIZE {
+        return Err(error(WrongNumberOfOctets, value.len(), ""));
+    }
+    Ok(octets)
 }
 
-impl TryFrom<&str> for OctetsVec {
-    type Error = Error;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let octets = value
-            .splitn(OCTETS_SIZE, ':')
-            .map(|octet| {
-                u8::from_str_radix(octet, 16).map_err(|error| {
-                    Error::InvalidOctet(value.to_string(), octet.to_string(), error.to_string())
-                })
-            })
-            .collect::<Result<Vec<u8>, Error>>()?;
-        Ok(Self(octets))
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
     }
 }
 
 impl FromStr for LinkLevelAddress {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseLLAddrError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let octets = OctetsVec::try_from(value)?;
+        parse_octets(value).map(Self)
+    }
+}
+
+// Strings for human-readable formats, raw octets for the others.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LinkLevelAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => serializer.serialize_bytes(&self.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LinkLevelAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = LinkLevelAddress;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a link level address")
+            }
 
-        if octets.len() != OCTETS_SIZE {
-            return Err(Error::WrongNumberOfOctets(value.to_string(), octets.len()).into());
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+                match OctetsType::try_from(value) {
+                    Ok(octets) => Ok(LinkLevelAddress(octets)),
+                    Err(_) => Err(E::custom(Error::WrongNumberOfOctets(
+                        format!("{:02x?}", value),
+                        value.len(),
+                    ))),
+                }
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut octets = Vec::with_capacity(OCTETS_SIZE);
+                while let Some(octet) = seq.next_element()? {
+                    octets.push(octet);
+                }
+                self.visit_bytes(&octets)
+            }
         }
 
-        let mut lladdr: OctetsType = unsafe { std::mem::zeroed() };
-        lladdr.copy_from_slice(&octets);
-        Ok(Self::from(&lladdr))
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_str(Visitor),
+            false => deserializer.deserialize_bytes(Visitor),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{FromStr, LinkLevelAddress, OctetsType};
+    use super::{
+        FromStr, LinkLevelAddress, MacFormat, OctetsType, OsRandom, ParseLLAddrErrorKind,
+        RandomSource,
+    };
     use std::collections::HashMap;
+    use std::net::Ipv6Addr;
 
     const LLADDR_SIZE: usize = 6;
     const OCTETS: OctetsType = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
 
+    struct FixedRandom(u8);
+
+    impl RandomSource for FixedRandom {
+        fn fill(&mut self, bytes: &mut [u8]) -> crate::Result<()> {
+            bytes.fill(self.0);
+            Ok(())
+        }
+    }
+
+    struct FailingRandom;
+
+    impl RandomSource for FailingRandom {
+        fn fill(&mut self, _: &mut [u8]) -> crate::Result<()> {
+            Err("RandomError".into())
+        }
+    }
+
     #[test]
     fn test_link_level_address_len() {
         let addr = LinkLevelAddress(OCTETS);
@@ -160,6 +660,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_link_level_address_clone() {
         let addr = LinkLevelAddress(OCTETS);
 
@@ -257,7 +758,7 @@ mod tests {
     #[test]
     fn test_link_level_address_from_str_length_too_small() {
         let source = "01:02:03";
-        let expected_error = "LinkLevelAddress::WrongNumberOfOctetsError { value: \"01:02:03\", value_octets: 3, expected_octets: 6 }";
+        let expected_error = "ParseLLAddrError { kind: WrongNumberOfOctets, value: \"01:02:03\", offset: 8, token: \"\" }";
 
         let error = LinkLevelAddress::from_str(source).unwrap_err();
 
@@ -268,7 +769,7 @@ mod tests {
     #[test]
     fn test_link_level_address_from_str_length_too_large() {
         let source = "01:02:03:04:05:06:07";
-        let expected_error = "LinkLevelAddress::InvalidOctetError { value: \"01:02:03:04:05:06:07\", octet: \"06:07\", error: \"invalid digit found in string\" }";
+        let expected_error = "ParseLLAddrError { kind: WrongNumberOfOctets, value: \"01:02:03:04:05:06:07\", offset: 18, token: \"07\" }";
 
         let error = LinkLevelAddress::from_str(source).unwrap_err();
 
@@ -279,7 +780,8 @@ mod tests {
     #[test]
     fn test_link_level_address_from_str_empty() {
         let source = "";
-        let expected_error = "LinkLevelAddress::InvalidOctetError { value: \"\", octet: \"\", error: \"cannot parse integer from empty string\" }";
+        let expected_error =
+            "ParseLLAddrError { kind: Empty, value: \"\", offset: 0, token: \"\" }";
 
         let error = LinkLevelAddress::from_str(source).unwrap_err();
 
@@ -290,7 +792,7 @@ mod tests {
     #[test]
     fn test_link_level_address_from_str_number_too_large() {
         let source = "01:02:300";
-        let expected_error = "LinkLevelAddress::InvalidOctetError { value: \"01:02:300\", octet: \"300\", error: \"number too large to fit in target type\" }";
+        let expected_error = "ParseLLAddrError { kind: InvalidOctetLength, value: \"01:02:300\", offset: 6, token: \"300\" }";
 
         let error = LinkLevelAddress::from_str(source).unwrap_err();
 
@@ -301,11 +803,454 @@ mod tests {
     #[test]
     fn test_link_level_address_from_str_invalid_digit() {
         let source = "01:02:XX:04:05:06";
-        let expected_error = "LinkLevelAddress::InvalidOctetError { value: \"01:02:XX:04:05:06\", octet: \"XX\", error: \"invalid digit found in string\" }";
+        let expected_error = "ParseLLAddrError { kind: InvalidDigit, value: \"01:02:XX:04:05:06\", offset: 6, token: \"XX\" }";
 
         let error = LinkLevelAddress::from_str(source).unwrap_err();
 
         assert_eq!(format!("{}", error), expected_error);
         assert_eq!(format!("{:?}", error), expected_error);
     }
+
+    #[test]
+    fn test_link_level_address_from_str_error_fields() {
+        let error = LinkLevelAddress::from_str("0011.22zz.bbcc").unwrap_err();
+
+        assert_eq!(error.kind(), ParseLLAddrErrorKind::InvalidDigit);
+        assert_eq!(error.value(), "0011.22zz.bbcc");
+        assert_eq!(error.offset(), 7);
+        assert_eq!(error.token(), "zz");
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_empty_octet() {
+        let error = LinkLevelAddress::from_str("01:02::04:05:06").unwrap_err();
+
+        assert_eq!(error.kind(), ParseLLAddrErrorKind::InvalidOctetLength);
+        assert_eq!(error.offset(), 6);
+        assert_eq!(error.token(), "");
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_sign() {
+        let error = LinkLevelAddress::from_str("+1:02:03:04:05:06").unwrap_err();
+
+        assert_eq!(error.kind(), ParseLLAddrErrorKind::InvalidDigit);
+        assert_eq!(error.offset(), 0);
+        assert_eq!(error.token(), "+1");
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_non_ascii() {
+        let error = LinkLevelAddress::from_str("01:02:0é:04:05:06").unwrap_err();
+
+        assert_eq!(error.kind(), ParseLLAddrErrorKind::InvalidDigit);
+        assert_eq!(error.offset(), 7);
+        assert_eq!(error.token(), "é");
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_single_digit_octets() {
+        let addr = LinkLevelAddress::from_str("1:2:3:4:5:6").unwrap();
+
+        assert_eq!(addr, LinkLevelAddress(OCTETS));
+    }
+
+    #[test]
+    fn test_link_level_address_format() {
+        let addr = LinkLevelAddress([0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc]);
+
+        assert_eq!(addr.format(MacFormat::Colon), "00:11:22:aa:bb:cc");
+        assert_eq!(addr.format(MacFormat::Dash), "00-11-22-aa-bb-cc");
+        assert_eq!(addr.format(MacFormat::Dotted), "0011.22aa.bbcc");
+        assert_eq!(addr.format(MacFormat::Bare), "001122aabbcc");
+        assert_eq!(addr.format(MacFormat::Upper), "00:11:22:AA:BB:CC");
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_formats() {
+        let expected = LinkLevelAddress([0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc]);
+
+        for source in [
+            "00:11:22:aa:bb:cc",
+            "00-11-22-AA-BB-CC",
+            "0011.22aa.bbcc",
+            "001122AABBCC",
+            "00:11:22:AA:BB:CC",
+        ] {
+            assert_eq!(LinkLevelAddress::from_str(source).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_format_round_trip() {
+        let addr = LinkLevelAddress(OCTETS);
+
+        for format in [
+            MacFormat::Colon,
+            MacFormat::Dash,
+            MacFormat::Dotted,
+            MacFormat::Bare,
+            MacFormat::Upper,
+        ] {
+            assert_eq!(
+                addr.format(format).parse::<LinkLevelAddress>().unwrap(),
+                addr
+            );
+        }
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_dash_invalid_digit() {
+        let source = "01-02-XX-04-05-06";
+        let expected_error = "ParseLLAddrError { kind: InvalidDigit, value: \"01-02-XX-04-05-06\", offset: 6, token: \"XX\" }";
+
+        let error = LinkLevelAddress::from_str(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_dotted_short_group() {
+        let source = "011.2233.4455";
+        let expected_error = "ParseLLAddrError { kind: InvalidOctetLength, value: \"011.2233.4455\", offset: 0, token: \"011\" }";
+
+        let error = LinkLevelAddress::from_str(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_dotted_wrong_number_of_groups() {
+        let source = "0011.2233";
+        let expected_error = "ParseLLAddrError { kind: WrongNumberOfOctets, value: \"0011.2233\", offset: 9, token: \"\" }";
+
+        let error = LinkLevelAddress::from_str(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+    }
+
+    #[test]
+    fn test_link_level_address_from_str_bare_invalid_digit() {
+        let source = "0011223344zz";
+        let expected_error = "ParseLLAddrError { kind: InvalidDigit, value: \"0011223344zz\", offset: 10, token: \"zz\" }";
+
+        let error = LinkLevelAddress::from_str(source).unwrap_err();
+
+        assert_eq!(format!("{}", error), expected_error);
+    }
+
+    #[test]
+    fn test_link_level_address_random_local_unicast() {
+        let addr = LinkLevelAddress::random_local_unicast_from(&mut FixedRandom(0xff)).unwrap();
+
+        assert_eq!(addr, LinkLevelAddress([0xfe, 0xff, 0xff, 0xff, 0xff, 0xff]));
+    }
+
+    #[test]
+    fn test_link_level_address_random_local_unicast_sets_local_bit() {
+        let addr = LinkLevelAddress::random_local_unicast_from(&mut FixedRandom(0x00)).unwrap();
+
+        assert_eq!(addr, LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0x00]));
+    }
+
+    #[test]
+    fn test_link_level_address_random_local_unicast_os_random() {
+        let addr = LinkLevelAddress::random_local_unicast().unwrap();
+
+        assert_eq!(addr[0] & 0x03, 0x02);
+    }
+
+    #[test]
+    fn test_link_level_address_random_with_oui() {
+        let addr =
+            LinkLevelAddress::random_with_oui_from(&[0x00, 0x11, 0x22], &mut FixedRandom(0xab))
+                .unwrap();
+
+        assert_eq!(addr, LinkLevelAddress([0x00, 0x11, 0x22, 0xab, 0xab, 0xab]));
+    }
+
+    #[test]
+    fn test_link_level_address_random_with_oui_clears_multicast_bit() {
+        let addr =
+            LinkLevelAddress::random_with_oui_from(&[0x01, 0x00, 0x5e], &mut FixedRandom(0x00))
+                .unwrap();
+
+        assert_eq!(addr, LinkLevelAddress([0x00, 0x00, 0x5e, 0x00, 0x00, 0x00]));
+    }
+
+    #[test]
+    fn test_link_level_address_random_error() {
+        let error = LinkLevelAddress::random_local_unicast_from(&mut FailingRandom).unwrap_err();
+
+        assert_eq!(format!("{}", error), "RandomError");
+    }
+
+    #[test]
+    fn test_os_random_fill() {
+        let mut bytes = [0; 32];
+
+        OsRandom.fill(&mut bytes).unwrap();
+
+        assert_ne!(bytes, [0; 32]);
+    }
+
+    #[test]
+    fn test_link_level_address_predicates() {
+        let addr = LinkLevelAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        assert!(addr.is_unicast());
+        assert!(!addr.is_multicast());
+        assert!(!addr.is_broadcast());
+        assert!(!addr.is_zero());
+        assert!(addr.is_universal());
+        assert!(!addr.is_locally_administered());
+    }
+
+    #[test]
+    fn test_link_level_address_predicates_special() {
+        let broadcast = LinkLevelAddress([0xff; 6]);
+        let zero = LinkLevelAddress([0x00; 6]);
+        let local = LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]);
+
+        assert!(broadcast.is_broadcast() && broadcast.is_multicast());
+        assert!(zero.is_zero() && zero.is_unicast());
+        assert!(local.is_locally_administered() && !local.is_universal());
+    }
+
+    #[test]
+    fn test_link_level_address_with_bits() {
+        let addr = LinkLevelAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let changed = addr.with_multicast(true).with_locally_administered(true);
+
+        assert_eq!(changed[0], 0x03);
+        assert_eq!(
+            changed
+                .with_multicast(false)
+                .with_locally_administered(false),
+            addr
+        );
+    }
+
+    #[test]
+    fn test_link_level_address_check_assignable() {
+        let addr = LinkLevelAddress([0x02, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        assert!(addr.check_assignable().is_ok());
+    }
+
+    #[test]
+    fn test_link_level_address_check_assignable_error() {
+        for (octets, expected_error) in [
+            ([0x00; 6], "LinkLevelAddress::UnassignableError { lladdr: \"00:00:00:00:00:00\", reason: \"zero\" }"),
+            ([0xff; 6], "LinkLevelAddress::UnassignableError { lladdr: \"ff:ff:ff:ff:ff:ff\", reason: \"broadcast\" }"),
+            ([0x01, 0x00, 0x5e, 0x00, 0x00, 0x01], "LinkLevelAddress::UnassignableError { lladdr: \"01:00:5e:00:00:01\", reason: \"multicast\" }"),
+        ] {
+            let error = LinkLevelAddress(octets).check_assignable().unwrap_err();
+
+            assert_eq!(format!("{}", error.source().unwrap()), expected_error);
+        }
+    }
+
+    #[test]
+    fn test_link_level_address_to_eui64() {
+        let addr = LinkLevelAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let eui64 = addr.to_eui64();
+
+        assert_eq!(eui64, [0x02, 0x11, 0x22, 0xff, 0xfe, 0x33, 0x44, 0x55]);
+    }
+
+    #[test]
+    fn test_link_level_address_to_ipv6_link_local() {
+        let addr = LinkLevelAddress([0x02, 0xfc, 0x00, 0x00, 0x00, 0x01]);
+
+        let link_local = addr.to_ipv6_link_local();
+
+        assert_eq!(
+            link_local,
+            "fe80::fc:ff:fe00:1".parse::<Ipv6Addr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_link_level_address_from_ipv6_link_local() {
+        let link_local = "fe80::211:22ff:fe33:4455".parse().unwrap();
+
+        let addr = LinkLevelAddress::from_ipv6_link_local(&link_local).unwrap();
+
+        assert_eq!(addr, LinkLevelAddress([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]));
+        assert_eq!(addr.to_ipv6_link_local(), link_local);
+    }
+
+    #[test]
+    fn test_link_level_address_from_ipv6_link_local_error() {
+        for source in ["fe80::1", "2001:db8::211:22ff:fe33:4455"] {
+            let link_local = source.parse().unwrap();
+            let expected_error = format!(
+                "LinkLevelAddress::NotEui64LinkLocalError {{ addr: {} }}",
+                source
+            );
+
+            let error = LinkLevelAddress::from_ipv6_link_local(&link_local).unwrap_err();
+
+            assert_eq!(format!("{}", error), expected_error);
+        }
+    }
+
+    #[test]
+    fn test_link_level_address_parse_const() {
+        let expected = LinkLevelAddress([0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc]);
+
+        for source in [
+            "00:11:22:aa:bb:cc",
+            "00-11-22-AA-BB-CC",
+            "0011.22aa.bbcc",
+            "001122AABBCC",
+        ] {
+            assert_eq!(LinkLevelAddress::parse_const(source), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_link_level_address_parse_const_invalid() {
+        for source in [
+            "",
+            "00:11:22:aa:bb",
+            "00:11-22:aa:bb:cc",
+            "00.11.22.aa.bb.cc",
+            "0011:22aa:bbcc",
+            "00:11:22:aa:bb:zz",
+            "0:1:2:a:b:c",
+        ] {
+            assert_eq!(LinkLevelAddress::parse_const(source), None, "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_lladdr_macro() {
+        const LLADDR: LinkLevelAddress = crate::lladdr!("01:02:03:04:05:06");
+
+        assert_eq!(LLADDR, LinkLevelAddress(OCTETS));
+    }
+
+    #[test]
+    fn test_link_level_address_ord() {
+        let low = LinkLevelAddress([0x00, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        let high = LinkLevelAddress([0x01, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        assert!(low < high);
+        assert_eq!(low.max(high), high);
+    }
+
+    #[test]
+    fn test_link_level_address_u64() {
+        let addr = LinkLevelAddress(OCTETS);
+
+        let value = u64::from(addr);
+
+        assert_eq!(value, 0x0102_0304_0506);
+        assert_eq!(LinkLevelAddress::try_from(value).unwrap(), addr);
+    }
+
+    #[test]
+    fn test_link_level_address_try_from_u64_out_of_range() {
+        let error = LinkLevelAddress::try_from(1u64 << 48).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "LinkLevelAddress::OutOfRangeError { value: 0x1000000000000, max: 0xffffffffffff }"
+        );
+    }
+
+    #[test]
+    fn test_link_level_address_be_bytes() {
+        let addr = LinkLevelAddress::from_be_bytes(OCTETS);
+
+        assert_eq!(addr.to_be_bytes(), OCTETS);
+    }
+
+    #[test]
+    fn test_link_level_address_checked_add() {
+        let addr = LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0xff]);
+
+        assert_eq!(
+            addr.checked_add(1),
+            Some(LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x01, 0x00]))
+        );
+        assert_eq!(LinkLevelAddress([0xff; 6]).checked_add(1), None);
+        assert_eq!(addr.checked_add(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_link_level_address_checked_sub() {
+        let addr = LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x01, 0x00]);
+
+        assert_eq!(
+            addr.checked_sub(1),
+            Some(LinkLevelAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0xff]))
+        );
+        assert_eq!(LinkLevelAddress([0x00; 6]).checked_sub(1), None);
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::LinkLevelAddress;
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token,
+        };
+
+        const LLADDR: LinkLevelAddress = LinkLevelAddress([0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc]);
+
+        #[test]
+        fn test_link_level_address_serde_readable() {
+            assert_tokens(&LLADDR.readable(), &[Token::Str("00:11:22:aa:bb:cc")]);
+        }
+
+        #[test]
+        fn test_link_level_address_serde_readable_any_format() {
+            assert_de_tokens(&LLADDR.readable(), &[Token::Str("0011.22AA.BBCC")]);
+        }
+
+        #[test]
+        fn test_link_level_address_serde_readable_error() {
+            assert_de_tokens_error::<serde_test::Readable<LinkLevelAddress>>(
+                &[Token::Str("01:02:XX:04:05:06")],
+                "ParseLLAddrError { kind: InvalidDigit, value: \"01:02:XX:04:05:06\", offset: 6, token: \"XX\" }",
+            );
+        }
+
+        #[test]
+        fn test_link_level_address_serde_compact() {
+            assert_tokens(
+                &LLADDR.compact(),
+                &[Token::Bytes(&[0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc])],
+            );
+        }
+
+        #[test]
+        fn test_link_level_address_serde_compact_seq() {
+            assert_de_tokens(
+                &LLADDR.compact(),
+                &[
+                    Token::Seq { len: Some(6) },
+                    Token::U8(0x00),
+                    Token::U8(0x11),
+                    Token::U8(0x22),
+                    Token::U8(0xaa),
+                    Token::U8(0xbb),
+                    Token::U8(0xcc),
+                    Token::SeqEnd,
+                ],
+            );
+        }
+
+        #[test]
+        fn test_link_level_address_serde_compact_error() {
+            assert_de_tokens_error::<serde_test::Compact<LinkLevelAddress>>(
+                &[Token::Bytes(&[0x00, 0x11, 0x22])],
+                "LinkLevelAddress::WrongNumberOfOctetsError { value: \"[00, 11, 22]\", value_octets: 3, expected_octets: 6 }",
+            );
+        }
+    }
 }
@@ -0,0 +1,290 @@
+use crate::{LinkLevelAddress, Result};
+use core::fmt::{Debug, Display};
+use std::str::FromStr;
+
+// Bits in a link level address
+const BITS: u8 = 48;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    MissingLen(String),
+    InvalidLen(String, String),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingLen(value) => f
+                .debug_struct("MacPrefix::MissingLenError")
+                .field("value", value)
+                .finish(),
+            Self::InvalidLen(value, len) => f
+                .debug_struct("MacPrefix::InvalidLenError")
+                .field("value", value)
+                .field("len", len)
+                .field("max", &BITS)
+                .finish(),
+        }
+    }
+}
+
+/// Block of addresses sharing their first `len` bits, written like
+/// `02:00:00:00:00:00/24`.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MacPrefix {
+    network: LinkLevelAddress,
+    len: u8,
+}
+
+impl MacPrefix {
+    /// Host bits of `lladdr` are cleared, so any address of the block will do.
+    pub fn new(lladdr: LinkLevelAddress, len: u8) -> Result<Self> {
+        if len > BITS {
+            return Err(Error::InvalidLen(lladdr.to_string(), len.to_string()).into());
+        }
+        let network = u64::from(lladdr) & !host_mask(len);
+        Ok(MacPrefix {
+            network: LinkLevelAddress::try_from(network)?,
+            len,
+        })
+    }
+
+    pub fn network(&self) -> LinkLevelAddress {
+        self.network
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.len
+    }
+
+    /// Number of addresses in the block.
+    pub fn size(&self) -> u64 {
+        host_mask(self.len) + 1
+    }
+
+    pub fn first(&self) -> LinkLevelAddress {
+        self.network
+    }
+
+    pub fn last(&self) -> LinkLevelAddress {
+        let last = u64::from(self.network) | host_mask(self.len);
+        LinkLevelAddress::try_from(last).expect("48 bit address")
+    }
+
+    pub fn contains(&self, lladdr: &LinkLevelAddress) -> bool {
+        u64::from(*lladdr) & !host_mask(self.len) == u64::from(self.network)
+    }
+
+    pub fn iter(&self) -> MacRange {
+        MacRange::new(self.first(), self.last())
+    }
+}
+
+impl IntoIterator for &MacPrefix {
+    type Item = LinkLevelAddress;
+    type IntoIter = MacRange;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Debug for MacPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
+impl Display for MacPrefix {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}/{}", self.network, self.len)
+    }
+}
+
+impl FromStr for MacPrefix {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (lladdr, len) = value
+            .rsplit_once('/')
+            .ok_or_else(|| Error::MissingLen(value.to_string()))?;
+        let len = len
+            .parse()
+            .map_err(|_| Error::InvalidLen(value.to_string(), len.to_string()))?;
+        Self::new(lladdr.parse()?, len)
+    }
+}
+
+/// Iterator over the addresses from `start` to `end`, both included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MacRange {
+    next: Option<LinkLevelAddress>,
+    end: LinkLevelAddress,
+}
+
+impl MacRange {
+    /// Empty when `start` comes after `end`.
+    pub fn new(start: LinkLevelAddress, end: LinkLevelAddress) -> Self {
+        MacRange {
+            next: Some(start).filter(|start| *start <= end),
+            end,
+        }
+    }
+}
+
+impl Iterator for MacRange {
+    type Item = LinkLevelAddress;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.checked_add(1).filter(|next| *next <= self.end);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self
+            .next
+            .map(|next| u64::from(self.end) - u64::from(next) + 1)
+            .unwrap_or(0);
+        match usize::try_from(len) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+fn host_mask(len: u8) -> u64 {
+    (1 << (BITS - len)) - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MacPrefix, MacRange};
+    use crate::{lladdr, LinkLevelAddress};
+
+    #[test]
+    fn test_mac_prefix_from_str() {
+        let prefix: MacPrefix = "02:00:00:00:00:00/24".parse().unwrap();
+
+        assert_eq!(prefix.network(), lladdr!("02:00:00:00:00:00"));
+        assert_eq!(prefix.prefix_len(), 24);
+        assert_eq!(prefix.size(), 1 << 24);
+        assert_eq!(prefix.first(), lladdr!("02:00:00:00:00:00"));
+        assert_eq!(prefix.last(), lladdr!("02:00:00:ff:ff:ff"));
+        assert_eq!(format!("{}", prefix), "02:00:00:00:00:00/24");
+        assert_eq!(format!("{:?}", prefix), "\"02:00:00:00:00:00/24\"");
+    }
+
+    #[test]
+    fn test_mac_prefix_new_clears_host_bits() {
+        let prefix = MacPrefix::new(lladdr!("02:00:00:12:34:56"), 28).unwrap();
+
+        assert_eq!(prefix.network(), lladdr!("02:00:00:10:00:00"));
+    }
+
+    #[test]
+    fn test_mac_prefix_bounds() {
+        let all = MacPrefix::new(lladdr!("12:34:56:78:9a:bc"), 0).unwrap();
+        let one = MacPrefix::new(lladdr!("12:34:56:78:9a:bc"), 48).unwrap();
+
+        assert_eq!(all.last(), lladdr!("ff:ff:ff:ff:ff:ff"));
+        assert_eq!(all.size(), 1 << 48);
+        assert_eq!(
+            one.iter().collect::<Vec<_>>(),
+            vec![lladdr!("12:34:56:78:9a:bc")]
+        );
+    }
+
+    #[test]
+    fn test_mac_prefix_contains() {
+        let prefix: MacPrefix = "02:00:00:00:00:00/24".parse().unwrap();
+
+        assert!(prefix.contains(&lladdr!("02:00:00:ab:cd:ef")));
+        assert!(!prefix.contains(&lladdr!("02:00:01:00:00:00")));
+    }
+
+    #[test]
+    fn test_mac_prefix_iter() {
+        let prefix: MacPrefix = "02:00:00:00:00:00/46".parse().unwrap();
+
+        let lladdrs: Vec<LinkLevelAddress> = prefix.iter().collect();
+
+        assert_eq!(
+            lladdrs,
+            vec![
+                lladdr!("02:00:00:00:00:00"),
+                lladdr!("02:00:00:00:00:01"),
+                lladdr!("02:00:00:00:00:02"),
+                lladdr!("02:00:00:00:00:03"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mac_prefix_from_str_missing_len() {
+        let error = "02:00:00:00:00:00".parse::<MacPrefix>().unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "MacPrefix::MissingLenError { value: \"02:00:00:00:00:00\" }"
+        );
+    }
+
+    #[test]
+    fn test_mac_prefix_from_str_invalid_len() {
+        let error = "02:00:00:00:00:00/49".parse::<MacPrefix>().unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "MacPrefix::InvalidLenError { value: \"02:00:00:00:00:00\", len: \"49\", max: 48 }"
+        );
+    }
+
+    #[test]
+    fn test_mac_prefix_from_str_invalid_lladdr() {
+        let error = "02:00:00:zz:00:00/24".parse::<MacPrefix>().unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "ParseLLAddrError { kind: InvalidDigit, value: \"02:00:00:zz:00:00\", offset: 9, token: \"zz\" }"
+        );
+    }
+
+    #[test]
+    fn test_mac_range() {
+        let range = MacRange::new(lladdr!("02:00:00:00:00:fe"), lladdr!("02:00:00:00:01:00"));
+
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(
+            range.collect::<Vec<_>>(),
+            vec![
+                lladdr!("02:00:00:00:00:fe"),
+                lladdr!("02:00:00:00:00:ff"),
+                lladdr!("02:00:00:00:01:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mac_range_empty() {
+        let mut range = MacRange::new(lladdr!("02:00:00:00:00:01"), lladdr!("02:00:00:00:00:00"));
+
+        assert_eq!(range.size_hint(), (0, Some(0)));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn test_mac_range_ends_at_broadcast() {
+        let range = MacRange::new(lladdr!("ff:ff:ff:ff:ff:fe"), lladdr!("ff:ff:ff:ff:ff:ff"));
+
+        assert_eq!(range.count(), 2);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{LinkLevelAddress, Result};
+use core::fmt::{Debug, Display};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
+
+// Same layout as the IEEE oui.csv, mam.csv and oui36.csv files, with the
+// address column left out. Regenerate with `make oui`, which runs
+// `scripts/gen_oui.py` against standards-oui.ieee.org.
+const EMBEDDED: &str = include_str!("oui.csv");
+
+// Longest prefix first: MA-S, MA-M, then MA-L
+const PREFIX_BITS: [u32; 3] = [36, 28, 24];
+
+static REGISTRY: LazyLock<OuiRegistry> =
+    LazyLock::new(|| OuiRegistry::from_csv(EMBEDDED).expect("embedded OUI registry"));
+
+static INSTALLED: OnceLock<OuiRegistry> = OnceLock::new();
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    Read(PathBuf, String),
+    Parse(usize, String),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(path, error) => f
+                .debug_struct("OuiRegistry::ReadError")
+                .field("path", path)
+                .field("error", error)
+                .finish(),
+            Self::Parse(line, value) => f
+                .debug_struct("OuiRegistry::ParseError")
+                .field("line", line)
+                .field("value", value)
+                .finish(),
+        }
+    }
+}
+
+/// Vendor names by IEEE assignment.
+///
+/// The embedded registry only covers a handful of common vendors. Load the
+/// IEEE CSV files with [`OuiRegistry::load`] for the full picture, and
+/// [`OuiRegistry::install`] the result for [`LinkLevelAddress::vendor`] to
+/// use it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OuiRegistry {
+    vendors: HashMap<(u32, u64), String>,
+}
+
+impl OuiRegistry {
+    pub fn embedded() -> &'static OuiRegistry {
+        &REGISTRY
+    }
+
+    /// Makes this registry the one [`LinkLevelAddress::vendor`] looks up,
+    /// in place of the embedded one. Only the first call takes effect, the
+    /// registry is handed back otherwise.
+    pub fn install(self) -> std::result::Result<(), OuiRegistry> {
+        INSTALLED.set(self)
+    }
+
+    /// The installed registry, or the embedded one when none is.
+    pub fn current() -> &'static OuiRegistry {
+        INSTALLED.get().unwrap_or(&REGISTRY)
+    }
+
+    /// Reads a registry CSV as published by the IEEE.
+    pub fn load(path: impl AsRef<Path>) -> Result<OuiRegistry> {
+        let path = path.as_ref();
+        let csv = std::fs::read_to_string(path)
+            .map_err(|error| Error::Read(path.to_path_buf(), error.to_string()))?;
+        Self::from_csv(&csv)
+    }
+
+    pub fn from_csv(csv: &str) -> Result<OuiRegistry> {
+        let mut registry = OuiRegistry::default();
+        for (index, line) in csv.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match split_fields(line).as_slice() {
+                [registry, ..] if registry == "Registry" => continue,
+                [kind, assignment, vendor, ..] => {
+                    let parse_error = || Error::Parse(index + 1, line.to_string());
+                    let bits = match kind.as_str() {
+                        "MA-L" => 24,
+                        "MA-M" => 28,
+                        "MA-S" | "IAB" => 36,
+                        _ => return Err(parse_error().into()),
+                    };
+                    if assignment.len() as u32 * 4 != bits {
+                        return Err(parse_error().into());
+                    }
+                    let prefix = u64::from_str_radix(assignment, 16).map_err(|_| parse_error())?;
+                    registry.vendors.insert((bits, prefix), vendor.clone());
+                }
+                _ => return Err(Error::Parse(index + 1, line.to_string()).into()),
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Adds the entries of `other`, replacing those already known.
+    pub fn extend(&mut self, other: OuiRegistry) {
+        self.vendors.extend(other.vendors);
+    }
+
+    pub fn len(&self) -> usize {
+        self.vendors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vendors.is_empty()
+    }
+
+    /// Looks up the longest registered prefix of `lladdr`.
+    pub fn vendor(&self, lladdr: &LinkLevelAddress) -> Option<&str> {
+        let value = lladdr
+            .iter()
+            .fold(0u64, |value, octet| value << 8 | *octet as u64);
+        PREFIX_BITS
+            .iter()
+            .find_map(|bits| self.vendors.get(&(*bits, value >> (48 - bits))))
+            .map(String::as_str)
+    }
+}
+
+impl LinkLevelAddress {
+    /// Vendor of the address according to [`OuiRegistry::current`].
+    /// Locally administered addresses have none.
+    pub fn vendor(&self) -> Option<&'static str> {
+        self.vendor_in(OuiRegistry::current())
+    }
+
+    /// Same as [`LinkLevelAddress::vendor`], looked up in `registry`.
+    pub fn vendor_in<'a>(&self, registry: &'a OuiRegistry) -> Option<&'a str> {
+        match self.is_locally_administered() {
+            true => None,
+            false => registry.vendor(self),
+        }
+    }
+}
+
+// Splits a CSV line on commas, honouring double quotes and "" escapes.
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(String::new()),
+            (c, _) => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OuiRegistry;
+    use crate::LinkLevelAddress;
+
+    const CSV: &str = "\
+Registry,Assignment,Organization Name,Organization Address
+MA-L,001122,\"Acme, Inc.\",1 Road Town US 00000
+MA-M,0011223,Acme Medium,
+MA-S,001122334,\"Acme \"\"Small\"\"\",
+";
+
+    fn lladdr(value: &str) -> LinkLevelAddress {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_oui_registry_embedded() {
+        let registry = OuiRegistry::embedded();
+
+        assert!(!registry.is_empty());
+        assert_eq!(
+            registry.vendor(&lladdr("00:50:56:12:34:56")),
+            Some("VMware, Inc.")
+        );
+    }
+
+    #[test]
+    fn test_oui_registry_from_csv() {
+        let registry = OuiRegistry::from_csv(CSV).unwrap();
+
+        assert_eq!(registry.len(), 3);
+        assert_eq!(
+            registry.vendor(&lladdr("00:11:22:ff:ff:ff")),
+            Some("Acme, Inc.")
+        );
+        assert_eq!(
+            registry.vendor(&lladdr("00:11:22:3f:ff:ff")),
+            Some("Acme Medium")
+        );
+        assert_eq!(
+            registry.vendor(&lladdr("00:11:22:33:4f:ff")),
+            Some("Acme \"Small\"")
+        );
+        assert_eq!(registry.vendor(&lladdr("00:11:23:00:00:00")), None);
+    }
+
+    #[test]
+    fn test_oui_registry_from_csv_parse_error() {
+        let error = OuiRegistry::from_csv("MA-L,00112,Short\n").unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "OuiRegistry::ParseError { line: 1, value: \"MA-L,00112,Short\" }"
+        );
+    }
+
+    #[test]
+    fn test_oui_registry_from_csv_unknown_registry() {
+        let error = OuiRegistry::from_csv("\nCID,001122,Acme\n").unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "OuiRegistry::ParseError { line: 2, value: \"CID,001122,Acme\" }"
+        );
+    }
+
+    #[test]
+    fn test_oui_registry_load() {
+        let path = std::env::temp_dir().join(format!("net-sys-oui-{}.csv", std::process::id()));
+        std::fs::write(&path, CSV).unwrap();
+
+        let registry = OuiRegistry::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(registry.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_oui_registry_load_error() {
+        let error = OuiRegistry::load("/nonexistent/oui.csv").unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "OuiRegistry::ReadError { path: \"/nonexistent/oui.csv\", error: \"No such file or directory (os error 2)\" }"
+        );
+    }
+
+    #[test]
+    fn test_oui_registry_extend() {
+        let mut registry = OuiRegistry::embedded().clone();
+
+        registry.extend(OuiRegistry::from_csv(CSV).unwrap());
+
+        assert_eq!(registry.len(), OuiRegistry::embedded().len() + 3);
+        assert_eq!(
+            registry.vendor(&lladdr("00:11:22:ff:ff:ff")),
+            Some("Acme, Inc.")
+        );
+    }
+
+    #[test]
+    fn test_link_level_address_vendor() {
+        assert_eq!(
+            lladdr("b8:27:eb:00:00:01").vendor(),
+            Some("Raspberry Pi Foundation")
+        );
+        assert_eq!(lladdr("02:50:56:12:34:56").vendor(), None);
+    }
+
+    #[test]
+    fn test_link_level_address_vendor_in() {
+        let registry = OuiRegistry::from_csv(CSV).unwrap();
+
+        assert_eq!(
+            lladdr("00:11:22:33:4f:ff").vendor_in(&registry),
+            Some("Acme \"Small\"")
+        );
+        assert_eq!(lladdr("02:11:22:33:4f:ff").vendor_in(&registry), None);
+        assert_eq!(lladdr("00:50:56:12:34:56").vendor_in(&registry), None);
+    }
+
+    // The only test that installs, the registry is process-wide
+    #[test]
+    fn test_oui_registry_install() {
+        let mut registry = OuiRegistry::embedded().clone();
+        registry.extend(OuiRegistry::from_csv(CSV).unwrap());
+
+        registry.install().unwrap();
+
+        assert_eq!(lladdr("00:11:22:3f:ff:ff").vendor(), Some("Acme Medium"));
+        assert_eq!(lladdr("00:50:56:12:34:56").vendor(), Some("VMware, Inc."));
+        assert!(OuiRegistry::default().install().is_err());
+    }
+}
@@ -0,0 +1,767 @@
+//! In-memory network stack, for end-to-end tests of code built on `Nic`
+//! that should run without privileges or real interfaces.
+//!
+//! [`SimNet`] holds virtual links and behaves like the kernel would: address
+//! changes are refused by drivers that want the link down, unprivileged
+//! callers get `EPERM`, and every change is queued as a [`LinkEvent`]. Tests
+//! play the network with the `SimNet` methods and the code under test sees
+//! it through [`crate::Nic::with_backend`].
+//!
+//! ```
+//! use net_sys::sim::{Quirk, SimLink, SimNet};
+//! use net_sys::{lladdr, ifname, LinkEvent, Nic};
+//!
+//! let net = SimNet::default();
+//! net.add(
+//!     SimLink::new(ifname!("wlan0"), lladdr!("00:11:22:33:44:55"))
+//!         .with_admin(true)
+//!         .with_quirk(Quirk::BusyWhileUp),
+//! );
+//! net.take_events();
+//!
+//! let nic = Nic::with_backend(net.clone());
+//! assert!(nic.set_lladd(ifname!("wlan0"), &lladdr!("02:00:00:00:00:01")).is_err());
+//!
+//! nic.set_lladd_bounce(ifname!("wlan0"), &lladdr!("02:00:00:00:00:01")).unwrap();
+//! assert!(matches!(net.take_events()[..], [
+//!     LinkEvent::AdminDown(_),
+//!     LinkEvent::CarrierDown(_),
+//!     LinkEvent::AddressChanged { .. },
+//!     LinkEvent::AdminUp(_),
+//!     LinkEvent::CarrierUp(_),
+//! ]));
+//! ```
+
+use crate::{
+    IfFlags, IfId, IfIndex, IfName, Link, LinkEvent, LinkLevelAddress, NicBackend, Operation,
+    Result,
+};
+use core::fmt::{Debug, Display};
+use std::collections::VecDeque;
+use std::result;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+const IFF_DEFAULT: libc::c_int = libc::IFF_BROADCAST | libc::IFF_MULTICAST;
+
+// What `Nic::watch` reads the carrier from on Linux
+#[cfg(target_os = "linux")]
+const IFF_CARRIER: libc::c_int = libc::IFF_RUNNING | libc::IFF_LOWER_UP;
+#[cfg(target_os = "macos")]
+const IFF_CARRIER: libc::c_int = libc::IFF_RUNNING;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Error {
+    NoSuchDevice(Operation, IfId),
+    NotPermitted(Operation, IfId),
+    Busy(Operation, IfId),
+    Unsupported(Operation, IfId),
+    Injected(Operation, IfId, libc::c_int),
+}
+
+impl Error {
+    fn errno(&self) -> libc::c_int {
+        match self {
+            Error::NoSuchDevice(..) => libc::ENODEV,
+            Error::NotPermitted(..) => libc::EPERM,
+            Error::Busy(..) => libc::EBUSY,
+            Error::Unsupported(..) => libc::EOPNOTSUPP,
+            Error::Injected(_, _, errno) => *errno,
+        }
+    }
+
+    fn operation(&self) -> Operation {
+        match self {
+            Error::NoSuchDevice(op, _)
+            | Error::NotPermitted(op, _)
+            | Error::Busy(op, _)
+            | Error::Unsupported(op, _)
+            | Error::Injected(op, _, _) => *op,
+        }
+    }
+
+    fn ifid(&self) -> IfId {
+        match self {
+            Error::NoSuchDevice(_, ifid)
+            | Error::NotPermitted(_, ifid)
+            | Error::Busy(_, ifid)
+            | Error::Unsupported(_, ifid)
+            | Error::Injected(_, ifid, _) => *ifid,
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Error::NoSuchDevice(..) => "Sim::NoSuchDeviceError",
+            Error::NotPermitted(..) => "Sim::NotPermittedError",
+            Error::Busy(..) => "Sim::BusyError",
+            Error::Unsupported(..) => "Sim::UnsupportedError",
+            Error::Injected(..) => "Sim::InjectedError",
+        };
+        f.debug_struct(name)
+            .field("op", &self.operation())
+            .field("ifid", &self.ifid())
+            .field("errno", &self.errno())
+            .finish()
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(error: Error) -> Self {
+        let ifid = error.ifid();
+        crate::Error::from_errno(error.errno(), error.operation(), error).with_ifid(ifid)
+    }
+}
+
+/// Driver behaviour a [`SimLink`] can be given.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Quirk {
+    /// Refuses address changes with `EBUSY` while the link is up, as many
+    /// Wi-Fi and USB drivers do.
+    BusyWhileUp,
+    /// Refuses address changes with `EOPNOTSUPP`.
+    FixedAddress,
+    /// Reports `00:00:00:00:00:00` as its permanent address.
+    NoPermanentAddress,
+}
+
+/// A virtual link of a [`SimNet`]. Starts down, with carrier, a 1500 bytes
+/// MTU and its current address as permanent address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimLink {
+    ifname: IfName,
+    index: IfIndex,
+    lladdr: LinkLevelAddress,
+    perm_lladdr: LinkLevelAddress,
+    mtu: u32,
+    up: bool,
+    carrier: bool,
+    quirks: Vec<Quirk>,
+}
+
+impl SimLink {
+    pub fn new(ifname: IfName, lladdr: LinkLevelAddress) -> Self {
+        Self {
+            ifname,
+            index: IfIndex::from(0),
+            lladdr,
+            perm_lladdr: lladdr,
+            mtu: 1500,
+            up: false,
+            carrier: true,
+            quirks: Vec::new(),
+        }
+    }
+
+    pub fn with_permanent_lladdr(self, perm_lladdr: LinkLevelAddress) -> Self {
+        Self {
+            perm_lladdr,
+            ..self
+        }
+    }
+
+    pub fn with_mtu(self, mtu: u32) -> Self {
+        Self { mtu, ..self }
+    }
+
+    pub fn with_admin(self, up: bool) -> Self {
+        Self { up, ..self }
+    }
+
+    pub fn with_carrier(self, carrier: bool) -> Self {
+        Self { carrier, ..self }
+    }
+
+    pub fn with_quirk(mut self, quirk: Quirk) -> Self {
+        self.quirks.push(quirk);
+        self
+    }
+
+    pub fn ifname(&self) -> &IfName {
+        &self.ifname
+    }
+
+    /// Given by [`SimNet::add`], 0 until then.
+    pub fn index(&self) -> IfIndex {
+        self.index
+    }
+
+    pub fn lladdr(&self) -> &LinkLevelAddress {
+        &self.lladdr
+    }
+
+    pub fn permanent_lladdr(&self) -> &LinkLevelAddress {
+        &self.perm_lladdr
+    }
+
+    pub fn mtu(&self) -> u32 {
+        self.mtu
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.up
+    }
+
+    pub fn has_carrier(&self) -> bool {
+        self.carrier
+    }
+
+    pub fn has_quirk(&self, quirk: Quirk) -> bool {
+        self.quirks.contains(&quirk)
+    }
+
+    /// `IFF_UP` follows the admin state, `IFF_RUNNING` needs the link up
+    /// and a carrier.
+    pub fn flags(&self) -> IfFlags {
+        let mut flags = IFF_DEFAULT;
+        if self.up {
+            flags |= libc::IFF_UP;
+        }
+        if self.up && self.carrier {
+            flags |= IFF_CARRIER;
+        }
+        IfFlags::from(flags as u32)
+    }
+
+    pub fn to_link(&self) -> Link {
+        Link::new(self.ifname, self.index, self.lladdr, self.flags())
+    }
+
+    fn matches(&self, ifid: IfId) -> bool {
+        match ifid {
+            IfId::Name(ifname) => ifname == self.ifname,
+            IfId::Index(index) => index == self.index,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    links: Vec<SimLink>,
+    last_index: u32,
+    unprivileged: bool,
+    // Operation, link, matching calls still let through, errno
+    failures: Vec<(Operation, IfId, usize, libc::c_int)>,
+    events: VecDeque<LinkEvent>,
+}
+
+impl State {
+    fn find(&mut self, op: Operation, ifid: IfId) -> result::Result<&mut SimLink, Error> {
+        let position = self
+            .links
+            .iter()
+            .position(|link| link.matches(ifid))
+            .ok_or(Error::NoSuchDevice(op, ifid))?;
+
+        let failure = self
+            .failures
+            .iter()
+            .position(|(failure_op, failure_ifid, _, _)| {
+                *failure_op == op && self.links[position].matches(*failure_ifid)
+            });
+        if let Some(failure) = failure {
+            match &mut self.failures[failure] {
+                (_, _, skip, _) if *skip > 0 => *skip -= 1,
+                _ => {
+                    let (_, _, _, errno) = self.failures.remove(failure);
+                    return Err(Error::Injected(op, ifid, errno));
+                }
+            }
+        }
+
+        Ok(&mut self.links[position])
+    }
+
+    fn get<T>(&mut self, op: Operation, ifid: IfId, f: impl FnOnce(&SimLink) -> T) -> Result<T> {
+        match self.find(op, ifid) {
+            Ok(link) => Ok(f(link)),
+            Err(error) => Err(crate::Error::from(error).into()),
+        }
+    }
+
+    fn update(
+        &mut self,
+        op: Operation,
+        ifid: IfId,
+        f: impl FnOnce(&mut SimLink) -> result::Result<(), Error>,
+    ) -> Result<()> {
+        let unprivileged = self.unprivileged;
+        let result = self.find(op, ifid).and_then(|link| match unprivileged {
+            true => Err(Error::NotPermitted(op, ifid)),
+            false => {
+                let previous = link.clone();
+                f(link).map(|()| diff(&previous, link))
+            }
+        });
+        match result {
+            Ok(events) => {
+                self.events.extend(events);
+                Ok(())
+            }
+            Err(error) => Err(crate::Error::from(error).into()),
+        }
+    }
+}
+
+/// Virtual network stack, to give to [`crate::Nic::with_backend`]. Clones
+/// share their links, so a test keeps one to script the network while the
+/// `Nic` uses another.
+#[derive(Clone, Debug, Default)]
+pub struct SimNet(Arc<Mutex<State>>);
+
+impl SimNet {
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.0.lock().unwrap()
+    }
+
+    /// Plugs `link` in under the next free index, which is returned.
+    pub fn add(&self, link: SimLink) -> IfIndex {
+        let mut state = self.state();
+        state.last_index += 1;
+        let link = SimLink {
+            index: IfIndex::from(state.last_index),
+            ..link
+        };
+        state.events.push_back(LinkEvent::Added(link.to_link()));
+        let index = link.index;
+        state.links.push(link);
+        index
+    }
+
+    /// Unplugs the link and returns its last state.
+    pub fn remove(&self, ifid: impl Into<IfId>) -> Option<SimLink> {
+        let ifid = ifid.into();
+        let mut state = self.state();
+        let position = state.links.iter().position(|link| link.matches(ifid))?;
+        let link = state.links.remove(position);
+        state.events.push_back(LinkEvent::Removed(link.to_link()));
+        Some(link)
+    }
+
+    /// Returns the current state of the link, if there is one.
+    pub fn link(&self, ifid: impl Into<IfId>) -> Option<SimLink> {
+        let ifid = ifid.into();
+        let state = self.state();
+        state.links.iter().find(|link| link.matches(ifid)).cloned()
+    }
+
+    pub fn rename(&self, ifid: impl Into<IfId>, ifname: IfName) -> Result<()> {
+        self.script(ifid.into(), |link| link.ifname = ifname)
+    }
+
+    /// Plugs or unplugs the cable. Only shows in the flags while the link
+    /// is up.
+    pub fn set_carrier(&self, ifid: impl Into<IfId>, carrier: bool) -> Result<()> {
+        self.script(ifid.into(), |link| link.carrier = carrier)
+    }
+
+    /// Unprivileged callers get `EPERM` from every change, like a process
+    /// without `CAP_NET_ADMIN`. Reads still work.
+    pub fn set_privileged(&self, privileged: bool) {
+        self.state().unprivileged = !privileged;
+    }
+
+    /// Fails the next `op` on the link with `errno`. Failures queued for
+    /// the same link and operation fire in order.
+    pub fn fail_next(&self, op: Operation, ifid: impl Into<IfId>, errno: libc::c_int) {
+        self.fail_after(op, ifid, 0, errno);
+    }
+
+    /// Like [`SimNet::fail_next`], once `skip` more `op` on the link went
+    /// through, such as the `SetFlags` bringing a link back up after the
+    /// one taking it down.
+    pub fn fail_after(
+        &self,
+        op: Operation,
+        ifid: impl Into<IfId>,
+        skip: usize,
+        errno: libc::c_int,
+    ) {
+        self.state().failures.push((op, ifid.into(), skip, errno));
+    }
+
+    /// Drains the events queued since the last call, oldest first.
+    pub fn take_events(&self) -> Vec<LinkEvent> {
+        self.state().events.drain(..).collect()
+    }
+
+    // Changes made by the test stand for the outside world, so they skip
+    // privileges and failure injection.
+    fn script(&self, ifid: IfId, f: impl FnOnce(&mut SimLink)) -> Result<()> {
+        let mut state = self.state();
+        let link = match state.links.iter_mut().find(|link| link.matches(ifid)) {
+            Some(link) => link,
+            None => {
+                let error = Error::NoSuchDevice(Operation::SetFlags, ifid);
+                return Err(crate::Error::from(error).into());
+            }
+        };
+        let previous = link.clone();
+        f(link);
+        let events = diff(&previous, link);
+        state.events.extend(events);
+        Ok(())
+    }
+}
+
+impl NicBackend for SimNet {
+    fn get_lladd(&self, ifid: IfId) -> Result<LinkLevelAddress> {
+        let op = Operation::GetLinkLevelAddress;
+        self.state().get(op, ifid, |link| link.lladdr)
+    }
+
+    fn set_lladd(&self, ifid: IfId, lladdr: &LinkLevelAddress) -> Result<()> {
+        let op = Operation::SetLinkLevelAddress;
+        self.state().update(op, ifid, |link| {
+            if link.has_quirk(Quirk::FixedAddress) {
+                return Err(Error::Unsupported(op, ifid));
+            }
+            if link.up && link.has_quirk(Quirk::BusyWhileUp) {
+                return Err(Error::Busy(op, ifid));
+            }
+            link.lladdr = *lladdr;
+            Ok(())
+        })
+    }
+
+    fn get_flags(&self, ifid: IfId) -> Result<IfFlags> {
+        self.state().get(Operation::GetFlags, ifid, SimLink::flags)
+    }
+
+    fn set_admin(&self, ifid: IfId, up: bool) -> Result<()> {
+        self.state().update(Operation::SetFlags, ifid, |link| {
+            link.up = up;
+            Ok(())
+        })
+    }
+
+    fn get_mtu(&self, ifid: IfId) -> Result<u32> {
+        self.state().get(Operation::GetMtu, ifid, |link| link.mtu)
+    }
+
+    fn set_mtu(&self, ifid: IfId, mtu: u32) -> Result<()> {
+        self.state().update(Operation::SetMtu, ifid, |link| {
+            link.mtu = mtu;
+            Ok(())
+        })
+    }
+
+    fn get_permanent_lladdr(&self, ifid: IfId) -> Result<LinkLevelAddress> {
+        let op = Operation::GetPermanentLinkLevelAddress;
+        self.state().get(op, ifid, |link| {
+            match link.has_quirk(Quirk::NoPermanentAddress) {
+                true => LinkLevelAddress::from_be_bytes([0; 6]),
+                false => link.perm_lladdr,
+            }
+        })
+    }
+
+    fn list(&self) -> Result<Vec<Link>> {
+        Ok(self.state().links.iter().map(SimLink::to_link).collect())
+    }
+}
+
+// Same events, in the same order, as `Nic::watch` reports for the change.
+fn diff(previous: &SimLink, link: &SimLink) -> Vec<LinkEvent> {
+    let (previous, link) = (previous.to_link(), link.to_link());
+
+    let mut events = Vec::new();
+    if previous.ifname() != link.ifname() {
+        let from = *previous.ifname();
+        events.push(LinkEvent::Renamed { link, from });
+    }
+    if previous.lladdr() != link.lladdr() {
+        let from = *previous.lladdr();
+        events.push(LinkEvent::AddressChanged { link, from });
+    }
+    match (previous.flags().is_up(), link.flags().is_up()) {
+        (false, true) => events.push(LinkEvent::AdminUp(link)),
+        (true, false) => events.push(LinkEvent::AdminDown(link)),
+        _ => (),
+    }
+    match (previous.flags().is_running(), link.flags().is_running()) {
+        (false, true) => events.push(LinkEvent::CarrierUp(link)),
+        (true, false) => events.push(LinkEvent::CarrierDown(link)),
+        _ => (),
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Quirk, SimLink, SimNet};
+    use crate::{
+        ifname, lladdr, ErrorKind, IfIndex, IfName, LinkEvent, LinkLevelAddress, Nic, Operation,
+    };
+
+    const WLAN: IfName = ifname!("wlan0");
+    const ETH: IfName = ifname!("eth0");
+    const WLAN_LLADDR: LinkLevelAddress = lladdr!("00:11:22:33:44:55");
+    const ETH_LLADDR: LinkLevelAddress = lladdr!("00:11:22:33:44:66");
+    const LLADDR: LinkLevelAddress = lladdr!("02:00:00:00:00:01");
+
+    fn sim_net() -> SimNet {
+        let net = SimNet::default();
+        net.add(
+            SimLink::new(WLAN, WLAN_LLADDR)
+                .with_admin(true)
+                .with_quirk(Quirk::BusyWhileUp),
+        );
+        net.add(SimLink::new(ETH, ETH_LLADDR).with_admin(true));
+        net.take_events();
+        net
+    }
+
+    fn kind(error: Box<dyn std::error::Error>) -> ErrorKind {
+        error.downcast_ref::<crate::Error>().unwrap().kind()
+    }
+
+    #[test]
+    fn test_sim_net_add() {
+        let net = SimNet::default();
+
+        let index = net.add(SimLink::new(WLAN, WLAN_LLADDR));
+
+        let link = net.link(WLAN).unwrap();
+        assert_eq!(index, IfIndex::from(1));
+        assert_eq!(link.index(), index);
+        assert_eq!(net.link(index), Some(link.clone()));
+        assert_eq!(net.take_events(), vec![LinkEvent::Added(link.to_link())]);
+        assert_eq!(net.take_events(), vec![]);
+    }
+
+    #[test]
+    fn test_sim_net_remove() {
+        let net = sim_net();
+
+        let link = net.remove(ETH).unwrap();
+
+        assert_eq!(net.remove(ETH), None);
+        assert_eq!(net.take_events(), vec![LinkEvent::Removed(link.to_link())]);
+        let error = Nic::with_backend(net).get_lladd(ETH).unwrap_err();
+        assert_eq!(kind(error), ErrorKind::NoSuchDevice);
+    }
+
+    #[test]
+    fn test_sim_net_get() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        assert_eq!(nic.get_lladd(WLAN).unwrap(), WLAN_LLADDR);
+        assert_eq!(nic.get_lladd(IfIndex::from(2)).unwrap(), ETH_LLADDR);
+        assert_eq!(nic.get_mtu(WLAN).unwrap(), 1500);
+        assert_eq!(nic.get_permanent_lladdr(WLAN).unwrap(), WLAN_LLADDR);
+        assert!(nic.get_flags(WLAN).unwrap().is_running());
+        assert_eq!(nic.list().unwrap().len(), 2);
+        assert_eq!(net.take_events(), vec![]);
+    }
+
+    #[test]
+    fn test_sim_net_set_lladd() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        nic.set_lladd(ETH, &LLADDR).unwrap();
+
+        let link = net.link(ETH).unwrap();
+        assert_eq!(*link.lladdr(), LLADDR);
+        assert_eq!(*link.permanent_lladdr(), ETH_LLADDR);
+        assert_eq!(
+            net.take_events(),
+            vec![LinkEvent::AddressChanged {
+                link: link.to_link(),
+                from: ETH_LLADDR
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sim_net_set_lladd_busy_while_up() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic.set_lladd(WLAN, &LLADDR).unwrap_err();
+
+        assert_eq!(
+            format!("{}", error.source().unwrap()),
+            "Sim::BusyError { op: SetLinkLevelAddress, ifid: Name(\"wlan0\"), errno: 16 }"
+        );
+        assert_eq!(kind(error), ErrorKind::Busy);
+        assert_eq!(net.take_events(), vec![]);
+
+        nic.set_lladd_bounce(WLAN, &LLADDR).unwrap();
+
+        let link = net.link(WLAN).unwrap();
+        assert_eq!(*link.lladdr(), LLADDR);
+        assert!(link.is_up());
+        assert_eq!(net.take_events().len(), 5);
+    }
+
+    #[test]
+    fn test_sim_net_set_lladd_fixed_address() {
+        let net = SimNet::default();
+        net.add(SimLink::new(ETH, ETH_LLADDR).with_quirk(Quirk::FixedAddress));
+
+        let error = Nic::with_backend(net).set_lladd(ETH, &LLADDR).unwrap_err();
+
+        assert_eq!(kind(error), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_sim_net_unprivileged() {
+        let net = sim_net();
+        net.set_privileged(false);
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic.set_lladd(ETH, &LLADDR).unwrap_err();
+
+        assert_eq!(kind(error), ErrorKind::PermissionDenied);
+        assert_eq!(
+            kind(nic.set_down(ETH).unwrap_err()),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(
+            kind(nic.set_mtu(ETH, 9000).unwrap_err()),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(nic.get_lladd(ETH).unwrap(), ETH_LLADDR);
+
+        net.set_privileged(true);
+
+        nic.set_lladd(ETH, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_sim_net_fail_next() {
+        let net = sim_net();
+        net.fail_next(
+            Operation::SetLinkLevelAddress,
+            IfIndex::from(2),
+            libc::EADDRNOTAVAIL,
+        );
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic.set_lladd(ETH, &LLADDR).unwrap_err();
+
+        let error = error.downcast_ref::<crate::Error>().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidAddress);
+        assert_eq!(error.operation(), Operation::SetLinkLevelAddress);
+        assert_eq!(error.errno(), Some(libc::EADDRNOTAVAIL));
+        assert_eq!(*net.link(ETH).unwrap().lladdr(), ETH_LLADDR);
+
+        nic.set_lladd(ETH, &LLADDR).unwrap();
+    }
+
+    #[test]
+    fn test_sim_net_fail_next_other_link() {
+        let net = sim_net();
+        net.fail_next(Operation::GetMtu, WLAN, libc::EIO);
+        let nic = Nic::with_backend(net);
+
+        assert_eq!(nic.get_mtu(ETH).unwrap(), 1500);
+        assert_eq!(kind(nic.get_mtu(WLAN).unwrap_err()), ErrorKind::Other);
+        assert_eq!(nic.get_mtu(WLAN).unwrap(), 1500);
+    }
+
+    #[test]
+    fn test_sim_net_fail_after() {
+        let net = sim_net();
+        net.fail_after(Operation::GetMtu, ETH, 1, libc::EIO);
+        let nic = Nic::with_backend(net);
+
+        assert_eq!(nic.get_mtu(ETH).unwrap(), 1500);
+        assert_eq!(kind(nic.get_mtu(ETH).unwrap_err()), ErrorKind::Other);
+        assert_eq!(nic.get_mtu(ETH).unwrap(), 1500);
+    }
+
+    #[test]
+    fn test_sim_net_permanent_lladdr() {
+        let net = SimNet::default();
+        net.add(SimLink::new(WLAN, LLADDR).with_permanent_lladdr(WLAN_LLADDR));
+        net.add(SimLink::new(ETH, ETH_LLADDR).with_quirk(Quirk::NoPermanentAddress));
+        let nic = Nic::with_backend(net.clone());
+
+        assert_eq!(nic.get_permanent_lladdr(WLAN).unwrap(), WLAN_LLADDR);
+        assert_eq!(
+            nic.get_permanent_lladdr(ETH).unwrap(),
+            lladdr!("00:00:00:00:00:00")
+        );
+    }
+
+    #[test]
+    fn test_sim_net_carrier() {
+        let net = sim_net();
+
+        net.set_carrier(ETH, false).unwrap();
+        net.set_carrier(ETH, true).unwrap();
+        Nic::with_backend(net.clone()).set_down(ETH).unwrap();
+        net.set_carrier(ETH, false).unwrap();
+
+        let link = net.link(ETH).unwrap();
+        assert!(!link.has_carrier());
+        assert!(!link.flags().is_running());
+        assert!(matches!(
+            net.take_events()[..],
+            [
+                LinkEvent::CarrierDown(_),
+                LinkEvent::CarrierUp(_),
+                LinkEvent::AdminDown(_),
+                LinkEvent::CarrierDown(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_sim_net_rename() {
+        let net = sim_net();
+
+        net.rename(ETH, ifname!("enp0s1")).unwrap();
+
+        let link = net.link(IfIndex::from(2)).unwrap();
+        assert_eq!(link.ifname(), &ifname!("enp0s1"));
+        assert_eq!(net.link(ETH), None);
+        assert_eq!(
+            net.take_events(),
+            vec![LinkEvent::Renamed {
+                link: link.to_link(),
+                from: ETH
+            }]
+        );
+        assert!(net.rename(ETH, WLAN).is_err());
+    }
+
+    #[test]
+    fn test_sim_net_roaming_swap() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        nic.set_lladd_bounce(WLAN, &LLADDR).unwrap();
+        nic.set_lladd_bounce(ETH, &WLAN_LLADDR).unwrap();
+        nic.set_lladd_bounce(WLAN, &ETH_LLADDR).unwrap();
+
+        assert_eq!(nic.get_lladd(WLAN).unwrap(), ETH_LLADDR);
+        assert_eq!(nic.get_lladd(ETH).unwrap(), WLAN_LLADDR);
+        let changes: Vec<_> = net
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                LinkEvent::AddressChanged { link, from } => Some((*link.ifname(), from)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![(WLAN, WLAN_LLADDR), (ETH, ETH_LLADDR), (WLAN, LLADDR)]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Ready-made [`NicBackend`] mock, for crates that build on `Nic` and want
+//! to unit test their own logic without privileges or real interfaces.
+//!
+//! Every call pops the next expectation and fails the test if it does not
+//! match the call. Expectations left over when the mock is dropped fail the
+//! test too.
+//!
+//! ```
+//! use net_sys::testing::{mock, Mockdown, MockNic};
+//! use net_sys::{lladdr, ifname, IfId, Nic};
+//!
+//! let backend = MockNic::default()
+//!     .expect(|mock::GetLLAddr(ifid)| {
+//!         assert_eq!(ifid, IfId::from(ifname!("eth0")));
+//!         net_sys::Result::Ok(lladdr!("00:11:22:33:44:55"))
+//!     });
+//!
+//! let nic = Nic::with_backend(backend);
+//!
+//! assert_eq!(nic.get_lladd(ifname!("eth0")).unwrap(), lladdr!("00:11:22:33:44:55"));
+//! ```
+
+use crate::{IfFlags, IfId, Link, LinkLevelAddress, NicBackend, Result};
+use mockdown::ExpectStore;
+pub use mockdown::Mockdown;
+
+/// Arguments of each [`MockNic`] call, to match in `expect` closures.
+pub mod mock {
+    use crate::{IfId, LinkLevelAddress};
+
+    #[derive(Debug)]
+    pub struct GetLLAddr(pub IfId);
+    #[derive(Debug)]
+    pub struct SetLLAddr(pub IfId, pub LinkLevelAddress);
+    #[derive(Debug)]
+    pub struct GetFlags(pub IfId);
+    #[derive(Debug)]
+    pub struct SetAdmin(pub IfId, pub bool);
+    #[derive(Debug)]
+    pub struct GetMtu(pub IfId);
+    #[derive(Debug)]
+    pub struct SetMtu(pub IfId, pub u32);
+    #[derive(Debug)]
+    pub struct GetPermLLAddr(pub IfId);
+    #[derive(Debug)]
+    pub struct List();
+}
+
+/// [`NicBackend`] answering from `expect` closures. Clones share their
+/// expectations.
+#[derive(Clone, Debug, Default)]
+pub struct MockNic(ExpectStore);
+
+impl Mockdown for MockNic {
+    fn store(&self) -> &ExpectStore {
+        &self.0
+    }
+}
+
+impl NicBackend for MockNic {
+    fn get_lladd(&self, ifid: IfId) -> Result<LinkLevelAddress> {
+        let args = mock::GetLLAddr(ifid);
+        self.on_mock(args).unwrap()
+    }
+
+    fn set_lladd(&self, ifid: IfId, lladdr: &LinkLevelAddress) -> Result<()> {
+        let args = mock::SetLLAddr(ifid, *lladdr);
+        self.on_mock(args).unwrap()
+    }
+
+    fn get_flags(&self, ifid: IfId) -> Result<IfFlags> {
+        let args = mock::GetFlags(ifid);
+        self.on_mock(args).unwrap()
+    }
+
+    fn set_admin(&self, ifid: IfId, up: bool) -> Result<()> {
+        let args = mock::SetAdmin(ifid, up);
+        self.on_mock(args).unwrap()
+    }
+
+    fn get_mtu(&self, ifid: IfId) -> Result<u32> {
+        let args = mock::GetMtu(ifid);
+        self.on_mock(args).unwrap()
+    }
+
+    fn set_mtu(&self, ifid: IfId, mtu: u32) -> Result<()> {
+        let args = mock::SetMtu(ifid, mtu);
+        self.on_mock(args).unwrap()
+    }
+
+    fn get_permanent_lladdr(&self, ifid: IfId) -> Result<LinkLevelAddress> {
+        let args = mock::GetPermLLAddr(ifid);
+        self.on_mock(args).unwrap()
+    }
+
+    fn list(&self) -> Result<Vec<Link>> {
+        let args = mock::List();
+        self.on_mock(args).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mock, MockNic, Mockdown};
+    use crate::{ifname, lladdr, IfFlags, IfId, IfName, Link, LinkLevelAddress, Nic, Result};
+
+    const IFNAME: IfName = ifname!("enx");
+    const LLADDR: LinkLevelAddress = lladdr!("02:00:00:00:00:01");
+
+    #[test]
+    fn test_mock_nic_set_lladd_bounce() {
+        let backend = MockNic::default()
+            .expect(|mock::GetFlags(_)| Result::Ok(IfFlags::from(libc::IFF_UP as u32)))
+            .expect(|mock::SetAdmin(ifid, up)| {
+                assert_eq!((ifid, up), (IfId::from(IFNAME), false));
+                Result::Ok(())
+            })
+            .expect(|mock::SetLLAddr(ifid, lladdr)| {
+                assert_eq!((ifid, lladdr), (IfId::from(IFNAME), LLADDR));
+                Result::Ok(())
+            })
+            .expect(|mock::SetAdmin(_, up)| {
+                assert!(up);
+                Result::Ok(())
+            });
+
+        Nic::with_backend(backend)
+            .set_lladd_bounce(IFNAME, &LLADDR)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_mock_nic_set_lladd_error() {
+        let backend = MockNic::default()
+            .expect(|mock::SetLLAddr(_, _)| -> Result<()> { Err("SetLLAddrError".into()) });
+
+        let error = Nic::with_backend(backend)
+            .set_lladd(IFNAME, &LLADDR)
+            .unwrap_err();
+
+        assert_eq!(format!("{}", error), "SetLLAddrError");
+    }
+
+    #[test]
+    fn test_mock_nic_set_lladd_unassignable() {
+        let nic = Nic::with_backend(MockNic::default());
+
+        let error = nic
+            .set_lladd(IFNAME, &lladdr!("ff:ff:ff:ff:ff:ff"))
+            .unwrap_err();
+
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, crate::ErrorKind::InvalidAddress);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_mock_nic_reset_lladdr() {
+        let backend = MockNic::default()
+            .expect(|mock::GetPermLLAddr(_)| Result::Ok(LLADDR))
+            .expect(|mock::SetLLAddr(_, lladdr)| {
+                assert_eq!(lladdr, LLADDR);
+                Result::Ok(())
+            });
+
+        let lladdr = Nic::with_backend(backend).reset_lladdr(IFNAME).unwrap();
+
+        assert_eq!(lladdr, LLADDR);
+    }
+
+    #[test]
+    fn test_mock_nic_mtu() {
+        let backend = MockNic::default()
+            .expect(|mock::GetMtu(_)| Result::Ok(1500u32))
+            .expect(|mock::SetMtu(_, mtu)| {
+                assert_eq!(mtu, 9000);
+                Result::Ok(())
+            });
+        let nic = Nic::with_backend(backend);
+
+        assert_eq!(nic.get_mtu(IFNAME).unwrap(), 1500);
+        nic.set_mtu(IFNAME, 9000).unwrap();
+    }
+
+    #[test]
+    fn test_mock_nic_list() {
+        let backend = MockNic::default().expect(|mock::List()| {
+            Result::Ok(vec![Link::new(IFNAME, 2.into(), LLADDR, IfFlags::from(0))])
+        });
+
+        let links = Nic::with_backend(backend).list().unwrap();
+
+        assert_eq!(*links[0].lladdr(), LLADDR);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_mock_nic_get_hwaddr() {
+        let backend = MockNic::default().expect(|mock::GetLLAddr(_)| Result::Ok(LLADDR));
+
+        let hwaddr = Nic::with_backend(backend).get_hwaddr(IFNAME).unwrap();
+
+        assert!(hwaddr.is_ether());
+        assert_eq!(hwaddr[..], LLADDR[..]);
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{BounceError, IfId, LinkLevelAddress, Nic, Result};
+use core::fmt::{Debug, Display};
+
+#[derive(Clone, Copy, Debug)]
+struct Change {
+    ifid: IfId,
+    lladdr: LinkLevelAddress,
+    bounce: bool,
+}
+
+/// Address changes to several interfaces that go through together or not
+/// at all, returned by `Nic::transaction`.
+///
+/// Nothing happens until [`Transaction::commit`], which applies the changes
+/// in order, recording each interface's address before changing it. When a
+/// change fails, the earlier ones are put back in reverse order.
+#[derive(Debug)]
+pub struct Transaction<'a> {
+    nic: &'a Nic,
+    changes: Vec<Change>,
+}
+
+impl<'a> Transaction<'a> {
+    pub(crate) fn new(nic: &'a Nic) -> Self {
+        Self {
+            nic,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Adds a change made with `Nic::set_lladd`.
+    pub fn set_lladd(mut self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Self {
+        self.changes.push(Change {
+            ifid: ifid.into(),
+            lladdr: *lladdr,
+            bounce: false,
+        });
+        self
+    }
+
+    /// Adds a change made with `Nic::set_lladd_bounce`, also used to put
+    /// the address back.
+    pub fn set_lladd_bounce(mut self, ifid: impl Into<IfId>, lladdr: &LinkLevelAddress) -> Self {
+        self.changes.push(Change {
+            ifid: ifid.into(),
+            lladdr: *lladdr,
+            bounce: true,
+        });
+        self
+    }
+
+    /// Applies the changes. All addresses are checked to be assignable
+    /// before any is set. Fails with a [`TransactionError`] holding the
+    /// error of the failed change and those of the rollback, if any.
+    pub fn commit(self) -> Result<()> {
+        for change in &self.changes {
+            change.lladdr.check_assignable()?;
+        }
+
+        let mut done = Vec::with_capacity(self.changes.len());
+        for change in &self.changes {
+            let result = self.nic.get_lladd(change.ifid).and_then(|previous| {
+                self.set(change.ifid, &change.lladdr, change.bounce)
+                    .map(|()| previous)
+            });
+            match result {
+                Ok(previous) => done.push(Change {
+                    lladdr: previous,
+                    ..*change
+                }),
+                Err(error) => {
+                    let mut rollback_errors = Vec::new();
+                    // The failed change may have left its interface down
+                    if error.is::<BounceError>() {
+                        if let Err(restore_error) = self.nic.set_up(change.ifid) {
+                            rollback_errors.push((change.ifid, restore_error));
+                        }
+                    }
+                    rollback_errors.extend(done.iter().rev().filter_map(|undo| {
+                        self.set(undo.ifid, &undo.lladdr, undo.bounce)
+                            .err()
+                            .map(|error| (undo.ifid, error))
+                    }));
+                    return Err(TransactionError {
+                        ifid: change.ifid,
+                        error,
+                        rollback_errors,
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // New addresses are checked up front, and the ones put back on rollback
+    // are what the interfaces had, so neither goes through
+    // `check_assignable` again.
+    fn set(&self, ifid: IfId, lladdr: &LinkLevelAddress, bounce: bool) -> Result<()> {
+        match bounce {
+            true => self.nic.set_lladd_bounce_unchecked(ifid, lladdr),
+            false => self.nic.set_lladd_unchecked(ifid, lladdr),
+        }
+    }
+}
+
+/// Failure of [`Transaction::commit`]. The changes made before the failed
+/// one have been undone, and an interface a failed bounce left down has been
+/// brought back up, except on the interfaces listed in
+/// [`TransactionError::rollback_errors`].
+pub struct TransactionError {
+    ifid: IfId,
+    error: Box<dyn std::error::Error>,
+    rollback_errors: Vec<(IfId, Box<dyn std::error::Error>)>,
+}
+
+impl TransactionError {
+    /// The interface whose change failed.
+    pub fn ifid(&self) -> IfId {
+        self.ifid
+    }
+
+    /// Why the change failed.
+    pub fn error(&self) -> &(dyn std::error::Error + 'static) {
+        &*self.error
+    }
+
+    /// The interfaces left with the new address or left down, in the order
+    /// their restore was attempted, and why.
+    pub fn rollback_errors(&self) -> &[(IfId, Box<dyn std::error::Error>)] {
+        &self.rollback_errors
+    }
+
+    /// Whether every interface is back to the address and admin state it
+    /// had before.
+    pub fn is_rolled_back(&self) -> bool {
+        self.rollback_errors.is_empty()
+    }
+}
+
+impl std::error::Error for TransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error())
+    }
+}
+
+impl Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Debug for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction::CommitError")
+            .field("ifid", &self.ifid)
+            .field("error", &self.error)
+            .field("rollback_errors", &self.rollback_errors)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionError;
+    use crate::sim::{Quirk, SimLink, SimNet};
+    use crate::{
+        ifname, lladdr, BounceError, ErrorKind, IfId, IfName, LinkEvent, LinkLevelAddress, Nic,
+        Operation,
+    };
+
+    const WLAN: IfName = ifname!("wlan0");
+    const ETH: IfName = ifname!("eth0");
+    const WLAN_LLADDR: LinkLevelAddress = lladdr!("00:11:22:33:44:55");
+    const ETH_LLADDR: LinkLevelAddress = lladdr!("00:11:22:33:44:66");
+
+    fn sim_net() -> SimNet {
+        let net = SimNet::default();
+        net.add(
+            SimLink::new(WLAN, WLAN_LLADDR)
+                .with_admin(true)
+                .with_quirk(Quirk::BusyWhileUp),
+        );
+        net.add(SimLink::new(ETH, ETH_LLADDR).with_admin(true));
+        net
+    }
+
+    fn lladdrs(net: &SimNet) -> (LinkLevelAddress, LinkLevelAddress) {
+        let lladdr = |ifname| *net.link(ifname).unwrap().lladdr();
+        (lladdr(WLAN), lladdr(ETH))
+    }
+
+    #[test]
+    fn test_transaction_swap() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        nic.transaction()
+            .set_lladd_bounce(WLAN, &ETH_LLADDR)
+            .set_lladd(ETH, &WLAN_LLADDR)
+            .commit()
+            .unwrap();
+
+        assert_eq!(lladdrs(&net), (ETH_LLADDR, WLAN_LLADDR));
+        assert!(net.link(WLAN).unwrap().is_up());
+    }
+
+    #[test]
+    fn test_transaction_empty() {
+        let nic = Nic::with_backend(sim_net());
+
+        nic.transaction().commit().unwrap();
+    }
+
+    #[test]
+    fn test_transaction_rollback() {
+        let net = sim_net();
+        net.fail_next(Operation::SetLinkLevelAddress, ETH, libc::EADDRNOTAVAIL);
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic
+            .transaction()
+            .set_lladd_bounce(WLAN, &ETH_LLADDR)
+            .set_lladd(ETH, &WLAN_LLADDR)
+            .commit()
+            .unwrap_err();
+
+        let error = error.downcast_ref::<TransactionError>().unwrap();
+        assert_eq!(error.ifid(), IfId::from(ETH));
+        assert!(error.is_rolled_back());
+        let kind = error.error().downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, ErrorKind::InvalidAddress);
+        assert_eq!(lladdrs(&net), (WLAN_LLADDR, ETH_LLADDR));
+    }
+
+    #[test]
+    fn test_transaction_rollback_in_reverse_order() {
+        let net = sim_net();
+        net.add(SimLink::new(ifname!("eth1"), lladdr!("00:11:22:33:44:77")));
+        net.fail_next(
+            Operation::GetLinkLevelAddress,
+            ifname!("eth1"),
+            libc::ENODEV,
+        );
+        let nic = Nic::with_backend(net.clone());
+        net.take_events();
+
+        nic.transaction()
+            .set_lladd(ETH, &lladdr!("02:00:00:00:00:01"))
+            .set_lladd(ETH, &lladdr!("02:00:00:00:00:02"))
+            .set_lladd(ifname!("eth1"), &lladdr!("02:00:00:00:00:03"))
+            .commit()
+            .unwrap_err();
+
+        assert_eq!(lladdrs(&net), (WLAN_LLADDR, ETH_LLADDR));
+        let changes: Vec<_> = net
+            .take_events()
+            .into_iter()
+            .map(|event| match event {
+                LinkEvent::AddressChanged { link, from } => (*link.ifname(), from, *link.lladdr()),
+                event => panic!("unexpected {:?}", event),
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (ETH, ETH_LLADDR, lladdr!("02:00:00:00:00:01")),
+                (
+                    ETH,
+                    lladdr!("02:00:00:00:00:01"),
+                    lladdr!("02:00:00:00:00:02")
+                ),
+                (
+                    ETH,
+                    lladdr!("02:00:00:00:00:02"),
+                    lladdr!("02:00:00:00:00:01")
+                ),
+                (ETH, lladdr!("02:00:00:00:00:01"), ETH_LLADDR),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transaction_rollback_bounce_left_down() {
+        let net = sim_net();
+        net.fail_next(Operation::SetLinkLevelAddress, ETH, libc::EIO);
+        // The restore right after the failed change, not the bounce itself
+        net.fail_after(Operation::SetFlags, ETH, 1, libc::EIO);
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic
+            .transaction()
+            .set_lladd_bounce(ETH, &WLAN_LLADDR)
+            .commit()
+            .unwrap_err();
+
+        let error = error.downcast_ref::<TransactionError>().unwrap();
+        assert!(error.error().is::<BounceError>());
+        assert!(error.is_rolled_back());
+        assert!(net.link(ETH).unwrap().is_up());
+    }
+
+    #[test]
+    fn test_transaction_rollback_bounce_still_down() {
+        let net = sim_net();
+        net.fail_next(Operation::SetLinkLevelAddress, ETH, libc::EIO);
+        net.fail_after(Operation::SetFlags, ETH, 1, libc::EIO);
+        net.fail_next(Operation::SetFlags, ETH, libc::EIO);
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic
+            .transaction()
+            .set_lladd_bounce(ETH, &WLAN_LLADDR)
+            .commit()
+            .unwrap_err();
+
+        let error = error.downcast_ref::<TransactionError>().unwrap();
+        assert!(!error.is_rolled_back());
+        assert_eq!(error.rollback_errors()[0].0, IfId::from(ETH));
+        assert!(!net.link(ETH).unwrap().is_up());
+    }
+
+    #[test]
+    fn test_transaction_rollback_unassignable_previous() {
+        // A link that never had an address gets its zero back
+        const ETH1: IfName = ifname!("eth1");
+        let net = sim_net();
+        net.add(SimLink::new(ETH1, lladdr!("00:00:00:00:00:00")));
+        net.fail_next(Operation::SetLinkLevelAddress, ETH, libc::EBUSY);
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic
+            .transaction()
+            .set_lladd(ETH1, &lladdr!("02:00:00:00:00:01"))
+            .set_lladd(ETH, &WLAN_LLADDR)
+            .commit()
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{}", error),
+            "Transaction::CommitError { \
+             ifid: Name(\"eth0\"), \
+             error: Error { kind: Busy, operation: SetLinkLevelAddress, ifid: Name(\"eth0\"), errno: 16, \
+             source: Sim::InjectedError { op: SetLinkLevelAddress, ifid: Name(\"eth0\"), errno: 16 } }, \
+             rollback_errors: [] }"
+        );
+        let error = error.downcast_ref::<TransactionError>().unwrap();
+        assert!(error.is_rolled_back());
+        assert_eq!(
+            *net.link(ETH1).unwrap().lladdr(),
+            lladdr!("00:00:00:00:00:00")
+        );
+    }
+
+    #[test]
+    fn test_transaction_unassignable() {
+        let net = sim_net();
+        let nic = Nic::with_backend(net.clone());
+
+        let error = nic
+            .transaction()
+            .set_lladd(ETH, &WLAN_LLADDR)
+            .set_lladd(WLAN, &lladdr!("ff:ff:ff:ff:ff:ff"))
+            .commit()
+            .unwrap_err();
+
+        let kind = error.downcast_ref::<crate::Error>().unwrap().kind();
+        assert_eq!(kind, ErrorKind::InvalidAddress);
+        assert_eq!(net.take_events().len(), 2);
+    }
+}
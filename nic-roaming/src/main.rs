@@ -1,23 +1,165 @@
-use net_sys::{IfName, LLAddr, Nic};
+use net_sys::{ErrorKind, IfId, IfIndex, LLAddr, Nic, ParseLLAddrError};
 use std::error::Error;
 
+#[cfg(all(target_os = "linux", feature = "netlink"))]
+const ACTIONS: &str = "get | set | swap | random | list | reset | info | hwaddr | mtu | altnames | watch";
+#[cfg(all(target_os = "linux", not(feature = "netlink")))]
+const ACTIONS: &str = "get | set | swap | random | list | reset | info | hwaddr | mtu";
+#[cfg(not(target_os = "linux"))]
+const ACTIONS: &str = "get | set | swap | random | list | hwaddr | mtu";
+
+fn ifid(value: Option<String>) -> Result<IfId, Box<dyn Error>> {
+    let value = value.ok_or("Missing ifname param")?;
+    match value.parse::<u32>() {
+        Ok(index) => Ok(IfIndex::from(index).into()),
+        Err(_) => Nic::default().resolve(&value),
+    }
+}
+
+fn parse_lladdr(value: Option<String>) -> Result<LLAddr, Box<dyn Error>> {
+    let value = value.ok_or("Missing lladdr param")?;
+    value.parse().map_err(|error: ParseLLAddrError| {
+        eprintln!("  {}\n  {:>2$}", error.value(), "^", error.offset() + 1);
+        error.into()
+    })
+}
+
+// Vendors come from the registry in NICR_OUI_CSV when set, on top of the
+// embedded one.
+#[cfg(feature = "oui")]
+fn install_oui_registry() -> Result<(), Box<dyn Error>> {
+    if let Some(path) = std::env::var_os("NICR_OUI_CSV") {
+        let mut registry = net_sys::OuiRegistry::embedded().clone();
+        registry.extend(net_sys::OuiRegistry::load(path)?);
+        let _ = registry.install();
+    }
+    Ok(())
+}
+
+// Looks through the whole chain, as `set_lladd_bounce` and transactions
+// wrap the `net_sys::Error` that has the kind.
+fn hint(error: &(dyn Error + 'static)) -> Option<String> {
+    let error = std::iter::successors(Some(error), |&error| error.source())
+        .find_map(|error| error.downcast_ref::<net_sys::Error>())?;
+    match error.kind() {
+        ErrorKind::PermissionDenied => Some("run as root or with CAP_NET_ADMIN".to_string()),
+        ErrorKind::Busy => error
+            .ifid()
+            .map(|ifid| format!("{ifid} must be down to change its address")),
+        _ => None,
+    }
+}
+
+fn print_hint(error: &(dyn Error + 'static)) {
+    if let Some(hint) = hint(error) {
+        eprintln!("hint: {hint}");
+    }
+}
+
+fn set_lladd(ifname: IfId, lladdr: &LLAddr) -> Result<(), Box<dyn Error>> {
+    Nic::default()
+        .set_lladd(ifname, lladdr)
+        .inspect_err(|error| print_hint(error.as_ref()))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let action = std::env::args().nth(1);
     let ifname = std::env::args().nth(2);
     let lladdr = std::env::args().nth(3);
 
-    match action.ok_or("Missing action param: [get | set]")?.as_str() {
+    match action
+        .ok_or(format!("Missing action param: [{ACTIONS}]"))?
+        .as_str()
+    {
         "get" => {
-            let ifname: IfName = ifname.ok_or("Missing ifname param")?.try_into()?;
-            let lladdr = Nic::default().get_lladd(&ifname)?;
+            let ifname = ifid(ifname)?;
+            #[cfg(feature = "oui")]
+            install_oui_registry()?;
+            let lladdr = Nic::default().get_lladd(ifname)?;
+            #[cfg(feature = "oui")]
+            let lladdr = match (lladdr.vendor(), lladdr.is_locally_administered()) {
+                (Some(vendor), _) => format!("{lladdr} ({vendor})"),
+                (None, true) => format!("{lladdr} (locally administered)"),
+                (None, false) => format!("{lladdr} (unknown vendor)"),
+            };
             eprintln!("Nic.get_lladd({ifname}) -> {lladdr}");
         }
         "set" => {
-            let ifname: IfName = ifname.ok_or("Missing ifname param")?.try_into()?;
-            let lladdr: LLAddr = lladdr.ok_or("Missing lladdr param")?.parse()?;
-            Nic::default().set_lladd(&ifname, &lladdr)?;
+            let ifname = ifid(ifname)?;
+            let lladdr = parse_lladdr(lladdr)?;
+            set_lladd(ifname, &lladdr)?;
             eprintln!("Nic.set_lladd({ifname}, {lladdr})");
         }
+        "swap" => {
+            let (first, second) = (ifid(ifname)?, ifid(lladdr)?);
+            let nic = Nic::default();
+            let first_lladdr = nic.get_lladd(first)?;
+            let second_lladdr = nic.get_lladd(second)?;
+            nic.transaction()
+                .set_lladd_bounce(first, &second_lladdr)
+                .set_lladd_bounce(second, &first_lladdr)
+                .commit()
+                .inspect_err(|error| print_hint(error.as_ref()))?;
+            eprintln!("Nic.transaction({first} -> {second_lladdr}, {second} -> {first_lladdr})");
+        }
+        "random" => {
+            let ifname = ifid(ifname)?;
+            let lladdr = LLAddr::random_local_unicast()?;
+            set_lladd(ifname, &lladdr)?;
+            eprintln!("Nic.set_lladd({ifname}, {lladdr})");
+        }
+        "mtu" => {
+            let ifname = ifid(ifname)?;
+            if let Some(mtu) = lladdr {
+                let mtu: u32 = mtu.parse()?;
+                Nic::default().set_mtu(ifname, mtu)?;
+                eprintln!("Nic.set_mtu({ifname}, {mtu})");
+            } else {
+                let mtu = Nic::default().get_mtu(ifname)?;
+                eprintln!("Nic.get_mtu({ifname}) -> {mtu}");
+            }
+        }
+        #[cfg(target_os = "linux")]
+        "reset" => {
+            let ifname = ifid(ifname)?;
+            let lladdr = Nic::default().reset_lladdr(ifname)?;
+            eprintln!("Nic.reset_lladdr({ifname}) -> {lladdr}");
+        }
+        #[cfg(target_os = "linux")]
+        "info" => {
+            let info = Nic::sysfs().info(ifid(ifname)?)?;
+            eprintln!("{info:?}");
+        }
+        "hwaddr" => {
+            let ifname = ifid(ifname)?;
+            let hwaddr = Nic::default().get_hwaddr(ifname)?;
+            eprintln!("Nic.get_hwaddr({ifname}) -> {hwaddr} (type {})", hwaddr.hwtype());
+        }
+        #[cfg(all(target_os = "linux", feature = "netlink"))]
+        "altnames" => {
+            let ifname = ifid(ifname)?;
+            for alt_name in Nic::default().get_alt_names(ifname)? {
+                eprintln!("{alt_name}");
+            }
+        }
+        #[cfg(all(target_os = "linux", feature = "netlink"))]
+        "watch" => {
+            let nic = Nic::netlink();
+            for event in nic.watch()? {
+                eprintln!("{:?}", event?);
+            }
+        }
+        "list" => {
+            for link in Nic::default().list()? {
+                eprintln!(
+                    "{}: {} {} {:?}",
+                    link.index(),
+                    link.ifname(),
+                    link.hwaddr(),
+                    link.flags()
+                );
+            }
+        }
         invalid => {
             return Err(format!("Invalid action: {invalid}").into());
         }
@@ -25,3 +167,87 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::hint;
+    use net_sys::sim::{SimLink, SimNet};
+    use net_sys::{
+        ifname, lladdr, BounceError, IfFlags, IfId, IfName, LLAddr, Link, Nic, NicBackend, Result,
+    };
+
+    const ETH: IfName = ifname!("eth0");
+
+    // Loses its privileges once a link is down, so neither the address
+    // change nor bringing the link back up goes through.
+    #[derive(Debug)]
+    struct Revoked(SimNet);
+
+    impl NicBackend for Revoked {
+        fn get_lladd(&self, ifid: IfId) -> Result<LLAddr> {
+            self.0.get_lladd(ifid)
+        }
+
+        fn set_lladd(&self, ifid: IfId, lladdr: &LLAddr) -> Result<()> {
+            self.0.set_lladd(ifid, lladdr)
+        }
+
+        fn get_flags(&self, ifid: IfId) -> Result<IfFlags> {
+            self.0.get_flags(ifid)
+        }
+
+        fn set_admin(&self, ifid: IfId, up: bool) -> Result<()> {
+            self.0.set_admin(ifid, up)?;
+            self.0.set_privileged(false);
+            Ok(())
+        }
+
+        fn get_mtu(&self, ifid: IfId) -> Result<u32> {
+            self.0.get_mtu(ifid)
+        }
+
+        fn set_mtu(&self, ifid: IfId, mtu: u32) -> Result<()> {
+            self.0.set_mtu(ifid, mtu)
+        }
+
+        fn get_permanent_lladdr(&self, ifid: IfId) -> Result<LLAddr> {
+            self.0.get_permanent_lladdr(ifid)
+        }
+
+        fn list(&self) -> Result<Vec<Link>> {
+            self.0.list()
+        }
+    }
+
+    #[test]
+    fn test_hint_permission_denied() {
+        let net = SimNet::default();
+        net.add(SimLink::new(ETH, lladdr!("00:11:22:33:44:55")));
+        net.set_privileged(false);
+
+        let error = Nic::with_backend(net)
+            .set_lladd(ETH, &lladdr!("02:00:00:00:00:01"))
+            .unwrap_err();
+
+        assert_eq!(
+            hint(error.as_ref()),
+            Some("run as root or with CAP_NET_ADMIN".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hint_bounce_permission_denied() {
+        let net = SimNet::default();
+        net.add(SimLink::new(ETH, lladdr!("00:11:22:33:44:55")).with_admin(true));
+
+        let error = Nic::with_backend(Revoked(net))
+            .set_lladd_bounce(ETH, &lladdr!("02:00:00:00:00:01"))
+            .unwrap_err();
+
+        assert!(error.downcast_ref::<BounceError>().is_some());
+        assert_eq!(
+            hint(error.as_ref()),
+            Some("run as root or with CAP_NET_ADMIN".to_string())
+        );
+    }
+}